@@ -24,12 +24,19 @@
 // use crate::structs::rings::complex::Complex;
 use crate::structs::rings::local_ring::Local;
 use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::BigInt;
 use crate::structs::rings::LogDepInt;
 use crate::structs::rings::Conj;
+use crate::structs::rings::CheckedRing;
+use crate::structs::rings::ArithmeticOverflow;
 use crate::structs::rings::zroot2::Zroot2;
 use crate::structs::sunimat::SUniMat;
 use crate::structs::rings::special_values::mu_8;
 use crate::structs::rings::special_values::sqrt2;
+use crate::structs::rings::special_values::omega;
+use crate::structs::rings::special_values::onebyroot2loc;
+use crate::structs::rings::zomega::Zomega;
 
 
 // Num traits
@@ -44,21 +51,30 @@ use num_traits::Pow;
 use num_complex::Complex;
 
 // Better looking code
+// Everything here works for any integer backend
 // type Quat = Quaternion<Local<Zroot2>>;
-type Loc = Local<Zroot2>;
-type Comp = Complex<Loc>;
-type Mat = SUniMat<Comp>;
+type Loc<I = Int> = Local<Zroot2<I>>;
+type Comp<I = Int> = Complex<Loc<I>>;
+type Mat<I = Int> = SUniMat<Comp<I>>;
 use crate::structs::unimat::ExactUniMat;
+use crate::structs::unimat::DyadicMat;
+use crate::structs::circuit::Circuit;
+use crate::structs::clifford::Clifford;
+use crate::algorithms::normal_form::normal_form;
+use crate::algorithms::normal_form::bloch_lde;
 
 // A state is also a matrix technically
-type State = Mat;
+type State<I = Int> = Mat<I>;
+
+// Entries in D[ω], where u and t have one denominator each
+type DLoc<I = Int> = Local<Zomega<I>>;
 
 // Look up table stuff
 use crate::algorithms::exact_synth_hashtable_lookup::GateTable;
-use crate::algorithms::exact_synth_hashtable_lookup::read_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::gate_table;
 
 
-pub fn multiply_h_times_t_to_n( gamma: Mat, n: Int) -> Mat
+pub fn multiply_h_times_t_to_n<I: IntBackend>( gamma: Mat<I>, n: Int) -> Mat<I>
 {
     if n==0
     {     
@@ -67,14 +83,15 @@ pub fn multiply_h_times_t_to_n( gamma: Mat, n: Int) -> Mat
 
     else
     {
-        let omega = mu_8();
-        let sqrt2 = sqrt2();
+        let omega = mu_8::<I>();
+        let sqrt2 = sqrt2::<I>();
 
         let u1 = gamma.u;
         let t1 = gamma.t;
 
-        let u2 = ( u1+omega.pow(n)*t1 )/sqrt2;
-        let t2 = ( u1-omega.pow(n)*t1 )/sqrt2;
+        let twisted_t1 = omega.pow(n)*t1;
+        let u2 = ( u1.clone()+twisted_t1.clone() )/sqrt2.clone();
+        let t2 = ( u1-twisted_t1 )/sqrt2;
 
         return Mat{
             u: u2,
@@ -87,11 +104,9 @@ pub fn multiply_h_times_t_to_n( gamma: Mat, n: Int) -> Mat
 
 // Shoud be deprecated
 // Instead, we should use ExactUniMat::from_string(...)*gamma
-pub fn apply_gate_string_to_state( gate_string: String ,  gamma: Mat) -> Mat
+pub fn apply_gate_string_to_state<I: IntBackend>( gate_string: String ,  gamma: Mat<I>) -> Mat<I>
 {
 
-    let rt2 = sqrt2();
-    let omega = mu_8();
     let mut state = gamma;
 
     // Why is it rev? We apply gates right to left in the string
@@ -106,25 +121,29 @@ pub fn apply_gate_string_to_state( gate_string: String ,  gamma: Mat) -> Mat
         {
             state = apply_t_gate(state);
         }
+        if i=='S' 
+        {
+            state = apply_t_gate(apply_t_gate(state));
+        }
     }
 
     return state;
 }
 
-pub fn apply_h_gate( gamma: Mat) -> Mat
+pub fn apply_h_gate<I: IntBackend>( gamma: Mat<I>) -> Mat<I>
 {
-    let rt2 = sqrt2();
+    let rt2 = sqrt2::<I>();
     let state = Mat
     {
-        u: (gamma.u+gamma.t)/rt2,
+        u: (gamma.u.clone()+gamma.t.clone())/rt2.clone(),
         t: (gamma.u-gamma.t)/rt2,
     };
     return state;
 }
 
-pub fn apply_tinv_gate( gamma: Mat) -> Mat
+pub fn apply_tinv_gate<I: IntBackend>( gamma: Mat<I>) -> Mat<I>
 {
-    let omega = mu_8();
+    let omega = mu_8::<I>();
     let state = Mat
     {
         u: gamma.u,
@@ -133,9 +152,9 @@ pub fn apply_tinv_gate( gamma: Mat) -> Mat
     return state;
 }
 
-pub fn apply_t_gate( gamma: Mat) -> Mat
+pub fn apply_t_gate<I: IntBackend>( gamma: Mat<I>) -> Mat<I>
 {
-    let omega = mu_8();
+    let omega = mu_8::<I>();
     let state = Mat
     {
         u: gamma.u,
@@ -146,10 +165,10 @@ pub fn apply_t_gate( gamma: Mat) -> Mat
 
 
 // The main deal
-pub fn exact_synth( gamma: ExactUniMat) -> String 
+pub fn exact_synth<I: IntBackend>( gamma: ExactUniMat<I>) -> Circuit
 { 
 
-    let gammamat = gamma.mat; 
+    let gammamat = gamma.mat.clone(); 
 
 
     // assert_eq!(gamma, ExactUniMat::from_string(&"HTHT".to_string() ) );
@@ -168,11 +187,28 @@ pub fn exact_synth( gamma: ExactUniMat) -> String
 
 
 
+    // When what is left is a Clifford, phase included, it is written as
+    // its shortest word in H and S, and neither the table nor T gates are needed
+    let rest = ExactUniMat::<I>::from_string(&seq).inv()*gamma.clone();
+    if let Some(clifford) = Clifford::from_exact_unimat(&rest)
+    {
+        seq.push_str(&clifford.word().to_string());
+        let circuit: Circuit = seq.parse().unwrap();
+        return circuit.collapse_t_runs();
+    }
+
     if to_be_looked_up != Mat::one()
     {
-        let file_saved_at = "data/gates_with_small_t_count.dat";
-        let gatetable = read_hash_table(file_saved_at).unwrap();
-        let possible_answer = gatetable.get(&to_be_looked_up);
+        let gatetable = gate_table();
+
+        // The table is written over Int
+        // The residue has small sde, so it always fits
+        let to_be_looked_up_int = to_be_looked_up.to_backend::<Int>();
+        let possible_answer = match &to_be_looked_up_int
+        {
+            Some(key) => gatetable.get(key),
+            None => None,
+        };
 
         if possible_answer==None
         {
@@ -191,7 +227,7 @@ pub fn exact_synth( gamma: ExactUniMat) -> String
 
     // We would think that we have composed the gate
     // but unitary matrices are different from special unitary matrices
-    let almost_answer = ExactUniMat::from_string(&seq);
+    let almost_answer = ExactUniMat::<I>::from_string(&seq);
 
     let difference = almost_answer.inv()*gamma;
 
@@ -208,98 +244,156 @@ pub fn exact_synth( gamma: ExactUniMat) -> String
 
     // println!("FINAL SEQUENCE = {}", seq );
 
-    return seq;
+    let circuit: Circuit = seq.parse().expect("Lookup table gave a gate string that is not Clifford+T");
+    return circuit.collapse_t_runs();
 
 }
 
 
-
-
-pub fn sde(gamma: Mat) -> LogDepInt
+// The same, written in the letters of ExactUniMat::from_string
+pub fn exact_synth_string<I: IntBackend>( gamma: ExactUniMat<I>) -> String
 {
-    return gamma.u.norm_sqr().log_den
+    return exact_synth(gamma).to_string();
 }
 
 
+// T-optimal mode
+//
+// exact_synth is not T-optimal: the greedy reduction is, but the words in the
+// lookup table are not. The Matsumoto–Amano normal form is, so this mode
+// returns it, together with the lower bounds read off the matrix
+//
+// Why the bounds hold:
+// - Conjugating the Paulis, a Clifford is a signed permutation matrix on the
+//   Bloch sphere, and T has entries 0, ±1 and ±1/√2. So every T raises the
+//   lde of the Bloch rotation by at most one, and Cliffords do not change it
+// - |u|^2 = (1 + R_zz)/2, so sde(|u|^2) is at most the lde of R plus two
+// - det(T) = ω while the determinants of H, S, X, Y and Z are even powers of ω,
+//   so the T-count has the parity of omega_exp
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct TCountCertificate
+{
+    pub sde_bound: usize,       // sde(|u|^2) - 2, corrected for parity
+    pub lower_bound: usize,     // The lde of the Bloch rotation, never below sde_bound
+    pub t_count: usize,         // The T-count of the circuit that came with it
+}
 
-
-// This will get the sdeq small enough so that we can then use a look up table
-pub fn partial_exact_synth_given_norm_1( gamma: Mat) -> (String, Mat)
+impl TCountCertificate
 {
+    pub fn for_gate<I: IntBackend>(gamma: &ExactUniMat<I>, t_count: usize) -> Self
+    {
+        let parity = (gamma.omega_exp%2) as usize;
+        let mut sde_bound = (sde(gamma.mat.clone()) - 2).max(0) as usize;
+        if sde_bound%2 != parity
+        {
+            sde_bound = sde_bound + 1;
+        }
 
-    let mut gate_string = "".to_string();
+        let lower_bound = bloch_lde(gamma) as usize;
+        assert!( lower_bound >= sde_bound && lower_bound%2 == parity, "The bounds on the T-count disagree" );
 
-    if gamma.det()!= Comp::one()
-    {
-        println!("gamma.det was {}", gamma.det());
-        panic!("I was promised norm 1");
+        return Self{ sde_bound, lower_bound, t_count };
     }
 
-    // if gamma.u.norm_sqr().log_den != gamma.t.norm_sqr().log_den
-    // {
-    //     panic!("Mathematics is wrong");
-    // }
-
-    let mut g: Mat;
-    let mut h = gamma;
+    // No circuit for the gate has fewer T gates than this one
+    pub fn is_optimal(&self) -> bool
+    {
+        return self.t_count == self.lower_bound;
+    }
+}
 
+pub fn exact_synth_t_optimal<I: IntBackend>( gamma: ExactUniMat<I>) -> (Circuit, TCountCertificate)
+{
+    let circuit = normal_form(&gamma).to_circuit();
+    let certificate = TCountCertificate::for_gate(&gamma, circuit.t_count());
+    assert!( certificate.is_optimal(), "The normal form should be T-optimal" );
+    return (circuit, certificate);
+}
 
-    let mut nevercalled : bool;
-    let mut i: Int;
 
-    // This is what we want to reduce
-    let mut sdeq= sde(h);
+// See Circuit::collapse_t_runs
+// This panics on letters that are not Clifford+T
+pub fn collapse_t_runs(gate_string: &str) -> String
+{
+    let circuit: Circuit = gate_string.parse().expect("Not a Clifford+T gate string");
+    return circuit.collapse_t_runs().to_string();
+}
 
 
+pub fn sde<I: IntBackend>(gamma: Mat<I>) -> LogDepInt
+{
+    return gamma.u.norm_sqr().log_den
+}
 
-    // See Lemma 3 in 1206.5236v4 to see why sdeq > 3
-    while sdeq>3
-    {
-        // println!("SDEQ VALUE = {}", sdeq);
-        nevercalled = true;
+// The same, for matrices that were read from somewhere and may not fit
+pub fn checked_sde<I: IntBackend>(gamma: &Mat<I>) -> Result<LogDepInt, ArithmeticOverflow>
+{
+    let re = gamma.u.re.checked_mul(&gamma.u.re)?;
+    let im = gamma.u.im.checked_mul(&gamma.u.im)?;
+    return Ok(re.checked_add(&im)?.log_den);
+}
 
-        // See Lemma 3 in 1206.5236v4 to see why 0<i<4
-        i = 0;
-        while nevercalled && i < 4 
-        {
 
 
-            sdeq= sde(h);
-            g = multiply_h_times_t_to_n(h,-i);
 
+// This will get the sdeq small enough so that we can then use a look up table
+// The reduction runs on D[ω] entries. Their numerators grow like |1+ω|^sde
+// instead of √2^sde, so it is done over BigInt, and the residue,
+// being of sde at most 3, is brought back to I
+pub fn partial_exact_synth_given_norm_1<I: IntBackend>( gamma: Mat<I>) -> (String, Mat<I>)
+{
+    let gamma = gamma.to_backend::<BigInt>().expect("Every integer fits in a BigInt");
+    let (gate_string, rest) = partial_exact_synth_dyadic(gamma.to_dyadic());
+    let rest = rest.to_kmm().to_backend::<I>().expect("Gates of sde 3 fit in any backend");
+    return (gate_string, rest);
+}
 
-            let sdeq_new= sde(g);
 
-            if sdeq_new==sdeq-1
-            {
+// H T^n, with entries in D[ω]
+pub fn multiply_h_times_t_to_n_dyadic<I: IntBackend>( gamma: DyadicMat<I>, n: Int) -> DyadicMat<I>
+{
+    let twist = DLoc::from_base(omega::<I>().pow(n as i32));
+    let one_by_root2 = <DLoc<I> as From<Comp<I>>>::from(Comp::new(onebyroot2loc(), Loc::zero()));
 
-                // println!("DECREASED SDEQ TO {}",sdeq_new );
-                // println!("THIS HAPPENED AT i = {}", i);
-                nevercalled = false;
+    let twisted_t = twist*gamma.t;
+    return DyadicMat
+    {
+        u: ( gamma.u.clone()+twisted_t.clone() )*one_by_root2.clone(),
+        t: ( gamma.u-twisted_t )*one_by_root2
+    };
+}
 
-                sdeq = sdeq_new-1;
-                h = g;
 
+// The same reduction, on D[ω] entries
+// The sde is the denominator exponent of u, so no norms are taken
+pub fn partial_exact_synth_dyadic<I: IntBackend>( gamma: DyadicMat<I>) -> (String, DyadicMat<I>)
+{
+    if gamma.clone().det() != DLoc::one()
+    {
+        println!("gamma.det was {}", gamma.clone().det());
+        panic!("I was promised norm 1");
+    }
 
-                for j in 0..i
-                {
-                    gate_string.push_str("T");
-                }
+    let mut gate_string = "".to_string();
+    let mut h = gamma;
 
+    // See Lemma 3 in 1206.5236v4 to see why sdeq > 3
+    while h.sde() > 3
+    {
+        // See Lemma 3 in 1206.5236v4 to see why 0<i<4
+        let sdeq = h.sde();
+        let reduced = (0..4).map(|i| (i, multiply_h_times_t_to_n_dyadic(h.clone(), -i))).find(|(_, g)| g.sde() == sdeq-1);
+        match reduced
+        {
+            Some((i, g)) =>
+            {
+                h = g;
+                gate_string.push_str(&"T".repeat(i as usize));
                 gate_string.push_str("H");
             }
-            i=i+1;
-        }
-        if nevercalled
-        {
-            panic!("Could not decrease sdeq");
+            None => panic!("Could not decrease sdeq"),
         }
-
     }
 
-
-
     return (gate_string, h);
 }
-
-
@@ -1,18 +1,19 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{BufRead, BufReader};
+use std::sync::OnceLock;
 
 
-// Matrix multiplication
-use crate::algorithms::exact_synth::apply_gate_string_to_state;
-
 // Special values
 use crate::structs::rings::special_values::mu_8;
 use crate::structs::rings::special_values::sqrt2;
 
 
 // Rings and matrices
+use crate::structs::rings::Int;
+use crate::structs::rings::LogDepInt;
 use crate::structs::rings::zroot2::Zroot2;
 type Loc = crate::structs::rings::local_ring::Local::<Zroot2>;
 use num_complex::Complex;
@@ -24,9 +25,18 @@ type ExactGate = crate::structs::sunimat::SUniMat<Complex<Loc>>;
 type State = ExactGate;
 
 
-type GateString = String; // String of H and T
-pub const GATE_STRING_LENGTH : usize = 25;  // This is the number of H and T gates to be multiplied
-                                            // This number is recommended to be 24
+// The table that is compiled in
+use crate::algorithms::residual_table::RESIDUAL_TABLE;
+use crate::algorithms::gate_table_file::read_binary_table;
+use crate::algorithms::mapped_gate_table::GateLookup;
+use crate::algorithms::mapped_gate_table::MappedGateTable;
+use crate::algorithms::exact_synth::sde;
+use crate::structs::circuit::Circuit;
+use crate::structs::clifford::Clifford;
+use crate::structs::unimat::ExactUniMat;
+
+
+type GateString = String; // String of H, S and T
 
 
 // Hash table. It will store gatestring and corresponding exact gate
@@ -42,67 +52,68 @@ pub fn read_hash_table(filename: &str) -> std::io::Result<GateTable> {
     let file = File::open(filename)?;
     let reader = BufReader::new(file);
 
+    return parse_hash_table(reader);
+}
+
+
+fn invalid_data(message: &str, line: &str) -> std::io::Error
+{
+    return std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}: \"{}\"", message, line));
+}
+
+// Reads what save_hash_table writes, from anywhere
+// Bad lines are errors, not panics
+pub fn parse_hash_table<R: BufRead>(reader: R) -> std::io::Result<GateTable> {
+
     let mut table = HashMap::new();
 
 
     for line in reader.lines() 
     {
         let line = line?;
+        if line.is_empty()
+        {
+            continue;
+        }
         let parts: Vec<&str> = line.split(" ").collect();
+        if parts.len() != 13
+        {
+            return Err(invalid_data("expected a gate string and 12 numbers", &line));
+        }
 
-        let value: GateString = parts[0].parse().unwrap();
+        let value: GateString = parts[0].to_string();
 
         let index = 1; // If we want to move ahead later
 
         // Reconstruct from the data 
         // Since we assume that the data is written by save_hash_table
         // we do not need the constructors Loc::from_base 
-        let key  = ExactGate
+        let number = |i: usize| parts[index+i].parse().map_err(|_| invalid_data("not a number", &line));
+        let loc = |i: usize| -> std::io::Result<Loc>
         {
-            u: Complex
+            return Ok(Loc
             {
-                re: Loc
+                num: Zroot2
                 {
-                    num: Zroot2
-                    {
-                        0: parts[index+0].parse().unwrap(),
-                        1: parts[index+1].parse().unwrap()
-                    },
-                    log_den: parts[index+2].parse().unwrap()
+                    0: number(i)?,
+                    1: number(i+1)?
                 },
-                im: Loc
-                {
-                    num: Zroot2
-                    {
-                        0: parts[index+3].parse().unwrap(),
-                        1: parts[index+4].parse().unwrap()
-                    },
-                    log_den: parts[index+5].parse().unwrap()
-                }
+                log_den: parts[index+i+2].parse().map_err(|_| invalid_data("not an exponent", &line))?
+            });
+        };
 
+        let key  = ExactGate
+        {
+            u: Complex
+            {
+                re: loc(0)?,
+                im: loc(3)?
             },
             
             t: Complex
             {
-                re: Loc
-                {
-                    num: Zroot2
-                    {
-                        0: parts[index+6].parse().unwrap(),
-                        1: parts[index+7].parse().unwrap()
-                    },
-                    log_den: parts[index+8].parse().unwrap()
-                },
-                im: Loc
-                {
-                    num: Zroot2
-                    {
-                        0: parts[index+9].parse().unwrap(),
-                        1: parts[index+10].parse().unwrap()
-                    },
-                    log_den: parts[index+11].parse().unwrap()
-                }
-
+                re: loc(6)?,
+                im: loc(9)?
             },
         };
 
@@ -143,103 +154,350 @@ pub fn save_hash_table(table: &GateTable, filename: &str) -> std::io::Result<()>
     Ok(())
 }
 
-pub fn push_h_gate( sequence: &mut GateString)
+// Breadth first generation
+//
+// Gates are explored by T-count. U and U*C have the same T-count for every
+// Clifford C, and a word for U*C is a word for U followed by the word of C.
+// So the search keeps one representative of each right coset U*Clifford,
+// and since omega is a Clifford this also takes care of the global phase.
+// Every level multiplies the representatives of the one before by HT and
+// SHT, and the first level also by T. These are the normal forms of
+// normal_form.rs, so every coset is found, at its least T-count
+//
+// At the end of each level, the new cosets are spread out over their 192
+// elements, and every matrix with sde at most the cutoff keeps a word with
+// the fewest T gates. Ties go to the shorter word, then to the first in
+// alphabetical order, so the table does not depend on the threads
+
+pub struct TableGeneration
 {
-    sequence.push('H');
+    pub sde_cutoff: LogDepInt,      // Only matrices with at most this sde are kept
+    pub max_t_count: usize,         // The last level that is explored, the table is complete from sde_cutoff + 2
+    pub threads: usize,
+    pub report_progress: bool,      // Print a line after each level
 }
 
-pub fn push_t_gate( sequence: &mut GateString)
+impl TableGeneration
 {
-    sequence.push('T');
+    // Every matrix with sde at most k has T-count at most k + 2, so these
+    // levels find all of them. Writing u = x/δ^m with δ = 1+ω and x not
+    // divisible by δ, we have u*ū = x*x̄/(δ*δ̄)^m = x*x̄/(√2 λ)^m, and x*x̄
+    // is not divisible by √2 because δ̄ is an associate of δ. So the sde of
+    // |u|^2 is exactly m, and t has the same denominator since |t|^2 = 1-|u|^2.
+    // The Bloch rotation has entries like Re(u*t̄*ω^j) = (z+z̄)/2 with z of
+    // denominator δ^(2m) = √2^m λ^m, so its lde is at most m + 2, and the
+    // lde of the Bloch rotation is the T-count (see TCountCertificate).
+    // generate_table_breadth_first checks this bound on every element
+    pub fn new(sde_cutoff: LogDepInt) -> Self
+    {
+        let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        return Self{ sde_cutoff, max_t_count: sde_cutoff.max(0) as usize + 2, threads, report_progress: false };
+    }
 }
 
-pub fn pop_sequence( sequence: &mut GateString)
+
+type Word = (usize, usize, String);     // T-count, length, letters
+type CosetKey = ([Int; 12], u8);
+
+fn word_of(circuit: &Circuit) -> Word
 {
-    sequence.pop();
+    return (circuit.t_count(), circuit.len(), circuit.to_string());
 }
 
-// WARNING: final_length given should be less than 8*size_of(GateString)
-// otherwise there shall be overflow
-pub fn generate_hashtable_of_gate_sequence_recursively(final_length: &usize, sequence: &mut GateString, table: &mut GateTable )
+// The smallest element of the coset, with its phase
+fn coset_key(gate: &ExactUniMat<Int>, cliffords: &[ExactUniMat<Int>]) -> CosetKey
 {
+    return cliffords.iter().map(|c|
+    {
+        let element = *gate*(*c);
+        return (key_numbers(&element.mat), element.omega_exp%8);
+    }).min().unwrap();
+}
 
-    let mut len = sequence.len();
-    if len == *final_length
+// Splits the items between threads, and gives back the results in order
+fn in_parallel<T: Sync, R: Send, F: Fn(&[T]) -> R + Sync>(items: &[T], threads: usize, f: F) -> Vec<R>
+{
+    let chunk_size = items.len().div_ceil(threads.max(1)).max(1);
+    return std::thread::scope(|scope|
     {
-        // write sequence to hashtable
-        let ident = ExactGate::one();
-        let temp = apply_gate_string_to_state(sequence.to_string(), ident);
+        let handles: Vec<_> = items.chunks(chunk_size).map(|chunk| scope.spawn(|| f(chunk))).collect();
+        return handles.into_iter().map(|handle| handle.join().expect("A table generation thread panicked")).collect();
+    });
+}
 
-        let sde = temp.u.norm_sqr().log_den;
-        
-        if sde < 5
+fn keep_best(best: &mut HashMap<ExactGate, Word>, key: ExactGate, word: Word)
+{
+    let better = match best.get(&key)
+    {
+        Some(old) => word < *old,
+        None => true,
+    };
+    if better
+    {
+        best.insert(key, word);
+    }
+}
+
+pub fn generate_table_breadth_first(settings: &TableGeneration) -> GateTable
+{
+    let cliffords: Vec<(ExactUniMat<Int>, Circuit)> = Clifford::all().map(|c| (c.to_exact_unimat(), c.word())).collect();
+    let clifford_gates: Vec<ExactUniMat<Int>> = cliffords.iter().map(|(gate, _)| *gate).collect();
+    let syllables: Vec<(ExactUniMat<Int>, Circuit)> = ["T", "HT", "SHT"].iter().map(|word|
+    {
+        let circuit: Circuit = word.parse().unwrap();
+        return (circuit.to_exact_unimat(), circuit);
+    }).collect();
+
+    let mut seen = HashSet::<CosetKey>::new();
+    let mut best = HashMap::<ExactGate, Word>::new();
+    let mut level = vec![(ExactUniMat::<Int>::one(), Circuit::one())];
+    seen.insert(coset_key(&level[0].0, &clifford_gates));
+
+    for t_count in 0..(settings.max_t_count+1)
+    {
+        if t_count > 0
         {
-            println!("Writing gate sequence {} in the hash table with sde {}", sequence, sde);
-            table.insert(temp, sequence.to_string());
+            // Only the first level starts with T
+            let first = if t_count == 1 { 0 } else { 1 };
+            let candidates: Vec<(ExactUniMat<Int>, Circuit)> = level.iter().flat_map(|(gate, word)|
+            {
+                return syllables[first..].iter().map(move |(syllable_gate, syllable)| (*gate*(*syllable_gate), word.clone()*syllable.clone()));
+            }).collect();
+
+            let keys: Vec<CosetKey> = in_parallel(&candidates, settings.threads, |chunk|
+            {
+                return chunk.iter().map(|(gate, _)| coset_key(gate, &clifford_gates)).collect::<Vec<_>>();
+            }).concat();
+
+            // The same coset twice keeps the better word
+            let mut next = HashMap::<CosetKey, (ExactUniMat<Int>, Circuit)>::new();
+            for (key, (gate, word)) in keys.into_iter().zip(candidates)
+            {
+                if seen.contains(&key)
+                {
+                    continue;
+                }
+                let better = match next.get(&key)
+                {
+                    Some((_, old)) => word_of(&word) < word_of(old),
+                    None => true,
+                };
+                if better
+                {
+                    next.insert(key, (gate, word));
+                }
+            }
+            let mut next: Vec<(CosetKey, (ExactUniMat<Int>, Circuit))> = next.into_iter().collect();
+            next.sort_by(|a, b| a.0.cmp(&b.0));
+            seen.extend(next.iter().map(|(key, _)| *key));
+            level = next.into_iter().map(|(_, entry)| entry).collect();
         }
 
-        return;
-    }
+        let found = in_parallel(&level, settings.threads, |chunk|
+        {
+            let mut found = HashMap::<ExactGate, Word>::new();
+            for (gate, word) in chunk
+            {
+                for (clifford, clifford_word) in cliffords.iter()
+                {
+                    let element = *gate*(*clifford);
+                    let element_sde = sde(element.mat);
+                    assert!( t_count as LogDepInt <= element_sde + 2, "Found sde {} with {} T gates", element_sde, t_count );
+                    if element_sde <= settings.sde_cutoff
+                    {
+                        keep_best(&mut found, element.mat, word_of(&(word.clone()*clifford_word.clone())));
+                    }
+                }
+            }
+            return found;
+        });
+        for (key, word) in found.into_iter().flatten()
+        {
+            keep_best(&mut best, key, word);
+        }
 
-    push_h_gate(sequence);
-    len = len+1;
-    
-    // avoiding HH in gate sequence
-    if len > 1
-    {
-        if sequence[(len-2)..(len)] != "HH".to_string()
+        if settings.report_progress
         {
-            generate_hashtable_of_gate_sequence_recursively(final_length,  sequence, table);
+            println!("T-count {}: {} new cosets, {} gates with sde at most {}", t_count, level.len(), best.len(), settings.sde_cutoff);
         }
     }
-    else
+
+    return best.into_iter().map(|(key, (_, _, word))|
     {
-        generate_hashtable_of_gate_sequence_recursively(final_length,  sequence, table);
-    }
+        let value = if word.is_empty() { "I".to_string() } else { word };
+        return (key, value);
+    }).collect();
+}
+
+
 
-    pop_sequence(sequence);
+// The whole table, in memory
+// This used to be every H and T string of up to 25 letters,
+// which gives the same matrices as the residual table with worse words
+pub fn generate_hash_table() -> GateTable {
+    return generate_residual_table();
+}
+
+
+pub fn generate_gate_table() {
+    let file_to_be_saved_at = DEFAULT_TABLE_PATH;
+
+    let gatetable = generate_hash_table();
+    println!("Writing {} gate sequences in {}", gatetable.len(), file_to_be_saved_at);
     
-    push_t_gate(sequence);
+    save_hash_table(&gatetable, file_to_be_saved_at ).unwrap();
+
+}
 
-    // Avoiding TTTTTTTT in gate sequence
-    if len > 7
+
+
+// Every special unitary with sde at most 4, with a word of least T-count
+//
+// This is all that exact_synth needs: partial_exact_synth_given_norm_1
+// stops at sde 3 or 4. The table is generated by generate_residual_table, and
+// write_residual_table saves it as Rust source in RESIDUAL_TABLE_PATH, so
+// it is compiled into the crate and no data file is needed
+//
+// A matrix here is the matrix part of eight ExactUniMat, one for each phase
+// on the second column, and exact_synth puts the phase right with T gates at
+// the end. So the word is the normal form of the one of the eight with the
+// fewest T gates. Its Clifford is spelled out in H and S, because W would
+// change the matrix part
+
+pub const RESIDUAL_SDE: LogDepInt = 4;
+
+pub const RESIDUAL_TABLE_PATH: &str = "src/algorithms/residual_table.rs";
+
+
+pub fn generate_residual_table() -> GateTable
+{
+    return generate_table_breadth_first(&TableGeneration::new(RESIDUAL_SDE));
+}
+
+
+// The twelve numbers that save_hash_table writes for a key
+pub fn key_numbers(key: &ExactGate) -> [Int; 12]
+{
+    let numbers = |x: &Loc| [x.num.0, x.num.1, x.log_den as Int];
+    let [a, b, c, d] = [numbers(&key.u.re), numbers(&key.u.im), numbers(&key.t.re), numbers(&key.t.im)];
+    return [a[0], a[1], a[2], b[0], b[1], b[2], c[0], c[1], c[2], d[0], d[1], d[2]];
+}
+
+pub fn key_from_numbers(numbers: &[Int; 12]) -> ExactGate
+{
+    let loc = |i: usize| Loc{ num: Zroot2(numbers[i], numbers[i+1]), log_den: numbers[i+2] as LogDepInt };
+    return ExactGate
     {
-        if sequence[(len-8)..(len)] != "TTTTTTTT".to_string()
-        {
-            generate_hashtable_of_gate_sequence_recursively(final_length,  sequence, table);
-        }
-    }
-    else
+        u: Complex{ re: loc(0), im: loc(3) },
+        t: Complex{ re: loc(6), im: loc(9) },
+    };
+}
+
+// The table as the Rust source of RESIDUAL_TABLE_PATH, sorted so that it
+// only changes when the table does
+pub fn residual_table_source(table: &GateTable) -> String
+{
+    let mut entries: Vec<([Int; 12], &GateString)> = table.iter().map(|(key, value)| (key_numbers(key), value)).collect();
+    entries.sort();
+
+    let mut source = String::new();
+    source.push_str("// Generated by write_residual_table in exact_synth_hashtable_lookup.rs\n");
+    source.push_str("// Do not edit by hand\n");
+    source.push_str("//\n");
+    source.push_str("// A word, and the numbers of the key in the order of save_hash_table\n\n");
+    source.push_str("use crate::structs::rings::Int;\n\n");
+    source.push_str(&format!("pub const RESIDUAL_TABLE: [(&str, [Int; 12]); {}] = [\n", entries.len()));
+    for (numbers, value) in entries
     {
-        generate_hashtable_of_gate_sequence_recursively(final_length,  sequence, table);
+        let numbers: Vec<String> = numbers.iter().map(|x| x.to_string()).collect();
+        source.push_str(&format!("    (\"{}\", [{}]),\n", value, numbers.join(", ")));
     }
+    source.push_str("];\n");
+    return source;
+}
 
-    pop_sequence(sequence);
+pub fn write_residual_table()
+{
+    let table = generate_residual_table();
+    println!("Writing {} gate sequences in {}", table.len(), RESIDUAL_TABLE_PATH);
+
+    let mut file = File::create(RESIDUAL_TABLE_PATH).unwrap();
+    file.write_all(residual_table_source(&table).as_bytes()).unwrap();
 }
 
+pub fn embedded_residual_table() -> GateTable
+{
+    return RESIDUAL_TABLE.iter().map(|(value, numbers)| (key_from_numbers(numbers), value.to_string())).collect();
+}
 
 
-pub fn generate_gate_table() {
-    let mut gatetable = GateTable::new();
-    let file_to_be_saved_at = "data/gates_with_small_t_count.dat";
 
+// One table for the whole process
+//
+// exact_synth used to read the file on every call. Now the table is loaded
+// once, the first time it is needed, and every thread shares it. Call
+// init_gate_table first to choose where it comes from. Otherwise gate_table
+// takes the residual table that is compiled in
+//
+// A Mapped table is not loaded at all, see mapped_gate_table.rs
 
-    for i in 0..GATE_STRING_LENGTH
-    {
-        let gatelength = GATE_STRING_LENGTH -i ; // This reverse length makes shorter gate sequences overwrite longer ones
+pub const DEFAULT_TABLE_PATH: &str = "data/gates_with_small_t_count.dat";
 
-        generate_hashtable_of_gate_sequence_recursively( &gatelength  ,  &mut "".to_string(), &mut gatetable);
-    }
-    
-    // Updating the value of the identity state
-    println!("Writing identity in the hashtable");
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum TableSource
+{
+    Embedded,           // The residual table in RESIDUAL_TABLE_PATH
+    File(String),
+    Binary(String),     // See gate_table_file.rs
+    Generate,           // The residual table, generated now
+    Mapped(String),     // See mapped_gate_table.rs
+}
 
+static GATE_TABLE: OnceLock<Box<dyn GateLookup>> = OnceLock::new();
 
-    let option = gatetable.insert(ExactGate::one(), "I".to_string());
 
-    
-    save_hash_table(&gatetable, file_to_be_saved_at ).unwrap();
-        
-    // Adding the empty gate sequence
+pub fn load_hash_table(source: &TableSource) -> std::io::Result<GateTable>
+{
+    return match source
+    {
+        TableSource::Embedded => Ok(embedded_residual_table()),
+        TableSource::File(filename) => read_hash_table(filename),
+        TableSource::Binary(filename) => Ok(read_binary_table(filename)?.1),
+        TableSource::Generate => Ok(generate_residual_table()),
+        TableSource::Mapped(filename) => Ok(MappedGateTable::open(filename)?.to_gate_table()?),
+    };
+}
+
+// The same, but a Mapped table stays in its file
+pub fn open_gate_table(source: &TableSource) -> std::io::Result<Box<dyn GateLookup>>
+{
+    return match source
+    {
+        TableSource::Mapped(filename) => Ok(Box::new(MappedGateTable::open(filename)?)),
+        other => Ok(Box::new(load_hash_table(other)?)),
+    };
+}
+
+// Fails if the table cannot be loaded, or if it was already loaded
+pub fn init_gate_table(source: TableSource) -> std::io::Result<&'static dyn GateLookup>
+{
+    if GATE_TABLE.get().is_some()
+    {
+        return Err(std::io::Error::new(std::io::ErrorKind::AlreadyExists, "the gate table is already loaded"));
+    }
+    let table = open_gate_table(&source)?;
+
+    // Another thread may have been faster, then its table is kept
+    if GATE_TABLE.set(table).is_err()
+    {
+        return Err(std::io::Error::new(std::io::ErrorKind::AlreadyExists, "the gate table is already loaded"));
+    }
+    return Ok(GATE_TABLE.get().unwrap().as_ref());
+}
 
+// Never fails: a source that cannot be loaded is reported by init_gate_table,
+// and then this falls back to the compiled-in table like when none was chosen
+pub fn gate_table() -> &'static dyn GateLookup
+{
+    return GATE_TABLE.get_or_init(|| Box::new(embedded_residual_table())).as_ref();
 }
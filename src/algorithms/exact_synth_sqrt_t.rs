@@ -0,0 +1,151 @@
+// Exact synthesis for the Clifford+√T gate set
+//
+// This is exact_synth.rs one level up the cyclotomic tower: the entries
+// live in Z[ζ] localized at δ = 1+ζ, where ζ is the sixteenth root of unity,
+// and the gates are H and √T = diag(1,ζ), written Q in gate strings
+//
+// The algorithm is the same. Multiplying by H√T^(-j) for a well chosen j
+// lowers the sde, until nothing is left but a monomial matrix. See
+// arxiv:1501.04944 for why this works for every power of two cyclotomic
+//
+// The sde here is counted in powers of δ and √2 = δ^4 times a unit,
+// so one H can lower it by up to 4
+
+
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::zzeta16::Zzeta16;
+use crate::structs::rings::special_values::sqrt2_zomega;
+use crate::structs::sqrt_t_unimat::SqrtTUniMat;
+use crate::structs::sqrt_t_unimat::ZetaMat;
+use crate::structs::circuit::Circuit;
+use crate::structs::circuit::SqrtTGate;
+
+use num_traits::Zero;
+use num_traits::One;
+
+type ZetaLoc<I = Int> = Local<Zzeta16<I>>;
+
+// X = HZH, and Z = √T^8
+const X_GATE: &str = "HQQQQQQQQH";
+
+
+// Returns H√T^n gamma, on the special unitary part
+// As with multiply_h_times_t_to_n, this is [[1,1],[1,-1]]/√2 times (u, ζ^n t)
+pub fn multiply_h_times_sqrt_t_to_n<I: IntBackend>( gamma: ZetaMat<I>, n: LogDepInt) -> ZetaMat<I>
+{
+    let sqrt2 = ZetaLoc::from_base(Zzeta16::from_zomega(sqrt2_zomega::<I>()));
+
+    let twisted_t = Local
+    {
+        num: gamma.t.num.multiply_by_zeta(n),
+        log_den: gamma.t.log_den
+    };
+    return ZetaMat
+    {
+        u: ( gamma.u.clone()+twisted_t.clone() )/sqrt2.clone(),
+        t: ( gamma.u-twisted_t )/sqrt2
+    };
+}
+
+
+// The main deal
+pub fn exact_synth_sqrt_t<I: IntBackend>( gamma: SqrtTUniMat<I>) -> Circuit<SqrtTGate>
+{
+    let (mut seq, residue) = partial_exact_synth_sqrt_t(gamma.mat.clone());
+    seq.push_str(&monomial_gate_string(residue));
+
+    // As in exact_synth, the special unitary parts now agree
+    // and whatever is left is a power of √T
+    let almost_answer = SqrtTUniMat::<I>::from_string(&seq);
+    let difference = almost_answer.inv()*gamma;
+    for _ in 0..difference.zeta_exp
+    {
+        seq.push_str("Q");
+    }
+    return seq.parse().unwrap();
+}
+
+
+// The same, written in the letters of SqrtTUniMat::from_string
+pub fn exact_synth_sqrt_t_string<I: IntBackend>( gamma: SqrtTUniMat<I>) -> String
+{
+    return exact_synth_sqrt_t(gamma).to_string();
+}
+
+
+// Lowers the sde to 0, one H at a time
+// Returns the gates used, and the monomial matrix that is left
+pub fn partial_exact_synth_sqrt_t<I: IntBackend>( gamma: ZetaMat<I>) -> (String, ZetaMat<I>)
+{
+    if gamma.clone().det() != ZetaLoc::one()
+    {
+        println!("gamma.det was {}", gamma.det());
+        panic!("I was promised norm 1");
+    }
+
+    let mut gate_string = "".to_string();
+    let mut h = gamma;
+    let mut sdeq = h.sde();
+
+    while sdeq > 0
+    {
+        // Take the j that lowers the sde the most
+        let mut best: Option<(LogDepInt, ZetaMat<I>, LogDepInt)> = None;
+        for j in 0..16
+        {
+            let g = multiply_h_times_sqrt_t_to_n(h.clone(), -j);
+            let sdeq_new = g.sde();
+            if sdeq_new < sdeq && best.as_ref().map_or(true, |(_, _, s)| sdeq_new < *s)
+            {
+                best = Some((j, g, sdeq_new));
+            }
+        }
+
+        match best
+        {
+            Some((j, g, sdeq_new)) =>
+            {
+                for _ in 0..j
+                {
+                    gate_string.push_str("Q");
+                }
+                gate_string.push_str("H");
+                h = g;
+                sdeq = sdeq_new;
+            },
+            None => panic!("Could not decrease sdeq"),
+        }
+    }
+
+    return (gate_string, h);
+}
+
+
+// Gates for a special unitary with sde 0
+// Its entries are in Z[ζ] and have norm 0 or 1, so one of them is zero
+// and the other is a power of ζ. As special unitary parts,
+//      X√T^mX   gives   diag(ζ^m, ζ^(-m))
+//      √T^mX    gives   [[0, -ζ^(-m)], [ζ^m, 0]]
+fn monomial_gate_string<I: IntBackend>( residue: ZetaMat<I>) -> String
+{
+    let (entry, is_diagonal) = if residue.t.is_zero() { (residue.u.clone(), true) } else { (residue.t.clone(), false) };
+
+    for m in 0..16
+    {
+        if entry == ZetaLoc::from_base(Zzeta16::one().multiply_by_zeta(m))
+        {
+            let powers: String = "Q".repeat(m as usize);
+            if is_diagonal
+            {
+                return if m == 0 { "".to_string() } else { format!("{}{}{}", X_GATE, powers, X_GATE) };
+            }
+            return format!("{}{}", powers, X_GATE);
+        }
+    }
+
+    println!("LEFT WITH: \n{}", residue);
+    panic!("Not a monomial matrix");
+}
@@ -0,0 +1,104 @@
+// Exact synthesis for the Pauli+V gate set
+//
+// A gate is a Lipschitz quaternion of norm 5^k, and the six V gates and their
+// inverses are the quaternions of norm 5, up to units. Quaternions of odd norm
+// factor uniquely up to units (see arxiv:1303.1411), so at each step exactly
+// one V gate can be peeled off from the left to lower k by one. Whatever is left
+// at k = 0 is a unit quaternion, that is, a Pauli
+//
+// The gate strings use the letters of ExactVUniMat::from_string
+
+
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zi::Zi;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::LogDepInt;
+use crate::structs::v_unimat::ExactVUniMat;
+use crate::structs::circuit::Circuit;
+use crate::structs::circuit::VGate;
+
+use num_traits::One;
+
+type ILoc<I = Int> = Local<Zi<I>>;
+
+const V_GATES: [char; 6] = ['A', 'B', 'C', 'a', 'b', 'c'];
+
+// Between them, these are all eight Paulis with signs, as special unitaries
+const PAULI_WORDS: [&str; 8] = ["", "XX", "X", "XXX", "Y", "YYY", "Z", "ZZZ"];
+
+
+// The main deal
+// Returns a circuit with exactly sde V gates
+pub fn exact_synth_v<I: IntBackend>( gamma: ExactVUniMat<I>) -> Circuit<VGate>
+{
+    let (mut seq, residue) = partial_exact_synth_v(gamma);
+    seq.push_str(&pauli_gate_string(residue));
+    return seq.parse().unwrap();
+}
+
+
+// The same, written in the letters of ExactVUniMat::from_string
+pub fn exact_synth_v_string<I: IntBackend>( gamma: ExactVUniMat<I>) -> String
+{
+    return exact_synth_v(gamma).to_string();
+}
+
+
+// Lowers the sde to 0, one V gate at a time
+// Returns the gates used, and the Pauli that is left
+pub fn partial_exact_synth_v<I: IntBackend>( gamma: ExactVUniMat<I>) -> (String, ExactVUniMat<I>)
+{
+    // The stored matrix has determinant 5^sqrt5_exp
+    let expected_det = ILoc{ num: Zi::one(), log_den: -(gamma.sqrt5_exp as LogDepInt) };
+    if gamma.clone().det() != expected_det
+    {
+        println!("gamma.det was {}", gamma.det());
+        panic!("I was promised norm 1");
+    }
+
+    let mut gate_string = "".to_string();
+    let mut h = gamma;
+    let mut sdeq = h.sde();
+
+    while sdeq > 0
+    {
+        let mut lowered = false;
+        for v in V_GATES
+        {
+            let v_gate = ExactVUniMat::<I>::from_string(&v.to_string());
+            let g = v_gate.inv()*h.clone();
+            if g.sde() < sdeq
+            {
+                gate_string.push(v);
+                h = g;
+                sdeq = sdeq - 1;
+                lowered = true;
+                break;
+            }
+        }
+
+        if !lowered
+        {
+            panic!("Could not decrease sdeq");
+        }
+    }
+
+    return (gate_string, h);
+}
+
+
+// Gates for a special unitary with sde 0
+fn pauli_gate_string<I: IntBackend>( residue: ExactVUniMat<I>) -> String
+{
+    for word in PAULI_WORDS
+    {
+        if ExactVUniMat::<I>::from_string(&word.to_string()) == residue
+        {
+            return word.to_string();
+        }
+    }
+
+    println!("LEFT WITH: \n{}", residue);
+    panic!("Not a Pauli");
+}
@@ -0,0 +1,377 @@
+// Binary files for GateTable
+//
+// The text format of save_hash_table has no header, so a reader cannot tell
+// which generator wrote a file, or whether it was cut short. This format is
+//
+//      magic       4 bytes, "GSGT"
+//      version     2 bytes, little endian
+//      parameters  gate string length, sde bound, gate set
+//      entries     how many, then for each one the word and the 12 numbers
+//                  that save_hash_table writes
+//      checksum    4 bytes, CRC-32 of everything before it, little endian
+//
+// Numbers are varints: 7 bits at a time, lowest first, with the top bit set
+// on every byte but the last. Signed numbers are zigzag encoded first, so
+// that small negative numbers stay short. Strings are their length, then
+// their bytes
+//
+// Reading checks all of the above and that the entries agree with the
+// parameters, and returns a GateTableError instead of panicking
+
+
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::io::BufReader;
+
+// For display
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use num_traits::Zero;
+
+use crate::structs::rings::Int;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::Localizable;
+use crate::algorithms::exact_synth::checked_sde;
+use crate::algorithms::exact_synth_hashtable_lookup::GateTable;
+use crate::algorithms::exact_synth_hashtable_lookup::key_numbers;
+use crate::algorithms::exact_synth_hashtable_lookup::key_from_numbers;
+use crate::algorithms::exact_synth_hashtable_lookup::read_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::RESIDUAL_SDE;
+
+
+pub const MAGIC: [u8; 4] = *b"GSGT";
+pub const FORMAT_VERSION: u16 = 1;
+
+
+// How the table was made
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct TableParameters
+{
+    pub gate_string_length: u32,    // No word is longer
+    pub sde_bound: LogDepInt,       // No key has larger sde
+    pub gate_set: String,           // The letters that words use, besides "I"
+}
+
+impl TableParameters
+{
+    // What generate_residual_table makes, and generate_gate_table writes
+    pub fn residual_table(table: &GateTable) -> Self
+    {
+        let longest = table.values().map(|word| word.len()).max().unwrap_or(0);
+        return Self{ gate_string_length: longest as u32, sde_bound: RESIDUAL_SDE, gate_set: "HST".to_string() };
+    }
+}
+
+
+#[derive(Debug)]
+pub enum GateTableError
+{
+    Io(std::io::Error),
+    BadMagic([u8; 4]),
+    UnsupportedVersion(u16),
+    Truncated,
+    BadVarint,                      // Too long, or too big for its type
+    BadString,                      // Not UTF-8
+    ChecksumMismatch{ stored: u32, computed: u32 },
+    TrailingBytes(usize),
+    BadEntry(String),               // Disagrees with the parameters
+}
+
+impl Display for GateTableError
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result
+    {
+        return match self
+        {
+            GateTableError::Io(error) => write!(f, "could not read the gate table: {}", error),
+            GateTableError::BadMagic(magic) => write!(f, "not a gate table, it starts with {:?}", magic),
+            GateTableError::UnsupportedVersion(version) => write!(f, "gate table format version {} is not supported", version),
+            GateTableError::Truncated => write!(f, "the gate table is cut short"),
+            GateTableError::BadVarint => write!(f, "the gate table has a number that is too long"),
+            GateTableError::BadString => write!(f, "the gate table has a string that is not UTF-8"),
+            GateTableError::ChecksumMismatch{ stored, computed } => write!(f, "the gate table checksum is {:08x}, but the data gives {:08x}", stored, computed),
+            GateTableError::TrailingBytes(count) => write!(f, "the gate table has {} bytes after its last entry", count),
+            GateTableError::BadEntry(message) => write!(f, "bad gate table entry: {}", message),
+        };
+    }
+}
+
+impl std::error::Error for GateTableError {}
+
+impl From<std::io::Error> for GateTableError
+{
+    fn from(error: std::io::Error) -> Self
+    {
+        return GateTableError::Io(error);
+    }
+}
+
+// So that TableSource can load binary files next to text files
+impl From<GateTableError> for std::io::Error
+{
+    fn from(error: GateTableError) -> Self
+    {
+        return match error
+        {
+            GateTableError::Io(error) => error,
+            other => std::io::Error::new(std::io::ErrorKind::InvalidData, other.to_string()),
+        };
+    }
+}
+
+
+// CRC-32 as in zip and png, one bit at a time
+// Tables are read once, so speed does not matter
+pub fn crc32(data: &[u8]) -> u32
+{
+    let mut crc = 0xFFFFFFFFu32;
+    for byte in data
+    {
+        crc = crc ^ (*byte as u32);
+        for _ in 0..8
+        {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB88320 & mask);
+        }
+    }
+    return !crc;
+}
+
+
+pub fn zigzag(x: Int) -> u128
+{
+    return ((x << 1) ^ (x >> 127)) as u128;
+}
+
+pub fn unzigzag(x: u128) -> Int
+{
+    return ((x >> 1) as Int) ^ -((x & 1) as Int);
+}
+
+pub fn push_varint(output: &mut Vec<u8>, mut x: u128)
+{
+    while x >= 0x80
+    {
+        output.push((x as u8) | 0x80);
+        x = x >> 7;
+    }
+    output.push(x as u8);
+}
+
+pub fn push_string(output: &mut Vec<u8>, string: &str)
+{
+    push_varint(output, string.len() as u128);
+    output.extend_from_slice(string.as_bytes());
+}
+
+
+// Reads from the front of a slice
+pub struct ByteReader<'a>
+{
+    pub data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a>
+{
+    pub fn bytes(&mut self, count: usize) -> Result<&'a [u8], GateTableError>
+    {
+        if self.data.len() < count
+        {
+            return Err(GateTableError::Truncated);
+        }
+        let (front, back) = self.data.split_at(count);
+        self.data = back;
+        return Ok(front);
+    }
+
+    pub fn varint(&mut self) -> Result<u128, GateTableError>
+    {
+        let mut output = 0u128;
+        for shift in (0..128).step_by(7)
+        {
+            let byte = self.bytes(1)?[0];
+            let bits = (byte & 0x7F) as u128;
+            if shift == 126 && bits > 3
+            {
+                return Err(GateTableError::BadVarint);
+            }
+            output = output | (bits << shift);
+            if byte & 0x80 == 0
+            {
+                return Ok(output);
+            }
+        }
+        return Err(GateTableError::BadVarint);
+    }
+
+    pub fn small_varint<T: TryFrom<u128>>(&mut self) -> Result<T, GateTableError>
+    {
+        return T::try_from(self.varint()?).map_err(|_| GateTableError::BadVarint);
+    }
+
+    pub fn string(&mut self) -> Result<String, GateTableError>
+    {
+        return Ok(self.str()?.to_string());
+    }
+
+    // The same, without copying
+    pub fn str(&mut self) -> Result<&'a str, GateTableError>
+    {
+        let length: usize = self.small_varint()?;
+        let bytes = self.bytes(length)?;
+        return std::str::from_utf8(bytes).map_err(|_| GateTableError::BadString);
+    }
+}
+
+
+pub fn encode_binary_table(table: &GateTable, parameters: &TableParameters) -> Vec<u8>
+{
+    let mut output = Vec::<u8>::new();
+    output.extend_from_slice(&MAGIC);
+    output.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+
+    push_varint(&mut output, parameters.gate_string_length as u128);
+    push_varint(&mut output, zigzag(parameters.sde_bound as Int));
+    push_string(&mut output, &parameters.gate_set);
+
+    // Sorted, so the same table always gives the same file
+    let mut entries: Vec<([Int; 12], &String)> = table.iter().map(|(key, word)| (key_numbers(key), word)).collect();
+    entries.sort();
+
+    push_varint(&mut output, entries.len() as u128);
+    for (numbers, word) in entries
+    {
+        push_string(&mut output, word);
+        for x in numbers
+        {
+            push_varint(&mut output, zigzag(x));
+        }
+    }
+
+    let checksum = crc32(&output);
+    output.extend_from_slice(&checksum.to_le_bytes());
+    return output;
+}
+
+pub fn decode_binary_table(data: &[u8]) -> Result<(TableParameters, GateTable), GateTableError>
+{
+    // Magic and version first, so that other files get the right error
+    let mut reader = ByteReader{ data };
+    let magic: [u8; 4] = reader.bytes(4)?.try_into().unwrap();
+    if magic != MAGIC
+    {
+        return Err(GateTableError::BadMagic(magic));
+    }
+    let version = u16::from_le_bytes(reader.bytes(2)?.try_into().unwrap());
+    if version != FORMAT_VERSION
+    {
+        return Err(GateTableError::UnsupportedVersion(version));
+    }
+
+    if data.len() < 10
+    {
+        return Err(GateTableError::Truncated);
+    }
+    let (body, checksum) = data.split_at(data.len() - 4);
+    let stored = u32::from_le_bytes(checksum.try_into().unwrap());
+    let computed = crc32(body);
+    if stored != computed
+    {
+        return Err(GateTableError::ChecksumMismatch{ stored, computed });
+    }
+
+    let mut reader = ByteReader{ data: &body[6..] };
+    let parameters = TableParameters
+    {
+        gate_string_length: reader.small_varint()?,
+        sde_bound: LogDepInt::try_from(unzigzag(reader.varint()?)).map_err(|_| GateTableError::BadVarint)?,
+        gate_set: reader.string()?,
+    };
+
+    let count: usize = reader.small_varint()?;
+    let mut table = GateTable::new();
+    for _ in 0..count
+    {
+        let word = reader.string()?;
+        let mut numbers = [0 as Int; 12];
+        for x in numbers.iter_mut()
+        {
+            *x = unzigzag(reader.varint()?);
+        }
+        // The sde multiplies two entries, so their denominators get added
+        for i in [2, 5, 8, 11]
+        {
+            if LogDepInt::try_from(numbers[i]).is_err()
+            {
+                return Err(GateTableError::BadVarint);
+            }
+            if numbers[i].abs() > (LogDepInt::MAX/2) as Int
+            {
+                return Err(GateTableError::BadEntry(format!("the key of \"{}\" has denominator √2^{}", word, numbers[i])));
+            }
+        }
+
+        // Keys are compared as they are, so one matrix written two ways
+        // would be two keys. Only the form that fix gives is accepted
+        let key = key_from_numbers(&numbers);
+        let entries = [key.u.re, key.u.im, key.t.re, key.t.im];
+        if entries.iter().any(|x| if x.num.is_zero() { x.log_den != 0 } else { x.num.is_divisible() })
+        {
+            return Err(GateTableError::BadEntry(format!("the key of \"{}\" is not in lowest terms", word)));
+        }
+
+        if word.len() > parameters.gate_string_length as usize
+        {
+            return Err(GateTableError::BadEntry(format!("\"{}\" is longer than {}", word, parameters.gate_string_length)));
+        }
+        if word.chars().any(|letter| letter != 'I' && !parameters.gate_set.contains(letter))
+        {
+            return Err(GateTableError::BadEntry(format!("\"{}\" is not in the gate set {}", word, parameters.gate_set)));
+        }
+        let key_sde = checked_sde(&key).map_err(|_| GateTableError::BadEntry(format!("the key of \"{}\" is too big", word)))?;
+        if key_sde > parameters.sde_bound
+        {
+            return Err(GateTableError::BadEntry(format!("the key of \"{}\" has sde {}", word, key_sde)));
+        }
+        if table.insert(key, word.clone()).is_some()
+        {
+            return Err(GateTableError::BadEntry(format!("the key of \"{}\" is there twice", word)));
+        }
+    }
+
+    if !reader.data.is_empty()
+    {
+        return Err(GateTableError::TrailingBytes(reader.data.len()));
+    }
+    return Ok((parameters, table));
+}
+
+
+pub fn save_binary_table(table: &GateTable, parameters: &TableParameters, filename: &str) -> Result<(), GateTableError>
+{
+    let mut file = File::create(filename)?;
+    file.write_all(&encode_binary_table(table, parameters))?;
+    return Ok(());
+}
+
+pub fn read_binary_table(filename: &str) -> Result<(TableParameters, GateTable), GateTableError>
+{
+    let mut data = Vec::<u8>::new();
+    BufReader::new(File::open(filename)?).read_to_end(&mut data)?;
+    return decode_binary_table(&data);
+}
+
+// From the text format of save_hash_table
+// The parameters are checked against the table before anything is written
+pub fn convert_text_table(text_filename: &str, binary_filename: &str, parameters: &TableParameters) -> Result<GateTable, GateTableError>
+{
+    let table = read_hash_table(text_filename)?;
+    let data = encode_binary_table(&table, parameters);
+    decode_binary_table(&data)?;
+
+    let mut file = File::create(binary_filename)?;
+    file.write_all(&data)?;
+    return Ok(table);
+}
@@ -0,0 +1,203 @@
+// Gate tables over any set of exact generators
+//
+// generate_table_breadth_first knows that the gates are H, S and T, and that
+// the cost is the T-count. Here the generators are any ExactUniMat, each with
+// a name and a cost, and the words are their names one after the other. To
+// use the table in exact_synth, the names have to be letters of Circuit
+//
+// The search is Dijkstra's on words: the cheapest word not looked at yet is
+// taken next, and a word whose unitary already has a cheaper word (or an
+// equal one that is shorter, or first in alphabetical order) is a
+// relation, and nothing is built on it. So HH = I and T^8 = I, which used to
+// be written into the generator by hand, are found like all the others, and
+// so are the ones that only hold for the given costs, like TT = S
+//
+// Unitaries are compared with their phase, and the table is keyed by their
+// matrix part like every other table. Each matrix with sde at most the
+// cutoff keeps the first word that reaches it, which is one of least cost
+
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::BinaryHeap;
+use std::cmp::Reverse;
+
+use crate::structs::rings::Int;
+use crate::structs::rings::LogDepInt;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Gate;
+use crate::algorithms::exact_synth::sde;
+use crate::algorithms::exact_synth_hashtable_lookup::GateTable;
+use crate::algorithms::exact_synth_hashtable_lookup::key_numbers;
+
+
+pub type Cost = u32;
+
+// A word and a cheaper word for the same unitary
+pub type Relation = (String, String);
+
+
+#[derive(Debug,Clone)]
+pub struct Generator
+{
+    pub name: String,
+    pub gate: ExactUniMat<Int>,
+    pub cost: Cost,
+}
+
+impl Generator
+{
+    pub fn new(name: &str, gate: ExactUniMat<Int>, cost: Cost) -> Self
+    {
+        return Self{ name: name.to_string(), gate, cost };
+    }
+
+    // Named by its letter, so that words parse as circuits
+    pub fn from_gate(gate: Gate, cost: Cost) -> Self
+    {
+        return Self::new(&gate.letter().to_string(), gate.to_exact_unimat(), cost);
+    }
+}
+
+// H, S and T, costing the T-count
+pub fn clifford_t_generators() -> Vec<Generator>
+{
+    return vec![Generator::from_gate(Gate::H, 0), Generator::from_gate(Gate::S, 0), Generator::from_gate(Gate::T, 1)];
+}
+
+
+pub struct GeneratorSearch
+{
+    pub sde_cutoff: LogDepInt,      // Only matrices with at most this sde are kept
+    pub max_cost: Cost,             // Words that cost more are not looked at
+    pub report_progress: bool,      // Print a line each time the cost goes up
+}
+
+
+type UnitaryKey = ([Int; 12], u8);
+type Entry = (Cost, usize, String);     // Cost, length, word, in the order they are compared
+
+fn unitary_key(gate: &ExactUniMat<Int>) -> UnitaryKey
+{
+    return (key_numbers(&gate.mat), gate.omega_exp%8);
+}
+
+// The search only ends if the generators that cost nothing make a finite
+// group. Such a group, with its phases, has at most 192 elements: it is
+// finite in SO(3) times at most the 8 phases ω^k, and the trace 1+2cos(θ)
+// of a rotation in it is in Q(√2), which leaves rotations of order 1, 2, 3,
+// 4, 6 and 8. The finite subgroups of SO(3) made of those have at most 24
+// elements, like the Clifford group, which has all 192
+pub fn free_generators_are_finite(generators: &[Generator]) -> bool
+{
+    let free: Vec<&Generator> = generators.iter().filter(|g| g.cost == 0).collect();
+    let mut group = HashSet::from([unitary_key(&ExactUniMat::<Int>::one())]);
+    let mut frontier = vec![ExactUniMat::<Int>::one()];
+    while let Some(gate) = frontier.pop()
+    {
+        for generator in free.iter()
+        {
+            let new_gate = gate*generator.gate;
+            if group.insert(unitary_key(&new_gate))
+            {
+                if group.len() > 192
+                {
+                    return false;
+                }
+                frontier.push(new_gate);
+            }
+        }
+    }
+    return true;
+}
+
+// Calls found for every unitary with its cheapest word, in order of cost,
+// and returns the relations it came across
+//
+// A unitary is remembered with the best word pushed for it so far, and a word
+// that is not better than that one is a relation and never goes in the queue
+fn search<F: FnMut(&ExactUniMat<Int>, &str)>(generators: &[Generator], max_cost: Cost, report_progress: bool, mut found: F) -> Vec<Relation>
+{
+    assert!( free_generators_are_finite(generators), "The generators of cost 0 make an infinite group, so the search would never end" );
+
+    let mut best = HashMap::<UnitaryKey, Entry>::new();
+    let mut relations = Vec::<Relation>::new();
+    let mut reached = 0;
+
+    // Gates are kept next to the heap, which only has what orders the words
+    let mut gates = vec![ExactUniMat::<Int>::one()];
+    let mut queue = BinaryHeap::from([Reverse((0 as Cost, 0usize, String::new(), 0usize))]);
+    best.insert(unitary_key(&gates[0]), (0, 0, String::new()));
+    let mut current_cost = 0;
+
+    while let Some(Reverse((cost, length, word, index))) = queue.pop()
+    {
+        if report_progress && cost > current_cost
+        {
+            println!("Cost {}: {} unitaries, {} relations", current_cost, reached, relations.len());
+        }
+        current_cost = cost;
+
+        // A better word came along after this one was pushed
+        let gate = gates[index];
+        if best[&unitary_key(&gate)].2 != word
+        {
+            continue;
+        }
+        found(&gate, &word);
+        reached = reached + 1;
+
+        for generator in generators.iter()
+        {
+            let new_cost = cost + generator.cost;
+            if new_cost > max_cost
+            {
+                continue;
+            }
+            let new_gate = gate*generator.gate;
+            let new_entry = (new_cost, length + 1, word.clone() + &generator.name);
+            let new_key = unitary_key(&new_gate);
+            match best.get(&new_key)
+            {
+                Some(old) if *old <= new_entry =>
+                {
+                    relations.push((new_entry.2, old.2.clone()));
+                    continue;
+                }
+                Some(old) => relations.push((old.2.clone(), new_entry.2.clone())),
+                None => (),
+            }
+            gates.push(new_gate);
+            queue.push(Reverse((new_entry.0, new_entry.1, new_entry.2.clone(), gates.len() - 1)));
+            best.insert(new_key, new_entry);
+        }
+    }
+
+    if report_progress
+    {
+        println!("Cost {}: {} unitaries, {} relations", current_cost, reached, relations.len());
+    }
+    return relations;
+}
+
+
+pub fn generate_table_with_generators(generators: &[Generator], settings: &GeneratorSearch) -> GateTable
+{
+    let mut table = GateTable::new();
+    search(generators, settings.max_cost, settings.report_progress, |gate, word|
+    {
+        if sde(gate.mat) <= settings.sde_cutoff && !table.contains_key(&gate.mat)
+        {
+            let value = if word.is_empty() { "I".to_string() } else { word.to_string() };
+            table.insert(gate.mat, value);
+        }
+    });
+    return table;
+}
+
+// The relations among words of cost at most max_cost, in the order they were
+// found. Each one has a word that is not itself built on a relation
+pub fn discover_relations(generators: &[Generator], max_cost: Cost) -> Vec<Relation>
+{
+    return search(generators, max_cost, false, |_, _| ());
+}
@@ -2,8 +2,14 @@
 // This is the content of 1403.2975v3 Section 7.3 on page 14
 
 
+use crate::structs::rings::GaloisAutomorphism;
 use crate::structs::rings::Float;
 use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::CheckedRing;
+use crate::structs::rings::CheckedNorm;
+use crate::structs::rings::ArithmeticOverflow;
 use crate::structs::rings::local_ring::Local;
 use crate::structs::rings::zroot2::Zroot2;
 use crate::structs::rings::LogDepInt;
@@ -22,7 +28,6 @@ use crate::algorithms::lll::gram_schmidt_orthogonalization;
 use crate::algorithms::exact_synth::exact_synth;
 
 use num_traits::Pow;
-use num_traits::pow;
 use num_traits::One;
 use nalgebra::linalg::QR;
 
@@ -33,16 +38,19 @@ use rand::Rng;
 
 // This will be rounded down to 64 or 128 bits
 // For bigger float, add more digits please
+// How far outside the region a float may land before we trust it is really outside
+const DISK_TEST_SLACK: Float = 1e-9;
+
 pub const SQRT2:Float = 1.414213562373095048801688724209698078569671875376948073176679737990732478462;
 
 type Comp = num_complex::Complex<Float>;
-type Loc = Local::<Zroot2>;
-type CompLoc = num_complex::Complex<Loc>;
+type Loc<I = Int> = Local::<Zroot2<I>>;
+type CompLoc<I = Int> = num_complex::Complex<Loc<I>>;
 type Mat2 = nalgebra::Matrix2<Float>;
 type Mat4 = nalgebra::Matrix4<Float>;
 type Mat4Int = nalgebra::Matrix4<Int>;
 type Vec4 = nalgebra::Matrix4x1<Float>;
-type Vec4Int = nalgebra::Matrix4x1<Int>;
+type Vec4Int<I = Int> = nalgebra::Matrix4x1<I>;
 use crate::structs::sunimat::SUniMat;
 type Sunimat = SUniMat<Float>;
 type Sunimatloc = SUniMat<Loc>;
@@ -126,12 +134,12 @@ pub fn find_operator_norm(input : Mat4) -> Float
 }
 
 
-pub fn extract_gate_coordinate_in_local_ring(integer_coords: Vec4Int) -> Option::<(Loc,Loc)>
+pub fn extract_gate_coordinate_in_local_ring<I: IntBackend>(integer_coords: Vec4Int<I>) -> Option::<(Loc<I>,Loc<I>)>
 {
     // println!("attempting_to_figure_out with {}", integer_coords );
 
-    let zrt_left = Zroot2(integer_coords[0],integer_coords[1]);
-    let zrt_right = Zroot2(integer_coords[2],integer_coords[3]);
+    let zrt_left = Zroot2(integer_coords[0].clone(),integer_coords[1].clone());
+    let zrt_right = Zroot2(integer_coords[2].clone(),integer_coords[3].clone());
 
     // We throw away the points if they were covered while checking smaller values of exactlogdep
     if zrt_left.is_divisible() && zrt_right.is_divisible()
@@ -146,35 +154,37 @@ pub fn extract_gate_coordinate_in_local_ring(integer_coords: Vec4Int) -> Option:
 
 
 
-pub fn get_comp_point_from_integer_coord( this_point: Vec4Int, exactlogdep : LogDepInt) -> (Comp, Comp)
+// The point is ( (a+b√2) + (c+d√2)i )/√2^exactlogdep
+pub fn get_exact_point_from_integer_coord<I: IntBackend>( this_point: Vec4Int<I>, exactlogdep : LogDepInt) -> CompLoc<I>
 {
-
-    // println!("{}", this_point);
-    // println!("{}", coordinate_basis);
-
-    // take the input and mutiply coordinate change matrix
-    // and scale the vector by sqrt2^exactlogdep
-    // This will give the actual point in 4d-space
-    let actual_point = vec4int_to_vec4float(this_point)*SQRT2.pow(-exactlogdep);
-
-    // println!("{}", actual_point);
-
-    let complex_point = Comp
-    { 
-        re: actual_point[0] + actual_point[1]*SQRT2,
-        im: actual_point[2] + actual_point[3]*SQRT2,
+    let scaling = Loc::<I>{ num: Zroot2::one(), log_den: exactlogdep };
+    return CompLoc
+    {
+        re: Loc::from_base(Zroot2(this_point[0].clone(), this_point[1].clone()))*scaling.clone(),
+        im: Loc::from_base(Zroot2(this_point[2].clone(), this_point[3].clone()))*scaling,
     };
+}
 
-    // Is this correct?
-    // Depends on the function 
-    let complex_point_dot_conj = Comp
-    { 
-        re: actual_point[0] - actual_point[1]*SQRT2,
-        im: actual_point[2] - actual_point[3]*SQRT2,
-    };
+// Its two embeddings give the point and its dot conjugate
+pub fn get_comp_point_from_integer_coord<I: IntBackend>( this_point: Vec4Int<I>, exactlogdep : LogDepInt) -> (Comp, Comp)
+{
+    return get_exact_point_from_integer_coord(this_point, exactlogdep).embeddings();
+}
 
-    return (complex_point, complex_point_dot_conj);
 
+// The half plane test of test_this_complex_pair_of_points, without floats
+// direction and epsilon_a are floats, so they are dyadic rationals and
+// Local<Zroot2> holds them exactly. This is done over BigInt, since it
+// only runs for points that passed the float test
+pub fn is_in_epsilon_region<I: IntBackend>( this_point: Vec4Int<I>, exactlogdep: LogDepInt, (direction, epsilon_a): GridParams) -> bool
+{
+    let point = get_exact_point_from_integer_coord(this_point.map(|x| x.to_bigint()), exactlogdep);
+    let exact_parameters = ( Loc::<BigInt>::from_float_exactly(direction.re), Loc::<BigInt>::from_float_exactly(direction.im), Loc::<BigInt>::from_float_exactly(epsilon_a) );
+    match exact_parameters
+    {
+        (Some(e_1), Some(e_2), Some(epsilon)) => return point.re*e_1 + point.im*e_2 > Loc::one() - epsilon,
+        _ => return false,
+    }
 }
 
 
@@ -182,15 +192,20 @@ pub fn get_comp_point_from_integer_coord( this_point: Vec4Int, exactlogdep : Log
 // That is, if it roughly lies in the region close to the required place,,
 // Here we make sure that it is truly within the region we want
 // and only then we take the trouble of all the prime factorization
+//
+// All three tests are only a float prefilter, so they are a bit lenient.
+// The exact decisions are is_in_epsilon_region, which consider does next,
+// and is_doubly_positive(1-|z|^2), which is done in
+// attempt_to_figure_out_gate_given_that_this_point_is_feasible
 pub fn test_this_complex_pair_of_points( complex_point: Comp , complex_point_dot_conj: Comp, (direction, epsilon_a): GridParams) -> bool
 {
 
-    if complex_point.norm_sqr() <= 1.0
+    if complex_point.norm_sqr() <= 1.0 + DISK_TEST_SLACK
     {
-        if complex_point_dot_conj.norm_sqr() <= 1.0
+        if complex_point_dot_conj.norm_sqr() <= 1.0 + DISK_TEST_SLACK
         {
             // println!("Norm squared in control ");
-            if complex_point.re*direction.re+complex_point.im*direction.im > 1.0- epsilon_a
+            if complex_point.re*direction.re+complex_point.im*direction.im > 1.0- epsilon_a - DISK_TEST_SLACK
             {
                 // println!("Test passed");
                 return true;
@@ -218,7 +233,7 @@ pub fn test_this_complex_pair_of_points( complex_point: Comp , complex_point_dot
 
 
 
-pub fn make_exact_gate_from(left: Loc ,right: Loc, left_scaled: Loc, right_scaled: Loc) -> ExactUniMat
+pub fn make_exact_gate_from<I: IntBackend>(left: Loc<I> ,right: Loc<I>, left_scaled: Loc<I>, right_scaled: Loc<I>) -> ExactUniMat<I>
 {
     // println!("sum_of_all_squares = {}", left*left + right*right +left_scaled*left_scaled + right_scaled*right_scaled );
     return ExactUniMat
@@ -233,80 +248,75 @@ pub fn make_exact_gate_from(left: Loc ,right: Loc, left_scaled: Loc, right_scale
 }
 
 
-pub fn is_doubly_positive( num : Loc) -> bool
+pub fn is_doubly_positive<I: IntBackend>( num : Loc<I>) -> bool
 {
-    let x = num.num;
-
-    let x0 = x.0 as Float;
-    let x1 = x.1 as Float;
-
-    let r1 = x0 + SQRT2 * x1;
-    let r2 = x0 - SQRT2 * x1;
-
-    if r1 >= 0.0 && r2 >= 0.0
-    {
-        return true;
-    }
-    else 
-    {
-        return false
-    }
-
-
+    // Exact, there are no floats involved
+    return num.is_doubly_positive();
 }
 
 // collection.push(Vec4Int::new( i1, i2, i3, i4));
 // Return a complete unitary, if it can be returned
 // Else return none
-pub fn attempt_to_figure_out_gate_given_that_this_point_is_feasible( this_point: Vec4Int, exactlogdep: LogDepInt) -> Option::<ExactUniMat>
+//
+// All the arithmetic here is checked. If the candidate is too big for the
+// integer backend, we get an Err and the caller can skip it
+pub fn attempt_to_figure_out_gate_given_that_this_point_is_feasible<I: IntBackend>( this_point: Vec4Int<I>, exactlogdep: LogDepInt) -> Result::<Option::<ExactUniMat<I>>, ArithmeticOverflow>
 {
 
     let get_coord =  extract_gate_coordinate_in_local_ring(this_point);
     if get_coord == None
     {
-        return None;
+        return Ok(None);
     }
     else
     {
         let (left_raw,right_raw) = get_coord.unwrap();
 
 
-        let left_scaled = left_raw * pow(Loc::one() / sqrt2loc(), exactlogdep.try_into().unwrap() );
-        let right_scaled = right_raw * pow(Loc::one() / sqrt2loc(), exactlogdep.try_into().unwrap() );
+        let left_scaled = left_raw * sqrt2loc().pow(-exactlogdep);
+        let right_scaled = right_raw * sqrt2loc().pow(-exactlogdep);
 
-        let our_num = Loc::one() - left_scaled*left_scaled - right_scaled*right_scaled;
+        let left_scaled_squared = left_scaled.checked_mul(&left_scaled)?;
+        let right_scaled_squared = right_scaled.checked_mul(&right_scaled)?;
+        let our_num = Loc::one().checked_sub(&left_scaled_squared)?.checked_sub(&right_scaled_squared)?;
 
 
         // preliminary test before sending off to computationally expensive prime numbers
         // Based on Lemma 6.1 of 1403.2975
-        if (! is_doubly_positive( our_num )) 
+        if (! is_doubly_positive( our_num.clone() )) 
         {
-            return None;
+            return Ok(None);
         }
 
-        let sum_of_square = attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num);
+        // Prime factorization starts with the norm
+        // Everything after that is a factor of our_num, so this is the biggest number we see
+        our_num.checked_norm()?;
+
+        let sum_of_square = attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num.clone())?;
 
         if sum_of_square != None
         {
             let (left,right) = sum_of_square.unwrap();
 
             // DEBUG ZONE
-            if ( left*left+right*right != our_num )
+            let left_squared = left.checked_mul(&left)?;
+            let right_squared = right.checked_mul(&right)?;
+            if ( left_squared.checked_add(&right_squared)? != our_num )
             {
                 println!("left = {}", left);
                 println!("right = {}", right);
                 println!("our_num = {}", our_num);
                 panic!("Sum of squares are wrong");
             }
-            assert!( left*left+right*right + left_scaled * left_scaled + right_scaled * right_scaled == Loc::one() );
+            assert!( our_num + left_scaled_squared + right_scaled_squared == Loc::one() );
             // END OF DEBUG ZONE
 
-            return Some(make_exact_gate_from(left,right,left_scaled,right_scaled));
+            return Ok(Some(make_exact_gate_from(left,right,left_scaled,right_scaled)));
         }
         else
         {   
             // Nothing to check
-            return None;
+            return Ok(None);
         }
 
     }
@@ -315,22 +325,26 @@ pub fn attempt_to_figure_out_gate_given_that_this_point_is_feasible( this_point:
 }
 
 
-pub fn consider( this_point: Vec4Int, exactlogdep: LogDepInt , ( direction_of_rotation, epsilon_a): GridParams ) -> Option::<ExactUniMat>
+pub fn consider<I: IntBackend>( this_point: Vec4Int<I>, exactlogdep: LogDepInt , ( direction_of_rotation, epsilon_a): GridParams ) -> Result::<Option::<ExactUniMat<I>>, ArithmeticOverflow>
 {
 
-    let (complex_point, complex_point_dot_conj) = get_comp_point_from_integer_coord(this_point, exactlogdep);
+    let (complex_point, complex_point_dot_conj) = get_comp_point_from_integer_coord(this_point.clone(), exactlogdep);
 
 
 
     if test_this_complex_pair_of_points( complex_point, complex_point_dot_conj,( direction_of_rotation, epsilon_a ) )
     {
+        if !is_in_epsilon_region( this_point.clone(), exactlogdep, ( direction_of_rotation, epsilon_a ) )
+        {
+            return Ok(None);
+        }
 
         // println!("Working Complex point is : {}", complex_point);
         return attempt_to_figure_out_gate_given_that_this_point_is_feasible( this_point , exactlogdep);
     }
     else
     {
-        return None;
+        return Ok(None);
     }
 }
 
@@ -476,7 +490,8 @@ pub fn generate_coordinates_and_center(direction : Comp , epsilon_a: Float) -> (
 // This is an implementation of Proposition 5.22
 // as in it does the job of Proposition 5.22
 // the way it does it is mostly Nihar's invention
-pub fn grid_problem_given_depth( exactlogdep: LogDepInt, (direction,epsilon) :GridParams ) -> Option::<ExactUniMat>
+// Candidates that overflow the integer backend are skipped and counted in overflow_count
+pub fn grid_problem_given_depth<I: IntBackend>( exactlogdep: LogDepInt, (direction,epsilon) :GridParams, overflow_count: &mut usize ) -> Option::<ExactUniMat<I>>
 {
     // Second attempt to write a function based on LLL
 
@@ -539,7 +554,7 @@ pub fn grid_problem_given_depth( exactlogdep: LogDepInt, (direction,epsilon) :Gr
     //    // Instead of this, we could have a rust thread
     //    // stream out lattice points by communicating messages.
     //    // and in parallel another one testing that it works
-    let possible_output =  test_integer_points_in_ball_around_integer_center_of_radius(new_radius, new_center_in_new_int, new_int_to_standard_int, exactlogdep,  (direction,epsilon));
+    let possible_output =  test_integer_points_in_ball_around_integer_center_of_radius(new_radius, new_center_in_new_int, new_int_to_standard_int, exactlogdep,  (direction,epsilon), overflow_count);
     return possible_output;
 
 }
@@ -547,20 +562,32 @@ pub fn grid_problem_given_depth( exactlogdep: LogDepInt, (direction,epsilon) :Gr
 
 
 
-pub fn grid_problem( direction: Comp, epsilon_a: Float)-> ExactUniMat
+// Candidates that overflow are skipped silently
+// Use grid_problem_counting_overflows to see how many there were
+pub fn grid_problem<I: IntBackend>( direction: Comp, epsilon_a: Float)-> ExactUniMat<I>
+{
+    return grid_problem_counting_overflows(direction, epsilon_a).0;
+}
+
+
+// Same as grid_problem, but also returns how many lattice candidates
+// were thrown away because the arithmetic overflowed
+pub fn grid_problem_counting_overflows<I: IntBackend>( direction: Comp, epsilon_a: Float)-> (ExactUniMat<I>, usize)
 {
     let problem_parameters = ( direction, epsilon_a*epsilon_a/2.0);
 
-    let mut answer: Option::<ExactUniMat>;
+    let mut answer: Option::<ExactUniMat<I>>;
+    let mut overflow_count = 0;
 
-    let maxdepth = 60;
+    // Epsilon around 1e-6 is only reached at depths in the 70s and 80s
+    let maxdepth = 140;
 
     for i in 0..maxdepth
     {
 
         // println!("--------------- \n \n ");
         // println!("Working with depth {}",i );
-        let answer = grid_problem_given_depth(i, problem_parameters);
+        let answer = grid_problem_given_depth(i, problem_parameters, &mut overflow_count);
         if answer != None
         {
             let gate = answer.unwrap();
@@ -568,7 +595,7 @@ pub fn grid_problem( direction: Comp, epsilon_a: Float)-> ExactUniMat
             println!("Found a candidate at depth {}",i );
             println!("which is \n {}", gate);
 
-            return(gate);
+            return (gate, overflow_count);
         }
         // println!("--------------- \n \n ");
     }
@@ -577,7 +604,7 @@ pub fn grid_problem( direction: Comp, epsilon_a: Float)-> ExactUniMat
 }
 
 
-pub fn grid_problem_given_theta_and_epsilon( theta: Float, epsilon : Float ) -> ExactUniMat
+pub fn grid_problem_given_theta_and_epsilon<I: IntBackend>( theta: Float, epsilon : Float ) -> ExactUniMat<I>
 {
     let x = Comp::new(0.0, theta);
     return grid_problem( x.exp() , epsilon);
@@ -588,7 +615,7 @@ pub fn grid_problem_given_theta_and_epsilon( theta: Float, epsilon : Float ) ->
 
 
 // To be replaced by some multithreading implimentation
-pub fn test_integer_points_in_ball_around_integer_center_of_radius(radius: Float, int_center: Vec4Int,  lattice_automorphism: Mat4Int,  exactlogdep: LogDepInt,  (direction_of_rotation, epsilon_a) : GridParams )  -> Option<ExactUniMat>
+pub fn test_integer_points_in_ball_around_integer_center_of_radius<I: IntBackend>(radius: Float, int_center: Vec4Int,  lattice_automorphism: Mat4Int,  exactlogdep: LogDepInt,  (direction_of_rotation, epsilon_a) : GridParams, overflow_count: &mut usize )  -> Option<ExactUniMat<I>>
 {
     let mut collection = Vec::<Vec4Int>::new();
 
@@ -748,13 +775,15 @@ pub fn test_integer_points_in_ball_around_integer_center_of_radius(radius: Float
                     while let Some(last_point) = collection.pop() 
                     {
 
-                        let int_standard = lattice_automorphism * (int_center + last_point);
-
-                        let mut possible_answer =  consider( int_standard, exactlogdep,  (direction_of_rotation, epsilon_a));
+                        let possible_answer = to_standard_coordinates::<I>(&lattice_automorphism, &(int_center + last_point))
+                            .and_then(|int_standard| consider( int_standard, exactlogdep,  (direction_of_rotation, epsilon_a)));
 
-                        if possible_answer != None
+                        match possible_answer
                         {
-                            return possible_answer;
+                            Ok(Some(answer)) => return Some(answer),
+                            Ok(None) => {},
+                            // Too big for the integer backend, move on
+                            Err(ArithmeticOverflow) => *overflow_count += 1,
                         }
 
                     }
@@ -782,6 +811,21 @@ pub fn test_integer_points_in_ball_around_integer_center_of_radius(radius: Float
     // return collection;
 }
 
+// lattice_automorphism * new_int, in the integer backend with checked arithmetic
+fn to_standard_coordinates<I: IntBackend>(lattice_automorphism: &Mat4Int, new_int: &Vec4Int) -> Result<Vec4Int<I>, ArithmeticOverflow>
+{
+    let mut output = Vec4Int::<I>::from_element(I::zero());
+    for row in 0..4
+    {
+        for column in 0..4
+        {
+            let term = I::from_int(lattice_automorphism[(row, column)]).checked_mul(&I::from_int(new_int[column]))?;
+            output[row] = output[row].checked_add(&term)?;
+        }
+    }
+    return Ok(output);
+}
+
 // This piece of code was generated by sagemath using matrices over symbolic ring
 // It is basically the formula of the adjugate of a matrix
 // What I really want to take matrix inverse of 4x4 matrix without losing integer precision
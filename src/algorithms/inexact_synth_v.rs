@@ -0,0 +1,136 @@
+// Approximating z-rotations with the Pauli+V gate set
+//
+// This is grid_problem_given_depth in inexact_synth.rs for ExactVUniMat
+// A gate of sde k is (u, t)/√5^k with u, t Gaussian integers and
+// |u|^2+|t|^2 = 5^k. We want u/√5^k in region A, the thin cap of the
+// unit disk around the direction of rotation
+//
+// Unlike the Clifford+T case there is no √2-conjugate to keep small,
+// so the grid problem is two dimensional and the lattice is just Z[i].
+// We scan the points of Z[i] in the ellipse around region A, and for each
+// one try to write 5^k-|u|^2 as |t|^2
+
+
+use crate::structs::rings::Float;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::checked_pow;
+use crate::structs::rings::zi::Zi;
+use crate::structs::v_unimat::ExactVUniMat;
+
+use crate::algorithms::inexact_synth::ellipse_parameters_for_region_a;
+use crate::algorithms::local_prime_factorization::attempt_to_write_integer_as_sum_of_two_squares;
+
+type Comp = num_complex::Complex<Float>;
+
+type GridParams = (Comp, Float);
+
+// Beyond this 5^k no longer fits in the u128 the factorization uses
+const MAXDEPTH: LogDepInt = 55;
+
+
+// Finds a gate of sde exactly exactlogdep whose top left entry is in region A
+// Candidates that overflow the integer backend are skipped and counted in overflow_count
+pub fn grid_problem_v_given_depth<I: IntBackend>( exactlogdep: LogDepInt, (direction,epsilon) :GridParams, overflow_count: &mut usize ) -> Option::<ExactVUniMat<I>>
+{
+    let five_to_the_k = match checked_pow(&I::from_int(5), exactlogdep as Int)
+    {
+        Ok(x) => x,
+        Err(_) =>
+        {
+            *overflow_count += 1;
+            return None;
+        }
+    };
+    let scale = (5.0 as Float).powi(exactlogdep).sqrt();
+
+    // The ellipse is |M(p-center)|^2 <= radius, see ellipse_parameters_for_region_a
+    let (center, sqrt_of_form, radius) = ellipse_parameters_for_region_a(direction, epsilon);
+    let form = sqrt_of_form*sqrt_of_form;
+    let (q00, q01, q11) = (form[(0,0)], form[(0,1)], form[(1,1)]);
+    let half_width = (radius*q11/form.determinant()).sqrt();
+
+    let a_min = ((center.re - half_width)*scale).floor() as Int;
+    let a_max = ((center.re + half_width)*scale).ceil() as Int;
+
+    for a in a_min..=a_max
+    {
+        // Solving q11 y^2 + 2 q01 x y + q00 x^2 <= radius for y
+        let x = a as Float/scale - center.re;
+        let discriminant = q01*q01*x*x - q11*(q00*x*x - radius);
+        if discriminant < 0.0
+        {
+            continue;
+        }
+        let y_min = (-q01*x - discriminant.sqrt())/q11 + center.im;
+        let y_max = (-q01*x + discriminant.sqrt())/q11 + center.im;
+
+        for b in ((y_min*scale).floor() as Int)..=((y_max*scale).ceil() as Int)
+        {
+            let answer = consider_v::<I>(a, b, exactlogdep, five_to_the_k.clone(), scale, (direction, epsilon));
+            if answer.is_some()
+            {
+                return answer;
+            }
+        }
+    }
+    return None;
+}
+
+
+// Tests if (a+bi)/√5^k is in region A, and if so, tries to complete it to a gate
+fn consider_v<I: IntBackend>( a: Int, b: Int, exactlogdep: LogDepInt, five_to_the_k: I, scale: Float, (direction, epsilon_a): GridParams) -> Option::<ExactVUniMat<I>>
+{
+    if (a as Float*direction.re + b as Float*direction.im)/scale <= 1.0 - epsilon_a
+    {
+        return None;
+    }
+
+    let u = Zi(I::from_int(a), I::from_int(b));
+    let remaining = five_to_the_k - u.norm();
+    if remaining.is_negative()
+    {
+        return None;
+    }
+
+    let t = attempt_to_write_integer_as_sum_of_two_squares(remaining)?;
+    return Some(ExactVUniMat::from_gaussian_entries(u, t, exactlogdep));
+}
+
+
+// Finds a Pauli+V gate whose top left entry is within epsilon_a of direction
+// Same conventions as grid_problem, and the V-count is as small as this search allows
+pub fn grid_problem_v<I: IntBackend>( direction: Comp, epsilon_a: Float)-> ExactVUniMat<I>
+{
+    return grid_problem_v_counting_overflows(direction, epsilon_a).0;
+}
+
+
+// Same as grid_problem_v, but also returns how many depths
+// were skipped because the arithmetic overflowed
+pub fn grid_problem_v_counting_overflows<I: IntBackend>( direction: Comp, epsilon_a: Float)-> (ExactVUniMat<I>, usize)
+{
+    let problem_parameters = ( direction, epsilon_a*epsilon_a/2.0);
+    let mut overflow_count = 0;
+
+    for i in 0..MAXDEPTH
+    {
+        let answer = grid_problem_v_given_depth(i, problem_parameters, &mut overflow_count);
+        if let Some(gate) = answer
+        {
+            println!("Found a candidate at depth {}",i );
+            println!("which is \n {}", gate);
+            return (gate, overflow_count);
+        }
+    }
+
+    panic!("Nothing found under depth {}, and {} depths overflowed", MAXDEPTH, overflow_count);
+}
+
+
+pub fn grid_problem_v_given_theta_and_epsilon<I: IntBackend>( theta: Float, epsilon : Float ) -> ExactVUniMat<I>
+{
+    let x = Comp::new(0.0, theta);
+    return grid_problem_v( x.exp() , epsilon);
+}
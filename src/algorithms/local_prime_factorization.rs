@@ -11,21 +11,31 @@
 use num_traits::One;
 use num_traits::pow;
 use num_traits::Zero;
-use num_traits::NumCast;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num::Integer;
 
-use std::ops::Rem;
 
 use prime_factorization::Factorization;
 
 
 
 use crate::structs::rings::Int; 
+use crate::structs::rings::IntBackend; 
 use crate::structs::rings::Float; 
 use crate::structs::rings::LogDepInt; 
 use crate::structs::rings::LocalizableNorm; 
+use crate::structs::rings::EuclideanDomain; 
+use crate::structs::rings::CheckedRing; 
+use crate::structs::rings::CheckedNorm; 
+use crate::structs::rings::ArithmeticOverflow; 
+use crate::structs::rings::BigInt; 
+use crate::structs::rings::checked_pow; 
+use crate::structs::rings::Conj; 
 use crate::structs::rings::local_ring::Local; 
 use crate::structs::rings::zomega::Zomega;
 use crate::structs::rings::zroot2::Zroot2; 
+use crate::structs::rings::zi::Zi;
 use crate::structs::rings::special_values::sqrt2loc;
 use crate::structs::rings::special_values::onebyroot2loc;
 use crate::structs::rings::special_values::omega;
@@ -34,36 +44,17 @@ use crate::structs::rings::special_values::sqrt2_zomega;
 
 use crate::algorithms::inexact_synth::SQRT2;
 
-type Loc = Local::<Zroot2>;
+type Loc<I = Int> = Local::<Zroot2<I>>;
 
-type FactorInt = u128;
+pub type FactorInt = BigInt;
 type FactorPowerInt = u32;
 
 
-// Generic function to compute gcd given that there is a remainder operation
-// Generated by ChatGPT for integers, modified by yours truly
+// Normalized gcd, see EuclideanDomain in structs/rings/mod.rs
 pub fn compute_gcd<T>(x: T, y: T) -> T 
-where T: Rem<Output=T>+Copy+Zero+PartialEq
+where T: EuclideanDomain
 {
-    // Initialize a and b to x and y, respectively
-    let (mut a, mut b) = (x, y);
-
-    // Repeatedly apply the Euclidean algorithm until the remainder is 0
-    while b != T::zero()
-    {
-
-        // Divide a by b and find the remainder
-        // CAUTION: a % b does not exist uniquely
-        //          for Zroot2. It is just some number 
-        //          r such that b=a*q+r
-        let remainder = a % b;
-
-        // Replace a with b and b with the remainder
-        a = b;
-        b = remainder;
-    }
-
-    return a;
+    return x.gcd(&y);
 }
 
 
@@ -90,17 +81,26 @@ pub fn power_mod_p(xorig: Int, m: Int, p: Int ) -> Int
     {
         if n & Int::one() == Int::one()
         {
-            result *= x;
-            result = result %p;
+            result = mul_mod(result, x, p);
         }
-        x *= x%p;
-        x = x%p;
+        x = mul_mod(x, x, p);
         n >>= 1;
     }
 
     return result;
 }
 
+// a*b mod p for 0 <= a,b < p
+// Once p passes 2^63 the product no longer fits in Int, so it goes through BigInt
+pub fn mul_mod(a: Int, b: Int, p: Int) -> Int
+{
+    return match a.checked_mul(b)
+    {
+        Some(product) => product%p,
+        None => Int::try_from( (BigInt::from(a)*BigInt::from(b)) % BigInt::from(p) ).unwrap(),
+    };
+}
+
 
 // Incorrectly generated by ChatGPT
 // Fixed by yours truly
@@ -136,16 +136,16 @@ pub fn tonelli_shanks(n: Int, p: Int) -> Int {
         let mut t_i = t;
         while t_i != 1 {
             i += 1;
-            t_i = (t_i * t_i) % p;
+            t_i = mul_mod(t_i, t_i, p);
         }
 
 
 
         let b = power_mod_p(c, power_mod_p(2, m - i - 1, p-1), p);
         m = i;
-        c = (b * b) % p;
-        t = (t * c) % p;
-        r = (r * b) % p;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
 
         if t == 0 
         {
@@ -212,6 +212,240 @@ pub fn legendre_symbol(a: Int,p: Int) -> Int
 
 }
 
+// Factoring the norms
+//
+// Norms that fit in u64 go to the prime_factorization crate. Bigger ones,
+// which come up at small epsilon, are done here, since the crate can take
+// minutes on a hard number near 2^128 and we would rather move on: trial division by
+// small primes, then Pollard's rho with Brent's cycle finding, and
+// Miller-Rabin for whatever does not split. Like in Ross-Selinger, a number
+// that does not come apart in RHO_ITERATIONS steps is given up on, and the
+// grid problem moves on to the next candidate
+pub const TRIAL_DIVISION_BOUND: u32 = 1 << 12;
+pub const RHO_ITERATIONS: usize = 1 << 14;
+
+// Prime factors and their powers, or None when n is too hard
+pub fn factor_integer(n: &BigInt) -> Option::<Vec::<(FactorInt, FactorPowerInt)>>
+{
+    let mut primes = Vec::<FactorInt>::new();
+    let mut n = n.abs();
+    if n.is_zero()
+    {
+        return Some(Vec::new());
+    }
+
+    let mut divisor: u32 = 2;
+    while n.to_u64().is_none() && divisor < TRIAL_DIVISION_BOUND
+    {
+        while (&n % divisor).is_zero()
+        {
+            n = n / divisor;
+            primes.push(BigInt::from(divisor));
+        }
+        divisor = if divisor == 2 { 3 } else { divisor + 2 };
+    }
+
+    let mut left = vec![n];
+    while let Some(m) = left.pop()
+    {
+        if let Some(small) = m.to_u64()
+        {
+            for (prime, power) in Factorization::run(small).prime_factor_repr()
+            {
+                primes.extend(std::iter::repeat(BigInt::from(prime)).take(power as usize));
+            }
+        }
+        else if is_probable_prime(&m)
+        {
+            primes.push(m);
+        }
+        else
+        {
+            let d = pollard_brent(&m)?;
+            left.push(&m / &d);
+            left.push(d);
+        }
+    }
+
+    primes.sort();
+    let mut output = Vec::<(FactorInt, FactorPowerInt)>::new();
+    for prime in primes
+    {
+        match output.last_mut()
+        {
+            Some((last, power)) if *last == prime => *power = *power + 1,
+            _ => output.push((prime, 1)),
+        }
+    }
+    return Some(output);
+}
+
+// Miller-Rabin with the first twenty primes as bases
+// Deterministic below 3.3*10^24, and wrong with probability below 4^-20 above
+pub fn is_probable_prime(n: &BigInt) -> bool
+{
+    let one = BigInt::one();
+    let two = BigInt::from(2);
+    if *n < two
+    {
+        return false;
+    }
+    let n_minus_one = n - &one;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    'bases: for base in [2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71]
+    {
+        let a = BigInt::from(base);
+        if (&a % n).is_zero()
+        {
+            return *n == a;
+        }
+        let mut x = a.modpow(&d, n);
+        if x == one || x == n_minus_one
+        {
+            continue;
+        }
+        for _ in 1..s
+        {
+            x = (&x * &x) % n;
+            if x == n_minus_one
+            {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    return true;
+}
+
+// A proper divisor of the odd composite n, if one turns up in RHO_ITERATIONS steps
+// Brent's variant: gcds are taken of products of 128 differences at a time
+fn pollard_brent(n: &BigInt) -> Option::<BigInt>
+{
+    let one = BigInt::one();
+    let mut steps = 0;
+    for c in 1u32..
+    {
+        let f = |x: &BigInt| (x*x + c) % n;
+        let (mut x, mut y, mut saved) = (BigInt::from(2), BigInt::from(2), BigInt::from(2));
+        let mut product = BigInt::one();
+        let mut cycle = 1;
+        let mut g = BigInt::one();
+        while g == one
+        {
+            x = y.clone();
+            for _ in 0..cycle
+            {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < cycle && g == one
+            {
+                saved = y.clone();
+                for _ in 0..(cycle - k).min(128)
+                {
+                    y = f(&y);
+                    product = (product * (&x - &y)) % n;
+                }
+                g = Integer::gcd(&product, n);
+                k = k + 128;
+            }
+            steps = steps + cycle;
+            cycle = 2*cycle;
+            if steps > RHO_ITERATIONS && g == one
+            {
+                return None;
+            }
+        }
+
+        // The product hit 0 mod n, so go back and take the gcds one at a time
+        if g == *n
+        {
+            loop
+            {
+                saved = f(&saved);
+                g = Integer::gcd(&(&x - &saved), n);
+                if g != one
+                {
+                    break;
+                }
+            }
+        }
+        if g != *n
+        {
+            return Some(g);
+        }
+        if steps > RHO_ITERATIONS
+        {
+            return None;
+        }
+    }
+    return None;
+}
+
+// A square root of n mod the odd prime p, for n a square
+// tonelli_shanks when p fits, and the same algorithm over BigInt otherwise
+pub fn square_root_mod(n: &BigInt, p: &BigInt) -> BigInt
+{
+    if let Some(small) = p.to_i128().filter(|&small| small < (1 << 62))
+    {
+        let residue = Int::try_from(n.mod_floor(p)).unwrap();
+        return BigInt::from(tonelli_shanks(residue, small));
+    }
+
+    let one = BigInt::one();
+    let n = n.mod_floor(p);
+    if n.is_zero()
+    {
+        return n;
+    }
+    let p_minus_one = p - &one;
+    let s = p_minus_one.trailing_zeros().unwrap_or(0);
+    let q = &p_minus_one >> s;
+    let half = &p_minus_one >> 1;
+
+    let mut z = BigInt::from(2);
+    while z.modpow(&half, p) != p_minus_one
+    {
+        z = z + 1;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = n.modpow(&q, p);
+    let mut r = n.modpow(&((&q + 1) >> 1), p);
+    while t != one
+    {
+        let mut i = 0;
+        let mut t_i = t.clone();
+        while t_i != one
+        {
+            i = i + 1;
+            t_i = (&t_i * &t_i) % p;
+        }
+        let b = c.modpow(&(BigInt::one() << (m - i - 1)), p);
+        m = i;
+        c = (&b * &b) % p;
+        t = (t * &c) % p;
+        r = (r * b) % p;
+    }
+    return r;
+}
+
+// n mod m, for the small m that tell primes apart
+fn residue(n: &FactorInt, m: u32) -> u32
+{
+    return (n % m).to_u32().unwrap();
+}
+
+// A factor as an element of the backend, or an overflow if it is too big for it
+fn backend_int<I: IntBackend>(n: &BigInt) -> Result::<I, ArithmeticOverflow>
+{
+    return I::from_bigint(n).ok_or(ArithmeticOverflow);
+}
+
+
 // This takes as input a Loc and returns Some((Loc,Loc)) such that the sum of two Locs in the
 // output is 1. If no such pairs exist, it returns None
 //
@@ -227,18 +461,24 @@ pub fn legendre_symbol(a: Int,p: Int) -> Int
 // (a+ib)(c+id) = (ac-bd) + i(ad+bc)
 //
 //
-pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)  -> Option::<(Loc,Loc)>
+//
+// Every step is checked, and an overflow anywhere is reported as ArithmeticOverflow
+pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc<I: IntBackend>(our_num: Loc<I>)  -> Result::<Option::<(Loc<I>,Loc<I>)>, ArithmeticOverflow>
 {
     
     // println!("Input is {}", our_num);
 
     if our_num.is_zero()
     {
-        return Some((Loc::zero(), Loc::zero() ) );
+        return Ok(Some((Loc::zero(), Loc::zero() ) ));
     }
 
-    let factorvec = prime_factorization_of_loc(our_num);
-    let mut output = Zomega::one();
+    let factorvec = match prime_factorization_of_loc(our_num.clone())?
+    {
+        Some(factorvec) => factorvec,
+        None => return Ok(None),
+    };
+    let mut output = Zomega::<I>::one();
 
 
     // To be mutliplied later
@@ -254,34 +494,33 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
 
         if power%2==1 || power%2==-1
         {
-            if prime==2
+            if prime == BigInt::from(2)
             {
                 if power >= 0 
                 {
-                    let delta = Zomega(1,1,0,0);
-                    let deltapower = pow(delta , power.try_into().unwrap() );
+                    let delta = Zomega::one() + omega();
+                    let deltapower = checked_pow(&delta , power.into() )?;
 
                     // println!("deltapower = {}", deltapower );
-                    output= output * deltapower;
+                    output= output.checked_mul(&deltapower)?;
                 }
                 else if power < 0
                 {
                     
                     let delta = Zomega::one() + omega();
-                    let deltapower = pow(delta , (-power).try_into().unwrap() );
+                    let deltapower = checked_pow(&delta , (-power).into() )?;
                     // println!("deltapower = {}", deltapower );
 
-                    output= output * deltapower;
+                    output= output.checked_mul(&deltapower)?;
                     power_of_sqrt2 = power;
                 }
             }
-            else if prime%4==1 
+            else if residue(&prime, 4)==1 
             {
                 // this is the case when the prime splits in z[omega]
-                let pint = prime as Int;
-                let u = tonelli_shanks(pint - 1,pint);
+                let u = square_root_mod(&(&prime - 1), &prime);
                 
-                let uzomega = <Zomega as NumCast>::from(u).unwrap();
+                let uzomega = Zomega::from_zroot2(Zroot2(backend_int::<I>(&u)?, I::zero()));
 
                 
                 let iota = iota_zomega();
@@ -295,22 +534,21 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
 
                 let eta = Zomega::from_zroot2(locprime.num);
 
-                let t = compute_gcd(eta,uzomega+iota);
-                let t_power = pow(t, power.try_into().unwrap() );
+                let t = eta.checked_gcd(&uzomega.checked_add(&iota)?)?;
+                let t_power = checked_pow(&t, power.into() )?;
                 // println!("t_power {}",t_power );
                 // println!("t_powernorm {}",t_power.norm() );
                 
-                output = output * t_power;
+                output = output.checked_mul(&t_power)?;
 
             }
-            else if prime%8==3
+            else if residue(&prime, 8)==3
             {
                 // this is the case when the prime splits in z[omega]
 
-                let pint = prime as Int;
-                let u = tonelli_shanks( pint - 2, pint ) ;
+                let u = square_root_mod( &(&prime - 2), &prime ) ;
 
-                let uzomega = <Zomega as NumCast>::from(u).unwrap();
+                let uzomega = Zomega::from_zroot2(Zroot2(backend_int::<I>(&u)?, I::zero()));
                 
 
                 let sqrt2 = sqrt2_zomega();
@@ -324,21 +562,21 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
                 // END OF DEBUG ZONE
 
                 let eta = Zomega::from_zroot2(locprime.num);
-                let t = compute_gcd(eta, uzomega + iota*sqrt2);
-                let t_power = pow(t, power.try_into().unwrap() );
+                let t = eta.checked_gcd(&uzomega.checked_add(&(iota*sqrt2))?)?;
+                let t_power = checked_pow(&t, power.into() )?;
 
                 // println!("t_power {}",t_power );
 
-                output = output * t_power;
+                output = output.checked_mul(&t_power)?;
             } else
             {
-                return None;
+                return Ok(None);
             }
 
         }
         else
         {
-            if prime == 2
+            if prime == BigInt::from(2)
             {
                 power_of_sqrt2 =  power >> 1 ;
                 // println!("adjusting power_of_sqrt2 to {}", power_of_sqrt2);
@@ -350,7 +588,7 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
                 // println!("powerby2 {}", powerby2);
                 
 
-                let to_multiply = pow(locprime,powerby2.try_into().unwrap());
+                let to_multiply = checked_pow(&locprime, powerby2.into())?;
 
                 // Debug zone 
                 // if locprime.log_den < 0
@@ -364,7 +602,7 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
 
                 // println!("zomega_mult = {}", zomega_mult );
 
-                output = output * zomega_mult;
+                output = output.checked_mul(&zomega_mult)?;
             }
 
         }
@@ -373,40 +611,39 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
     // Would have given this as output, but must now take care of 
     // 1. Unit
     // 2. power_of_sqrt2
-    let mut left = output.real_part();
-    let mut right = output.imag_part();
+    let (mut left, mut right) = checked_real_and_imag_parts(&output)?;
     
     // Taking care of power_of_sqrt2
     left.log_den = left.log_den - power_of_sqrt2;
     right.log_den = right.log_den - power_of_sqrt2;
 
-    let sum_of_squares_upto_unit = left*left+right*right;
+    let sum_of_squares_upto_unit = left.checked_mul(&left)?.checked_add(&right.checked_mul(&right)?)?;
 
     // println!("sum of squares upto unit {}",sum_of_squares_upto_unit );
-    let unit = our_num/sum_of_squares_upto_unit;
+    // The quotient is a unit, so the division is exact
+    let (unit, _) = our_num.num.checked_div_rem(&sum_of_squares_upto_unit.num)?;
     // println!("unit = {}", unit);
 
 
     // Time to take care of that unit
-    let zrt2_sqrt_unit = find_unit_square_root(unit.num);
+    let zrt2_sqrt_unit = find_unit_square_root(unit);
 
     // To be honest, if our_num is doubly positive,
     // there should exist a square root of the unit
-    if zrt2_sqrt_unit != None
+    if let Some(zrt2_sqrt_unit) = zrt2_sqrt_unit
     {
-        output = output * Zomega::from_zroot2(zrt2_sqrt_unit.unwrap());
+        output = output.checked_mul(&Zomega::from_zroot2(zrt2_sqrt_unit))?;
     }
     else
     {
-        panic!("Our unit was not decomposable! This is mathematically impossible if input was doubly positive. Imperfect floats are probably the reason");
+        panic!("Our unit was not decomposable! This is mathematically impossible if input was doubly positive.");
         // return None;
     }
 
     // println!("zrt2_sqrt_unit = {}", zrt2_sqrt_unit.unwrap());
     
     // Final left right
-    left = output.real_part();
-    right = output.imag_part();
+    (left, right) = checked_real_and_imag_parts(&output)?;
     
     // Taking care of power_of_sqrt2 again
     left.log_den = left.log_den - power_of_sqrt2;
@@ -423,10 +660,19 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
     // END OF DEBUG ZONE
 
 
-    return Some((left,right));
+    return Ok(Some((left,right)));
 
 }
 
+// Zomega::real_part and Zomega::imag_part, but reporting overflows
+// of the sums b-d and b+d they are built from
+fn checked_real_and_imag_parts<I: IntBackend>(z: &Zomega<I>) -> Result::<(Loc<I>,Loc<I>), ArithmeticOverflow>
+{
+    z.1.checked_sub(&z.3)?;
+    z.1.checked_add(&z.3)?;
+    return Ok((z.real_part(), z.imag_part()));
+}
+
 
 
 // Will keep track of base primes, prime above in Zroot2 and the multiplicty it has
@@ -434,19 +680,25 @@ pub fn attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num: Loc)
 // the factors
 // The multiplication will differ with the actual answer by a factor of plus-minus one and a power
 // of (sqrt2 - 1)
-pub fn prime_factorization_of_loc( input: Local::<Zroot2> ) -> Vec::<( FactorInt, Loc, LogDepInt)>
+//
+// The gcds with primes of Z[√2] are checked, and overflows are reported as ArithmeticOverflow
+// Returns Ok(None) when the norm is too hard to factor, see factor_integer
+pub fn prime_factorization_of_loc<I: IntBackend>( input: Local::<Zroot2<I>> ) -> Result::<Option::<Vec::<( FactorInt, Loc<I>, LogDepInt)>>, ArithmeticOverflow>
 {
 
-    // If this fails, then input is probably out of bound
-    let num : FactorInt= input.num.norm().abs().try_into().unwrap();
-
+    // Norms above u128 happen with BigInt at small epsilon
+    let num = input.num.checked_norm()?.to_bigint();
 
     // This line here can be replaced by any other prime factorization algorithm
-    let factorvec = Factorization::run(num).prime_factor_repr();
+    let factorvec = match factor_integer(&num)
+    {
+        Some(factorvec) => factorvec,
+        None => return Ok(None),
+    };
 
     // Take care of powers of two here,
     // they will be ignored in the for loop below
-    let mut factorvecloc = vec!((2, sqrt2loc() , -input.log_den));
+    let mut factorvecloc = vec!((BigInt::from(2), sqrt2loc() , -input.log_den));
 
     if input.log_den == 0
     {
@@ -464,11 +716,11 @@ pub fn prime_factorization_of_loc( input: Local::<Zroot2> ) -> Vec::<( FactorInt
         // println!("prime%8 is {}", prime%8 );
 
         // These are the cases where the prime in Z remains a prime in Zroot2
-        if prime%8==3 || prime%8==5
+        if residue(&prime, 8)==3 || residue(&prime, 8)==5
         {
 
             // This is an inert prime when lifting from Z to Z[sqrt2]
-            let primeloc = <Loc as NumCast >::from(prime).unwrap();
+            let primeloc = Loc::from_base(Zroot2(backend_int::<I>(&prime)?, I::zero()));
             let powerby2 = power >> 1;
             factorvecloc.push( (prime,primeloc,powerby2.try_into().unwrap() ) );
         }
@@ -481,34 +733,32 @@ pub fn prime_factorization_of_loc( input: Local::<Zroot2> ) -> Vec::<( FactorInt
             // we have primeloc = gcd(prime,x^2+2)
             // where x is a square root of -2 mod p
 
-            let p = < Zroot2 as NumCast>::from(prime).unwrap();
-            let pint = prime as Int;
+            let p = Zroot2(backend_int::<I>(&prime)?, I::zero());
 
-            let u = tonelli_shanks(2,pint);
-            let x = Zroot2(u,1);
+            let u = square_root_mod(&BigInt::from(2), &prime);
+            let x = Zroot2(backend_int::<I>(&u)?,I::one());
 
-            let primezrt = compute_gcd(p,x);
-            let primezrtconj = primezrt.conj();
+            let primezrt = p.checked_gcd(&x)?;
+            let primezrtconj = primezrt.clone().conj();
 
             // println!("primezrt found is {}",primezrt );
 
-            let primezrtpowerininput = compute_gcd( input.num, pow(primezrt, power.try_into().unwrap() ) );
-            let primezrtconjpowerininput = compute_gcd( input.num, pow(primezrtconj, power.try_into().unwrap() ) );
+            let primezrtpowerininput = input.num.checked_gcd( &checked_pow(&primezrt, power.into())? )?;
             //
             // println!("primezrtpowerininput {}", primezrtpowerininput );
             // println!("primezrtconjpowerininput {}", primezrtconjpowerininput );
 
-            if primezrtpowerininput.norm().abs() != 1
+            if !primezrtpowerininput.is_unit()
             {
 
-                let powerzrt = power_in_zrt2( primezrtpowerininput, primezrt);
+                let powerzrt = power_in_zrt2( primezrtpowerininput, primezrt.clone())?;
                 let powerremaining = ( power as LogDepInt)  - powerzrt;
 
                 let primeloc = Local::from_base(primezrt);
                 let primelocconj = Local::from_base(primezrtconj);
                 if powerzrt > 0
                 {
-                    factorvecloc.push( (prime,primeloc,powerzrt.try_into().unwrap() ) );
+                    factorvecloc.push( (prime.clone(),primeloc,powerzrt.try_into().unwrap() ) );
                 }
                 if powerremaining > 0
                 {
@@ -529,61 +779,17 @@ pub fn prime_factorization_of_loc( input: Local::<Zroot2> ) -> Vec::<( FactorInt
     }
 
     // println!("------- RETURNING THE VECTOR ------------");
-    return factorvecloc;
+    return Ok(Some(factorvecloc));
 }
 
 
-pub fn find_unit_square_root( unit : Zroot2) -> Option::<Zroot2>
+// Doubly positive units are exactly the even powers of λ = 1+√2
+// Returns λ^k when unit = λ^(2k)
+// See Zroot2::unit_sqrt, this is exact and never overflows
+// None when the unit is not doubly positive
+pub fn find_unit_square_root<I: IntBackend>( unit : Zroot2<I>) -> Option::<Zroot2<I>>
 {
-    let x0 = unit.0 as Float;
-    let x1 = unit.1 as Float;
-
-    let r1 = x0 + SQRT2 * x1;
-    let r2 = x0 - SQRT2 * x1;
-
-    let lambda = crate::structs::rings::special_values::sqrt2plus1();
-    if r1 >= 0.0 && r2 >= 0.0
-    {
-        if r1 >= 1.0
-        {
-            let base = SQRT2 + 1.0;
-            let mut power = floorlog(r1,base);
-
-            // DEBUG ZONE 
-            // if power % 2 !=0 || power < 0
-            // {
-            //     panic!("Something's not right");
-            // }
-            // END OF DEBUG ZONE
-
-            return Some( pow( lambda , ( power >> 1 ).try_into().unwrap()  ) );
-
-        }
-        else 
-        {
-
-            let base = SQRT2 + 1.0;
-
-            let mut power = floorlog(r2,base);
-
-            // DEBUG ZONE 
-            // if power % 2 !=0 || power < 0
-            // {
-            //     panic!("Something's not right");
-            // }
-            // END OF DEBUG ZONE
-
-            return Some( pow( Zroot2(-1,1), ( power >> 1 ).try_into().unwrap()  ) );
-        }
-
-    }
-    else 
-    {
-        println!("x1 x2 were {} {}",x0,x1 );
-        println!("r1 r2 were {} {}",r1,r2 );
-        println!("The unit {} was not doubly positive", unit );
-        return None;
-    }
+    return unit.unit_sqrt();
 }
 
 
@@ -606,17 +812,68 @@ pub fn floorlog( input: Float, base: Float) -> LogDepInt
 
 // WARNING: This function assumes that input is a perfect power of the base (upto unit)
 // It then finds the power
-pub fn power_in_zrt2(input: Zroot2, base : Zroot2) -> LogDepInt
+pub fn power_in_zrt2<I: IntBackend>(input: Zroot2<I>, base : Zroot2<I>) -> Result::<LogDepInt, ArithmeticOverflow>
 {
     // An earlier implementation used floorlog
     // But floating points suck when it comes comes to do this kind of stuff
 
     let mut out = 0;
     let mut x = input.clone();
-    while x % base == Zroot2::zero()
+    loop
     {
+        let (quotient, remainder) = x.checked_div_rem(&base)?;
+        if !remainder.is_zero()
+        {
+            return Ok(out);
+        }
         out = out + 1;
-        x = x / base
+        x = quotient;
     }
-    return out;
+}
+
+
+// Returns a Gaussian integer of norm n, if there is one
+// This is the same idea as attempt_to_write_this_number_as_sum_of_two_squares_in_loc
+// but one level down: Z[i] instead of Z[ω]
+//
+// 2 = -i(1+i)^2, primes 1 mod 4 split as gcd(p, u+i) times its conjugate
+// where u^2 = -1 mod p, and primes 3 mod 4 stay prime, so they need to come in pairs
+pub fn attempt_to_write_integer_as_sum_of_two_squares<I: IntBackend>(n: I) -> Option::<Zi<I>>
+{
+    if n.is_negative()
+    {
+        return None;
+    }
+    if n.is_zero()
+    {
+        return Some(Zi::zero());
+    }
+
+    let factorvec = factor_integer(&n.to_bigint())?;
+    let mut output = Zi::<I>::one();
+
+    for (prime, power) in factorvec
+    {
+        let p = I::from_bigint(&prime)?;
+        if prime == BigInt::from(2)
+        {
+            output = output * pow(Zi(I::one(), I::one()), power as usize);
+        }
+        else if residue(&prime, 4) == 1
+        {
+            let u = I::from_bigint(&square_root_mod(&(&prime - 1), &prime))?;
+            let t = Zi(p, I::zero()).gcd(&Zi(u, I::one()));
+            output = output * pow(t, power as usize);
+        }
+        else if power%2 == 0
+        {
+            output = output * pow(Zi(p, I::zero()), (power/2) as usize);
+        }
+        else
+        {
+            return None;
+        }
+    }
+
+    return Some(output);
 }
@@ -0,0 +1,401 @@
+// Gate tables that are too big to load
+//
+// A GateTable is a HashMap, so all of it has to be read before the first
+// lookup. With a larger sde cutoff in TableGeneration, tables get hundreds
+// of millions of entries. A MappedGateTable keeps them in a file that is
+// memory-mapped, so opening it takes no time, and the operating system only
+// reads the pages that lookups touch
+//
+// The file is
+//
+//      magic       4 bytes, "GSMT"
+//      version     2 bytes, then 2 bytes of zeros
+//      count       8 bytes, the number of entries
+//      records     8 bytes, where the records start
+//      payloads    for each entry, the 12 numbers of save_hash_table and
+//                  the word, in the varints of gate_table_file.rs
+//      records     count times 16 bytes: the 64-bit key of the entry and
+//                  where its payload starts, sorted by key
+//
+// All numbers in the header and records are little endian. The 64-bit key
+// is a hash of the matrix, so two matrices can share it. Lookups find all
+// records with the key by binary search, and compare the full matrix in the
+// payload, so the answer is always exact
+
+
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use memmap2::Mmap;
+
+use crate::structs::rings::Int;
+use crate::algorithms::exact_synth_hashtable_lookup::GateTable;
+use crate::algorithms::exact_synth_hashtable_lookup::key_numbers;
+use crate::algorithms::gate_table_file::GateTableError;
+use crate::algorithms::gate_table_file::ByteReader;
+use crate::algorithms::gate_table_file::zigzag;
+use crate::algorithms::gate_table_file::unzigzag;
+use crate::algorithms::gate_table_file::push_varint;
+use crate::algorithms::gate_table_file::push_string;
+
+// Rings and matrices
+use crate::structs::rings::zroot2::Zroot2;
+type Loc = crate::structs::rings::local_ring::Local::<Zroot2>;
+use num_complex::Complex;
+type ExactGate = crate::structs::sunimat::SUniMat<Complex<Loc>>;
+
+
+pub const MAPPED_MAGIC: [u8; 4] = *b"GSMT";
+pub const MAPPED_FORMAT_VERSION: u16 = 1;
+
+const HEADER_SIZE: usize = 24;
+const RECORD_SIZE: usize = 16;
+
+// How many records write_mapped_table sorts in memory at once, 64 MB of them
+pub const RECORDS_PER_RUN: usize = 1 << 22;
+
+
+// What exact_synth needs from a table
+pub trait GateLookup: Send + Sync
+{
+    fn get(&self, key: &ExactGate) -> Option<&str>;
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool
+    {
+        return self.len() == 0;
+    }
+}
+
+impl GateLookup for GateTable
+{
+    fn get(&self, key: &ExactGate) -> Option<&str>
+    {
+        return GateTable::get(self, key).map(|word| word.as_str());
+    }
+
+    fn len(&self) -> usize
+    {
+        return GateTable::len(self);
+    }
+}
+
+
+fn encoded_numbers(key: &ExactGate) -> Vec<u8>
+{
+    let mut output = Vec::<u8>::new();
+    for x in key_numbers(key)
+    {
+        push_varint(&mut output, zigzag(x));
+    }
+    return output;
+}
+
+// FNV-1a of the varints of the 12 numbers
+// This only has to be the same for the writer and the reader
+pub fn canonical_key(key: &ExactGate) -> u64
+{
+    let mut hash = 0xcbf29ce484222325u64;
+    for byte in encoded_numbers(key)
+    {
+        hash = (hash ^ byte as u64).wrapping_mul(0x100000001b3);
+    }
+    return hash;
+}
+
+
+// Writes entries one at a time, so the table never has to be in memory
+// Only the records are kept, at most RECORDS_PER_RUN of them in memory
+// If a matrix comes twice, lookups may give either word
+pub fn write_mapped_table<J: IntoIterator<Item = (ExactGate, String)>>(entries: J, filename: &str) -> Result<usize, GateTableError>
+{
+    return write_mapped_table_in_runs(entries, filename, RECORDS_PER_RUN);
+}
+
+// The same, keeping at most records_per_run records in memory
+// Each time that many are collected, they are sorted and written to a
+// temporary file next to the table, and at the end all of these runs are
+// merged into the table
+pub fn write_mapped_table_in_runs<J: IntoIterator<Item = (ExactGate, String)>>(entries: J, filename: &str, records_per_run: usize) -> Result<usize, GateTableError>
+{
+    let mut file = BufWriter::new(File::create(filename)?);
+    file.write_all(&[0u8; HEADER_SIZE])?;
+
+    let mut records = Vec::<(u64, u64)>::new();
+    let mut runs = Vec::<String>::new();
+    let mut count: u64 = 0;
+    let mut position = HEADER_SIZE as u64;
+    for (key, word) in entries
+    {
+        let mut payload = encoded_numbers(&key);
+        push_string(&mut payload, &word);
+        file.write_all(&payload)?;
+
+        records.push((canonical_key(&key), position));
+        position = position + payload.len() as u64;
+        count = count + 1;
+
+        if records.len() >= records_per_run.max(1)
+        {
+            runs.push(write_run(&mut records, filename, runs.len())?);
+        }
+    }
+
+    if runs.is_empty()
+    {
+        records.sort();
+        for record in records.iter()
+        {
+            write_record(&mut file, *record)?;
+        }
+    }
+    else
+    {
+        if !records.is_empty()
+        {
+            runs.push(write_run(&mut records, filename, runs.len())?);
+        }
+        let merged = merge_runs(&runs, &mut file);
+        for run in runs.iter()
+        {
+            let _ = std::fs::remove_file(run);
+        }
+        merged?;
+    }
+
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(&MAPPED_MAGIC)?;
+    file.write_all(&MAPPED_FORMAT_VERSION.to_le_bytes())?;
+    file.write_all(&[0u8; 2])?;
+    file.write_all(&count.to_le_bytes())?;
+    file.write_all(&position.to_le_bytes())?;
+    file.flush()?;
+
+    return Ok(count as usize);
+}
+
+fn write_record<W: Write>(output: &mut W, (key, offset): (u64, u64)) -> Result<(), GateTableError>
+{
+    output.write_all(&key.to_le_bytes())?;
+    output.write_all(&offset.to_le_bytes())?;
+    return Ok(());
+}
+
+fn read_record<R: Read>(input: &mut R) -> Result<Option<(u64, u64)>, GateTableError>
+{
+    let mut bytes = [0u8; RECORD_SIZE];
+    match input.read_exact(&mut bytes)
+    {
+        Ok(()) => {},
+        Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    }
+    let key = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+    let offset = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+    return Ok(Some((key, offset)));
+}
+
+// Sorts the records, writes them to a temporary file and empties them
+fn write_run(records: &mut Vec<(u64, u64)>, filename: &str, index: usize) -> Result<String, GateTableError>
+{
+    records.sort();
+    let run_name = format!("{}.run{}", filename, index);
+    let mut run = BufWriter::new(File::create(&run_name)?);
+    for record in records.iter()
+    {
+        write_record(&mut run, *record)?;
+    }
+    run.flush()?;
+    records.clear();
+    return Ok(run_name);
+}
+
+// Each run is sorted, so the smallest record left is always at the front
+// of one of them
+fn merge_runs<W: Write>(runs: &[String], output: &mut W) -> Result<(), GateTableError>
+{
+    let mut readers = Vec::<BufReader<File>>::new();
+    let mut fronts = BinaryHeap::<Reverse<((u64, u64), usize)>>::new();
+    for (i, run) in runs.iter().enumerate()
+    {
+        let mut reader = BufReader::new(File::open(run)?);
+        if let Some(record) = read_record(&mut reader)?
+        {
+            fronts.push(Reverse((record, i)));
+        }
+        readers.push(reader);
+    }
+
+    while let Some(Reverse((record, i))) = fronts.pop()
+    {
+        write_record(output, record)?;
+        if let Some(next) = read_record(&mut readers[i])?
+        {
+            fronts.push(Reverse((next, i)));
+        }
+    }
+    return Ok(());
+}
+
+pub fn save_mapped_table(table: &GateTable, filename: &str) -> Result<usize, GateTableError>
+{
+    return write_mapped_table(table.iter().map(|(key, word)| (*key, word.clone())), filename);
+}
+
+
+pub struct MappedGateTable
+{
+    map: Mmap,
+    count: usize,
+    records_start: usize,
+}
+
+impl MappedGateTable
+{
+    // Only the header is checked, so this takes the same time for any size
+    // The file must not change while it is open
+    pub fn open(filename: &str) -> Result<Self, GateTableError>
+    {
+        let file = File::open(filename)?;
+
+        // Safety: the map is read only, and we ask that nobody writes the
+        // file while it is open. Bad data can still only give wrong answers
+        // or None, since every read below is bounds checked
+        let map = unsafe { Mmap::map(&file)? };
+
+        let mut reader = ByteReader{ data: &map[..] };
+        let magic: [u8; 4] = reader.bytes(4)?.try_into().unwrap();
+        if magic != MAPPED_MAGIC
+        {
+            return Err(GateTableError::BadMagic(magic));
+        }
+        let version = u16::from_le_bytes(reader.bytes(2)?.try_into().unwrap());
+        if version != MAPPED_FORMAT_VERSION
+        {
+            return Err(GateTableError::UnsupportedVersion(version));
+        }
+        reader.bytes(2)?;
+        let count = u64::from_le_bytes(reader.bytes(8)?.try_into().unwrap());
+        let records_start = u64::from_le_bytes(reader.bytes(8)?.try_into().unwrap());
+
+        let records_end = count.checked_mul(RECORD_SIZE as u64).and_then(|size| size.checked_add(records_start));
+        if records_start < HEADER_SIZE as u64 || records_end.map_or(true, |end| end > map.len() as u64)
+        {
+            return Err(GateTableError::Truncated);
+        }
+        if records_end != Some(map.len() as u64)
+        {
+            return Err(GateTableError::TrailingBytes(map.len() - records_end.unwrap() as usize));
+        }
+
+        return Ok(Self{ map, count: count as usize, records_start: records_start as usize });
+    }
+
+    fn record(&self, i: usize) -> (u64, usize)
+    {
+        let start = self.records_start + i*RECORD_SIZE;
+        let bytes = &self.map[start..start+RECORD_SIZE];
+        let key = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let offset = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        return (key, offset as usize);
+    }
+
+    // The numbers and the word of a payload
+    fn payload(&self, offset: usize) -> Result<([Int; 12], &str), GateTableError>
+    {
+        if offset > self.records_start
+        {
+            return Err(GateTableError::Truncated);
+        }
+        let mut reader = ByteReader{ data: &self.map[offset..self.records_start] };
+        let mut numbers = [0 as Int; 12];
+        for x in numbers.iter_mut()
+        {
+            *x = unzigzag(reader.varint()?);
+        }
+        return Ok((numbers, reader.str()?));
+    }
+
+    pub fn get(&self, key: &ExactGate) -> Option<&str>
+    {
+        let wanted = canonical_key(key);
+
+        // The first record with a key that is not smaller
+        let (mut low, mut high) = (0, self.count);
+        while low < high
+        {
+            let middle = low + (high - low)/2;
+            if self.record(middle).0 < wanted
+            {
+                low = middle + 1;
+            }
+            else
+            {
+                high = middle;
+            }
+        }
+
+        let numbers = key_numbers(key);
+        let mut i = low;
+        while i < self.count && self.record(i).0 == wanted
+        {
+            if let Ok((found, word)) = self.payload(self.record(i).1)
+            {
+                if found == numbers
+                {
+                    return Some(word);
+                }
+            }
+            i = i + 1;
+        }
+        return None;
+    }
+
+    pub fn len(&self) -> usize
+    {
+        return self.count;
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        return self.count == 0;
+    }
+
+    // Reads every entry, for checking a file or loading a small one
+    pub fn to_gate_table(&self) -> Result<GateTable, GateTableError>
+    {
+        let mut table = GateTable::new();
+        for i in 0..self.count
+        {
+            let (key, offset) = self.record(i);
+            let (numbers, word) = self.payload(offset)?;
+            let matrix = crate::algorithms::exact_synth_hashtable_lookup::key_from_numbers(&numbers);
+            if canonical_key(&matrix) != key || (i > 0 && self.record(i-1).0 > key)
+            {
+                return Err(GateTableError::BadEntry(format!("the record of \"{}\" is out of place", word)));
+            }
+            table.insert(matrix, word.to_string());
+        }
+        return Ok(table);
+    }
+}
+
+impl GateLookup for MappedGateTable
+{
+    fn get(&self, key: &ExactGate) -> Option<&str>
+    {
+        return MappedGateTable::get(self, key);
+    }
+
+    fn len(&self) -> usize
+    {
+        return self.count;
+    }
+}
@@ -2,8 +2,17 @@
 
 pub mod exact_synth;
 pub mod exact_synth_hashtable_lookup;
+pub mod residual_table;
+pub mod gate_table_file;
+pub mod mapped_gate_table;
+pub mod generator_table;
+pub mod nearest_gate;
+pub mod exact_synth_sqrt_t;
+pub mod exact_synth_v;
 pub mod inexact_synth;
+pub mod inexact_synth_v;
 pub mod local_prime_factorization;
 pub mod near_int;
+pub mod normal_form;
 pub mod lll;
 
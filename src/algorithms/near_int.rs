@@ -1,5 +1,4 @@
-use crate::structs::rings::Int;
-use num_traits::Zero;
+use crate::structs::rings::IntBackend;
 
 
 
@@ -7,34 +6,34 @@ use num_traits::Zero;
 // Return closest integer to top/bottom
 // Will send halfs to the ceiling
 // Probably shouldn't matter
-pub fn nearest_integer(top :Int, bottom: Int) -> Int
+pub fn nearest_integer<I: IntBackend>(top :I, bottom: I) -> I
 {
-    if bottom == Int::zero()
+    if bottom.is_zero()
     {
         panic!("What do you think?");
     }
-    else if top ==Int::zero()
+    else if top.is_zero()
     {
-        return Int::zero();
+        return I::zero();
     }
-    else if  top > Int::zero() && bottom > Int::zero() 
+    else if  top.is_positive() && bottom.is_positive() 
     { 
         let twice = (top << 1)/bottom;
-        if twice%2==Int::zero()
+        if twice.clone()%(I::one()+I::one())==I::zero()
         {
             return twice>>1;
         }
         else 
         {
-            return (twice+1)>>1  ;
+            return (twice+I::one())>>1  ;
         }
 
     }
-    else if  top < Int::zero() && bottom < Int::zero()
+    else if  top.is_negative() && bottom.is_negative()
     {
         return nearest_integer(-top, -bottom);
     }
-    else if  top > Int::zero() && bottom < Int::zero() 
+    else if  top.is_positive() && bottom.is_negative() 
     {
         return -nearest_integer(top, -bottom);
     }
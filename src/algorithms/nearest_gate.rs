@@ -0,0 +1,218 @@
+// Approximate synthesis by looking in a gate table
+//
+// A table only answers exact questions. Here the word of every entry is
+// turned into a float special unitary with to_float_special_unitary, which is
+// a point (u.re, u.im, t.re, t.im) on the 3-sphere, and the points go in a
+// vantage-point tree. Then the entries near any target are found without
+// looking at all of them, which is a quick baseline for approximate
+// synthesis when epsilon is not too small
+//
+// The key of an entry is only the matrix part, and the phase ω^k on the second
+// column is in the word. So the points come from the words, or T would be
+// the same point as I. Each key is every one of the 8 phases, which are
+// the word followed by a power of T, and that power counts in the T-count
+//
+// The distance is the operator norm of the difference, up to global phase.
+// For special unitaries this is the distance between the points in R^4,
+// and the only phases left are 1 and -1, so q and -q are the same gate
+//
+// A vantage-point tree works for any metric: each node keeps a point and the
+// median distance from it to the points below, and the points closer than
+// that go to the inside, the others to the outside. A search only goes into
+// a side that can have something closer than what it already found
+
+
+use num_complex::Complex;
+
+use crate::structs::rings::Float;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::rings::Int;
+use crate::structs::circuit::Circuit;
+use crate::algorithms::exact_synth_hashtable_lookup::GateTable;
+
+type FloatGate = SUniMat<Complex<Float>>;
+type Point = [Float; 4];
+
+
+#[derive(Debug,Clone,PartialEq)]
+pub struct NearGate
+{
+    pub word: String,
+    pub t_count: usize,
+    pub distance: Float,
+}
+
+
+fn to_point(gate: &FloatGate) -> Point
+{
+    return [gate.u.re, gate.u.im, gate.t.re, gate.t.im];
+}
+
+pub fn distance_upto_phase(a: &FloatGate, b: &FloatGate) -> Float
+{
+    return point_distance(&to_point(a), &to_point(b));
+}
+
+fn point_distance(a: &Point, b: &Point) -> Float
+{
+    let minus: Float = (0..4).map(|i| (a[i] - b[i]).powi(2)).sum();
+    let plus: Float = (0..4).map(|i| (a[i] + b[i]).powi(2)).sum();
+    return minus.min(plus).sqrt();
+}
+
+// T and T^† in a word, whatever else it has
+fn t_count_of_word(word: &str) -> usize
+{
+    return word.chars().filter(|letter| *letter == 'T' || *letter == 't').count();
+}
+
+
+struct Node
+{
+    point: usize,               // Index into the entries
+    radius: Float,              // Inside is closer than this, outside is not
+    inside: Option<usize>,      // Indices into the nodes
+    outside: Option<usize>,
+}
+
+pub struct NearestGateTable
+{
+    entries: Vec<(Point, String)>,
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl NearestGateTable
+{
+    pub fn new(table: &GateTable) -> Self
+    {
+        // Sorted, so that the tree does not depend on the order of the HashMap
+        let mut entries = Vec::<(Point, String)>::new();
+        for (key, word) in table.iter()
+        {
+            for power in 0..8
+            {
+                let word = word.clone() + &"T".repeat(power);
+                let circuit: Circuit = word.parse().expect("A table word that is not a circuit");
+                let gate = circuit.to_exact_unimat::<Int>();
+                assert!( gate.mat == *key, "The word {} is not a circuit for its key", word );
+                entries.push((to_point(&gate.to_float_special_unitary()), circuit.collapse_t_runs().to_string()));
+            }
+        }
+        entries.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let mut output = Self{ entries, nodes: Vec::new(), root: None };
+        let mut indices: Vec<usize> = (0..output.entries.len()).collect();
+        output.root = output.build(&mut indices);
+        return output;
+    }
+
+    fn build(&mut self, indices: &mut [usize]) -> Option<usize>
+    {
+        let (&mut vantage, rest) = indices.split_first_mut()?;
+        let radius = if rest.is_empty()
+        {
+            0.0
+        }
+        else
+        {
+            let vantage_point = self.entries[vantage].0;
+            rest.sort_by(|a, b| point_distance(&vantage_point, &self.entries[*a].0).total_cmp(&point_distance(&vantage_point, &self.entries[*b].0)));
+            point_distance(&vantage_point, &self.entries[rest[rest.len()/2]].0)
+        };
+
+        let (inside, outside) = rest.split_at_mut(rest.len()/2);
+        let inside = self.build(inside);
+        let outside = self.build(outside);
+        self.nodes.push(Node{ point: vantage, radius, inside, outside });
+        return Some(self.nodes.len() - 1);
+    }
+
+    pub fn len(&self) -> usize
+    {
+        return self.entries.len();
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        return self.entries.is_empty();
+    }
+
+    // Calls visit on the entries that can be closer than bound(), which may
+    // shrink as the search goes
+    fn search<F: FnMut(usize, Float), B: Fn() -> Float>(&self, node: Option<usize>, target: &Point, visit: &mut F, bound: &B)
+    {
+        let node = match node
+        {
+            Some(node) => &self.nodes[node],
+            None => return,
+        };
+        let distance = point_distance(target, &self.entries[node.point].0);
+        if distance <= bound()
+        {
+            visit(node.point, distance);
+        }
+
+        // The closer side first, so the bound shrinks sooner
+        let sides = if distance < node.radius { [(node.inside, true), (node.outside, false)] } else { [(node.outside, false), (node.inside, true)] };
+        for (side, is_inside) in sides
+        {
+            let can_be_close = if is_inside { distance - bound() <= node.radius } else { distance + bound() >= node.radius };
+            if can_be_close
+            {
+                self.search(side, target, visit, bound);
+            }
+        }
+    }
+
+    fn near_gate(&self, index: usize, distance: Float) -> NearGate
+    {
+        let word = self.entries[index].1.clone();
+        return NearGate{ t_count: t_count_of_word(&word), word, distance };
+    }
+
+    // Fewer T gates first, then closer ones
+    fn sorted(&self, mut found: Vec<NearGate>) -> Vec<NearGate>
+    {
+        found.sort_by(|a, b| a.t_count.cmp(&b.t_count).then(a.distance.total_cmp(&b.distance)).then(a.word.cmp(&b.word)));
+        return found;
+    }
+
+    // The k entries closest to the target, sorted by T-count
+    pub fn nearest(&self, target: &FloatGate, k: usize) -> Vec<NearGate>
+    {
+        if k == 0
+        {
+            return Vec::new();
+        }
+        let target = to_point(target);
+
+        // The best so far, closest first
+        let best = std::cell::RefCell::new(Vec::<(Float, usize)>::new());
+        let bound = ||
+        {
+            let best = best.borrow();
+            return if best.len() < k { Float::INFINITY } else { best[k-1].0 };
+        };
+        let mut visit = |index: usize, distance: Float|
+        {
+            let mut best = best.borrow_mut();
+            let position = best.partition_point(|(d, i)| (*d, *i) < (distance, index));
+            best.insert(position, (distance, index));
+            best.truncate(k);
+        };
+        self.search(self.root, &target, &mut visit, &bound);
+
+        let found = best.into_inner().into_iter().map(|(distance, index)| self.near_gate(index, distance)).collect();
+        return self.sorted(found);
+    }
+
+    // All entries at distance at most epsilon from the target, sorted by T-count
+    pub fn within(&self, target: &FloatGate, epsilon: Float) -> Vec<NearGate>
+    {
+        let mut found = Vec::<NearGate>::new();
+        let mut visit = |index: usize, distance: Float| found.push(self.near_gate(index, distance));
+        self.search(self.root, &to_point(target), &mut visit, &|| epsilon);
+        return self.sorted(found);
+    }
+}
@@ -0,0 +1,219 @@
+// Matsumoto–Amano normal form of Clifford+T operators
+//
+// Every Clifford+T operator can be written in exactly one way as
+//      (T|ε) (HT|SHT)* C
+// where C is a Clifford, phase included. See arxiv:0806.3834 by Matsumoto
+// and Amano, and arxiv:1312.6584 by Giles and Selinger for the proofs used here
+//
+// The number of T gates in the normal form is the T-count of the operator,
+// and it is also the least denominator exponent (lde) of the Bloch sphere
+// rotation of the operator, counted in powers of √2. So the normal form can be
+// read off from the left: the first syllable is the only one of T, HT and SHT
+// whose inverse lowers the lde by one
+//
+// The Clifford at the end is written as Clifford::canonical_circuit writes it,
+// so the same operator always gives the same gates
+
+
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::special_values::onebyroot2comp;
+use crate::structs::rings::special_values::sqrtminus1;
+use crate::structs::rings::special_values::mu_8;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Gate;
+use crate::structs::circuit::Circuit;
+use crate::structs::clifford::Clifford;
+
+use num_complex::Complex;
+use num_traits::Zero;
+use num_traits::One;
+use num_traits::Pow;
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+type Loc<I = Int> = Local<Zroot2<I>>;
+type Comp<I = Int> = Complex<Loc<I>>;
+type Mat2<I = Int> = [[Comp<I>; 2]; 2];
+
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Syllable
+{
+    HT,
+    SHT,
+}
+
+impl Syllable
+{
+    pub fn gates(&self) -> &'static [Gate]
+    {
+        return match self
+        {
+            Syllable::HT => &[Gate::H, Gate::T],
+            Syllable::SHT => &[Gate::S, Gate::H, Gate::T],
+        };
+    }
+}
+
+
+#[derive(Debug,Clone,PartialEq,Eq,Hash)]
+pub struct NormalForm
+{
+    pub leading_t: bool,
+    pub syllables: Vec<Syllable>,
+    pub clifford: Circuit,          // Only H and S, and the phase
+}
+
+impl NormalForm
+{
+    pub fn t_count(&self) -> usize
+    {
+        return self.syllables.len() + self.leading_t as usize;
+    }
+
+    pub fn to_circuit(&self) -> Circuit
+    {
+        let mut gates = Vec::<Gate>::new();
+        if self.leading_t
+        {
+            gates.push(Gate::T);
+        }
+        for syllable in self.syllables.iter()
+        {
+            gates.extend_from_slice(syllable.gates());
+        }
+        gates.extend(self.clifford.iter());
+        return Circuit{ gates, omega_exp: self.clifford.omega_exp };
+    }
+
+    pub fn to_exact_unimat<I: IntBackend>(&self) -> ExactUniMat<I>
+    {
+        return self.to_circuit().to_exact_unimat();
+    }
+}
+
+impl Display for NormalForm
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        write!(f,"{}",self.to_circuit())
+    }
+}
+
+
+fn mat2_mul<I: IntBackend>(a: &Mat2<I>, b: &Mat2<I>) -> Mat2<I>
+{
+    let entry = |i: usize, j: usize| a[i][0].clone()*b[0][j].clone() + a[i][1].clone()*b[1][j].clone();
+    return [[entry(0,0), entry(0,1)], [entry(1,0), entry(1,1)]];
+}
+
+// The rotation of the Bloch sphere that the gate does
+// R_ij = tr(σ_i U σ_j U^†)/2, which does not see the global phase
+// The phase of ExactUniMat is not global, it only sits on the second column
+pub fn bloch_rotation<I: IntBackend>(gate: &ExactUniMat<I>) -> [[Loc<I>; 3]; 3]
+{
+    let (u, t) = (gate.mat.u.clone(), gate.mat.t.clone());
+    let phase = mu_8::<I>().pow(gate.omega_exp);
+    let unitary: Mat2<I> = [[u.clone(), -t.conj()*phase.clone()], [t.clone(), u.conj()*phase.clone()]];
+    let adjoint: Mat2<I> = [[u.conj(), t.conj()], [-t*phase.conj(), u*phase.conj()]];
+
+    let (zero, one, i) = (Comp::<I>::zero(), Comp::<I>::one(), sqrtminus1::<I>());
+    let paulis: [Mat2<I>; 3] = [
+        [[zero.clone(), one.clone()], [one.clone(), zero.clone()]],
+        [[zero.clone(), -i.clone()], [i, zero.clone()]],
+        [[one.clone(), zero.clone()], [zero, -one]],
+    ];
+    let half = onebyroot2comp::<I>()*onebyroot2comp::<I>();
+
+    let entry = |a: usize, b: usize|
+    {
+        let product = mat2_mul(&mat2_mul(&paulis[a], &unitary), &mat2_mul(&paulis[b], &adjoint));
+        return ((product[0][0].clone() + product[1][1].clone())*half.clone()).re;
+    };
+    return [
+        [entry(0,0), entry(0,1), entry(0,2)],
+        [entry(1,0), entry(1,1), entry(1,2)],
+        [entry(2,0), entry(2,1), entry(2,2)],
+    ];
+}
+
+// The least denominator exponent of the Bloch rotation
+// This is the T-count, see the top of this file
+pub fn bloch_lde<I: IntBackend>(gate: &ExactUniMat<I>) -> LogDepInt
+{
+    return bloch_rotation(gate).iter().flatten().map(|x| x.log_den).fold(0, LogDepInt::max);
+}
+
+
+// The main deal
+pub fn normal_form<I: IntBackend>(gate: &ExactUniMat<I>) -> NormalForm
+{
+    let t = Circuit::from_gates(vec![Gate::T]);
+    let ht = Circuit::from_gates(Syllable::HT.gates().to_vec());
+    let sht = Circuit::from_gates(Syllable::SHT.gates().to_vec());
+
+    let mut output = NormalForm{ leading_t: false, syllables: vec![], clifford: Circuit::one() };
+    let mut rest = gate.clone();
+    let mut lde = bloch_lde(&rest);
+
+    // Only the very first T can stand alone
+    if lde > 0
+    {
+        let candidate = t.inverse().to_exact_unimat()*rest.clone();
+        if bloch_lde(&candidate) < lde
+        {
+            output.leading_t = true;
+            rest = candidate;
+            lde = lde - 1;
+        }
+    }
+
+    while lde > 0
+    {
+        let candidate = ht.inverse().to_exact_unimat()*rest.clone();
+        if bloch_lde(&candidate) < lde
+        {
+            output.syllables.push(Syllable::HT);
+            rest = candidate;
+        }
+        else
+        {
+            // By uniqueness of the normal form, this one has to work
+            rest = sht.inverse().to_exact_unimat()*rest;
+            output.syllables.push(Syllable::SHT);
+            assert!( bloch_lde(&rest) < lde, "Could not decrease the lde" );
+        }
+        lde = lde - 1;
+    }
+
+    match Clifford::from_exact_unimat(&rest)
+    {
+        Some(clifford) => output.clifford = clifford.canonical_circuit(),
+        None =>
+        {
+            println!("LEFT WITH: \n{}", rest);
+            panic!("Not a Clifford");
+        }
+    }
+    return output;
+}
+
+// BigInt, so that long circuits never overflow
+pub fn normal_form_of_circuit(circuit: &Circuit) -> NormalForm
+{
+    return normal_form(&circuit.to_exact_unimat::<BigInt>());
+}
+
+// Two circuits are the same unitary exactly when they have the same normal form
+pub fn same_unitary(left: &Circuit, right: &Circuit) -> bool
+{
+    return normal_form_of_circuit(left) == normal_form_of_circuit(right);
+}
+
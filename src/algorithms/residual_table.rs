@@ -0,0 +1,473 @@
+// Generated by write_residual_table in exact_synth_hashtable_lookup.rs
+// Do not edit by hand
+//
+// A word, and the numbers of the key in the order of save_hash_table
+
+use crate::structs::rings::Int;
+
+pub const RESIDUAL_TABLE: [(&str, [Int; 12]); 464] = [
+    ("THTSSHSSHSSH", [-1, -1, 3, -1, 0, 3, -1, 0, 3, -1, 1, 3]),
+    ("SHTSSHSSHSSH", [-1, -1, 3, -1, 0, 3, -1, 0, 3, 1, -1, 3]),
+    ("HTHSSSHSSHSSH", [-1, -1, 3, -1, 0, 3, -1, 1, 3, -1, 0, 3]),
+    ("TSHTHSSSHSSHSSH", [-1, -1, 3, -1, 0, 3, -1, 1, 3, 1, 0, 3]),
+    ("TSHTSSHSSHSSH", [-1, -1, 3, -1, 0, 3, 1, -1, 3, -1, 0, 3]),
+    ("HTSSHSSHSSH", [-1, -1, 3, -1, 0, 3, 1, -1, 3, 1, 0, 3]),
+    ("SHTHSSSHSSHSSH", [-1, -1, 3, -1, 0, 3, 1, 0, 3, -1, 1, 3]),
+    ("THTHSSSHSSHSSH", [-1, -1, 3, -1, 0, 3, 1, 0, 3, 1, -1, 3]),
+    ("THTSHTSHSSHSSHSH", [-1, -1, 3, -1, 1, 3, -1, 0, 2, 0, 0, 0]),
+    ("SHTSHTSHSSHSSHSH", [-1, -1, 3, -1, 1, 3, -1, 0, 3, -1, 0, 3]),
+    ("HTSHTSHSSHSSHSH", [-1, -1, 3, -1, 1, 3, -1, 0, 3, 1, 0, 3]),
+    ("TSHTSHTSHSSHSSHSH", [-1, -1, 3, -1, 1, 3, 0, 0, 0, -1, 0, 2]),
+    ("TSHTHTHSHSSHSSH", [-1, -1, 3, -1, 1, 3, 0, 0, 0, 1, 0, 2]),
+    ("THTHTHSHSSHSSH", [-1, -1, 3, -1, 1, 3, 1, 0, 2, 0, 0, 0]),
+    ("HTHTHSHSSHSSH", [-1, -1, 3, -1, 1, 3, 1, 0, 3, -1, 0, 3]),
+    ("SHTHTHSHSSHSSH", [-1, -1, 3, -1, 1, 3, 1, 0, 3, 1, 0, 3]),
+    ("TSHTSHTSSHSSHSH", [-1, -1, 3, 1, -1, 3, -1, 0, 2, 0, 0, 0]),
+    ("HTHTSSHSSHSSH", [-1, -1, 3, 1, -1, 3, -1, 0, 3, -1, 0, 3]),
+    ("SHTSHTSSHSSHSH", [-1, -1, 3, 1, -1, 3, -1, 0, 3, 1, 0, 3]),
+    ("THTHTSSHSSHSSH", [-1, -1, 3, 1, -1, 3, 0, 0, 0, -1, 0, 2]),
+    ("THTSHTSSHSSHSH", [-1, -1, 3, 1, -1, 3, 0, 0, 0, 1, 0, 2]),
+    ("TSHTHTSSHSSHSSH", [-1, -1, 3, 1, -1, 3, 1, 0, 2, 0, 0, 0]),
+    ("SHTHTSSHSSHSSH", [-1, -1, 3, 1, -1, 3, 1, 0, 3, -1, 0, 3]),
+    ("HTSHTSSHSSHSH", [-1, -1, 3, 1, -1, 3, 1, 0, 3, 1, 0, 3]),
+    ("SHTSHSSHSSHSH", [-1, -1, 3, 1, 0, 3, -1, 0, 3, -1, 1, 3]),
+    ("TSHTSHSSHSSHSH", [-1, -1, 3, 1, 0, 3, -1, 0, 3, 1, -1, 3]),
+    ("THTSHSSHSSH", [-1, -1, 3, 1, 0, 3, -1, 1, 3, -1, 0, 3]),
+    ("HTSHSSHSSHSH", [-1, -1, 3, 1, 0, 3, -1, 1, 3, 1, 0, 3]),
+    ("HTSHSSHSSH", [-1, -1, 3, 1, 0, 3, 1, -1, 3, -1, 0, 3]),
+    ("THTSHSSHSSHSH", [-1, -1, 3, 1, 0, 3, 1, -1, 3, 1, 0, 3]),
+    ("TSHTSHSSHSSH", [-1, -1, 3, 1, 0, 3, 1, 0, 3, -1, 1, 3]),
+    ("SHTSHSSHSSH", [-1, -1, 3, 1, 0, 3, 1, 0, 3, 1, -1, 3]),
+    ("HSSHSSHSSH", [-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    ("HSHSSSHSSHSSH", [-1, 0, 1, -1, 0, 1, 0, 0, 0, 0, 0, 0]),
+    ("HTSHTSSHSSHSSH", [-1, 0, 1, -1, 0, 2, -1, 0, 2, 0, 0, 0]),
+    ("THTSHTSSHSSHSSH", [-1, 0, 1, -1, 0, 2, -1, 0, 3, -1, 0, 3]),
+    ("TSHTHTSSSHSSHSSH", [-1, 0, 1, -1, 0, 2, -1, 0, 3, 1, 0, 3]),
+    ("SHTSHTSSHSSHSSH", [-1, 0, 1, -1, 0, 2, 0, 0, 0, -1, 0, 2]),
+    ("SHTHTSSSHSSHSSH", [-1, 0, 1, -1, 0, 2, 0, 0, 0, 1, 0, 2]),
+    ("HTHTSSSHSSHSSH", [-1, 0, 1, -1, 0, 2, 1, 0, 2, 0, 0, 0]),
+    ("TSHTSHTSSHSSHSSH", [-1, 0, 1, -1, 0, 2, 1, 0, 3, -1, 0, 3]),
+    ("THTHTSSSHSSHSSH", [-1, 0, 1, -1, 0, 2, 1, 0, 3, 1, 0, 3]),
+    ("SSHSSHSSH", [-1, 0, 1, 0, 0, 0, -1, 0, 1, 0, 0, 0]),
+    ("TSSHSSHSSH", [-1, 0, 1, 0, 0, 0, -1, 0, 2, -1, 0, 2]),
+    ("TSHSSHSSH", [-1, 0, 1, 0, 0, 0, -1, 0, 2, 1, 0, 2]),
+    ("SSSHSSHSSH", [-1, 0, 1, 0, 0, 0, 0, 0, 0, -1, 0, 1]),
+    ("SHSSHSSH", [-1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1]),
+    ("HSSHSSH", [-1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0]),
+    ("TSSSHSSHSSH", [-1, 0, 1, 0, 0, 0, 1, 0, 2, -1, 0, 2]),
+    ("THSSHSSH", [-1, 0, 1, 0, 0, 0, 1, 0, 2, 1, 0, 2]),
+    ("HSHSSHSSHSH", [-1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0]),
+    ("HTHTSHSSHSSHSH", [-1, 0, 1, 1, 0, 2, -1, 0, 2, 0, 0, 0]),
+    ("THTHTSHSSHSSHSH", [-1, 0, 1, 1, 0, 2, -1, 0, 3, -1, 0, 3]),
+    ("TSHTSHTSSHSH", [-1, 0, 1, 1, 0, 2, -1, 0, 3, 1, 0, 3]),
+    ("SHTHTSHSSHSSHSH", [-1, 0, 1, 1, 0, 2, 0, 0, 0, -1, 0, 2]),
+    ("SHTSHTSSHSH", [-1, 0, 1, 1, 0, 2, 0, 0, 0, 1, 0, 2]),
+    ("HTSHTSSHSH", [-1, 0, 1, 1, 0, 2, 1, 0, 2, 0, 0, 0]),
+    ("TSHTHTSHSSHSSHSH", [-1, 0, 1, 1, 0, 2, 1, 0, 3, -1, 0, 3]),
+    ("THTSHTSSHSH", [-1, 0, 1, 1, 0, 2, 1, 0, 3, 1, 0, 3]),
+    ("SHTSHTHSHSSHSSH", [-1, 0, 2, -1, 0, 1, -1, 0, 2, 0, 0, 0]),
+    ("TSHTSHTHSHSSHSSH", [-1, 0, 2, -1, 0, 1, -1, 0, 3, -1, 0, 3]),
+    ("THTSHTHSHSSHSSH", [-1, 0, 2, -1, 0, 1, -1, 0, 3, 1, 0, 3]),
+    ("HTHTSHSSSHSSHSSH", [-1, 0, 2, -1, 0, 1, 0, 0, 0, -1, 0, 2]),
+    ("HTSHTHSHSSHSSH", [-1, 0, 2, -1, 0, 1, 0, 0, 0, 1, 0, 2]),
+    ("SHTHTSHSSSHSSHSSH", [-1, 0, 2, -1, 0, 1, 1, 0, 2, 0, 0, 0]),
+    ("THTHTSHSSSHSSHSSH", [-1, 0, 2, -1, 0, 1, 1, 0, 3, -1, 0, 3]),
+    ("TSHTHTSHSSSHSSHSSH", [-1, 0, 2, -1, 0, 1, 1, 0, 3, 1, 0, 3]),
+    ("THSSSHSSHSSH", [-1, 0, 2, -1, 0, 2, -1, 0, 1, 0, 0, 0]),
+    ("SHSSSHSSHSSH", [-1, 0, 2, -1, 0, 2, -1, 0, 2, -1, 0, 2]),
+    ("HSSSHSSHSSH", [-1, 0, 2, -1, 0, 2, -1, 0, 2, 1, 0, 2]),
+    ("TSHSSSHSSHSSH", [-1, 0, 2, -1, 0, 2, 0, 0, 0, -1, 0, 1]),
+    ("THSSHSSSHSH", [-1, 0, 2, -1, 0, 2, 0, 0, 0, 1, 0, 1]),
+    ("THSHSSSHSSH", [-1, 0, 2, -1, 0, 2, 1, 0, 1, 0, 0, 0]),
+    ("HSHSSSHSSH", [-1, 0, 2, -1, 0, 2, 1, 0, 2, -1, 0, 2]),
+    ("HSSHSSSHSH", [-1, 0, 2, -1, 0, 2, 1, 0, 2, 1, 0, 2]),
+    ("TSHTHTHSHSSSHSH", [-1, 0, 2, 0, 0, 0, -1, -1, 3, -1, 1, 3]),
+    ("THTHTSHSSHSSH", [-1, 0, 2, 0, 0, 0, -1, -1, 3, 1, -1, 3]),
+    ("HTSHTHSSSHSSHSSH", [-1, 0, 2, 0, 0, 0, -1, 0, 1, -1, 0, 2]),
+    ("HTHTSHSSHSSH", [-1, 0, 2, 0, 0, 0, -1, 0, 1, 1, 0, 2]),
+    ("SHTHTSHSSHSSH", [-1, 0, 2, 0, 0, 0, -1, 0, 2, -1, 0, 1]),
+    ("SHTHTHSHSSSHSH", [-1, 0, 2, 0, 0, 0, -1, 0, 2, 1, 0, 1]),
+    ("TSHTHTSHSSHSSH", [-1, 0, 2, 0, 0, 0, -1, 1, 3, -1, -1, 3]),
+    ("THTHTHSHSSSHSH", [-1, 0, 2, 0, 0, 0, -1, 1, 3, 1, 1, 3]),
+    ("THTSHTHSSSHSSHSSH", [-1, 0, 2, 0, 0, 0, 1, -1, 3, -1, -1, 3]),
+    ("TSHTSHTSSH", [-1, 0, 2, 0, 0, 0, 1, -1, 3, 1, 1, 3]),
+    ("HTSHTSSH", [-1, 0, 2, 0, 0, 0, 1, 0, 1, -1, 0, 2]),
+    ("HTHTHSHSSSHSH", [-1, 0, 2, 0, 0, 0, 1, 0, 1, 1, 0, 2]),
+    ("SHTSHTHSSSHSSHSSH", [-1, 0, 2, 0, 0, 0, 1, 0, 2, -1, 0, 1]),
+    ("SHTSHTSSH", [-1, 0, 2, 0, 0, 0, 1, 0, 2, 1, 0, 1]),
+    ("THTSHTSSH", [-1, 0, 2, 0, 0, 0, 1, 1, 3, -1, 1, 3]),
+    ("TSHTSHTHSSSHSSHSSH", [-1, 0, 2, 0, 0, 0, 1, 1, 3, 1, -1, 3]),
+    ("SHTSHTSHSSHSH", [-1, 0, 2, 1, 0, 1, -1, 0, 2, 0, 0, 0]),
+    ("TSHTSHTSHSSHSH", [-1, 0, 2, 1, 0, 1, -1, 0, 3, -1, 0, 3]),
+    ("THTSHTSHSSHSH", [-1, 0, 2, 1, 0, 1, -1, 0, 3, 1, 0, 3]),
+    ("HTHTSSHSSHSH", [-1, 0, 2, 1, 0, 1, 0, 0, 0, -1, 0, 2]),
+    ("HTSHTSHSSHSH", [-1, 0, 2, 1, 0, 1, 0, 0, 0, 1, 0, 2]),
+    ("SHTHTSSHSSHSH", [-1, 0, 2, 1, 0, 1, 1, 0, 2, 0, 0, 0]),
+    ("THTHTSSHSSHSH", [-1, 0, 2, 1, 0, 1, 1, 0, 3, -1, 0, 3]),
+    ("TSHTHTSSHSSHSH", [-1, 0, 2, 1, 0, 1, 1, 0, 3, 1, 0, 3]),
+    ("TSHSSHSSHSH", [-1, 0, 2, 1, 0, 2, -1, 0, 1, 0, 0, 0]),
+    ("HSHSSHSSH", [-1, 0, 2, 1, 0, 2, -1, 0, 2, -1, 0, 2]),
+    ("SHSSHSSHSH", [-1, 0, 2, 1, 0, 2, -1, 0, 2, 1, 0, 2]),
+    ("THSHSSHSSH", [-1, 0, 2, 1, 0, 2, 0, 0, 0, -1, 0, 1]),
+    ("THSSHSSHSH", [-1, 0, 2, 1, 0, 2, 0, 0, 0, 1, 0, 1]),
+    ("THSHSSSHSH", [-1, 0, 2, 1, 0, 2, 1, 0, 1, 0, 0, 0]),
+    ("HSHSSSHSH", [-1, 0, 2, 1, 0, 2, 1, 0, 2, -1, 0, 2]),
+    ("HSSHSSHSH", [-1, 0, 2, 1, 0, 2, 1, 0, 2, 1, 0, 2]),
+    ("HTSHSSSHSSHSSH", [-1, 0, 3, -1, -1, 3, -1, 0, 3, -1, 1, 3]),
+    ("THTSHSSSHSSHSSH", [-1, 0, 3, -1, -1, 3, -1, 0, 3, 1, -1, 3]),
+    ("TSHTSHSSSHSSHSSH", [-1, 0, 3, -1, -1, 3, -1, 1, 3, -1, 0, 3]),
+    ("SHTSHSSSHSSH", [-1, 0, 3, -1, -1, 3, -1, 1, 3, 1, 0, 3]),
+    ("SHTSHSSSHSSHSSH", [-1, 0, 3, -1, -1, 3, 1, -1, 3, -1, 0, 3]),
+    ("TSHTSHSSSHSSH", [-1, 0, 3, -1, -1, 3, 1, -1, 3, 1, 0, 3]),
+    ("THTSHSSSHSSH", [-1, 0, 3, -1, -1, 3, 1, 0, 3, -1, 1, 3]),
+    ("HTSHSSSHSSH", [-1, 0, 3, -1, -1, 3, 1, 0, 3, 1, -1, 3]),
+    ("SHTHTHSSHSSH", [-1, 0, 3, -1, 0, 3, -1, -1, 3, -1, 1, 3]),
+    ("HTHTHSSSHSSHSSH", [-1, 0, 3, -1, 0, 3, -1, -1, 3, 1, -1, 3]),
+    ("TSHTHTHSSHSSH", [-1, 0, 3, -1, 0, 3, -1, 0, 1, -1, 0, 2]),
+    ("TSHTSHTSSSHSH", [-1, 0, 3, -1, 0, 3, -1, 0, 1, 1, 0, 2]),
+    ("THTHTHSSSHSSHSSH", [-1, 0, 3, -1, 0, 3, -1, 0, 2, -1, 0, 1]),
+    ("THTHTHSSHSSH", [-1, 0, 3, -1, 0, 3, -1, 0, 2, 1, 0, 1]),
+    ("SHTHTHSSSHSSHSSH", [-1, 0, 3, -1, 0, 3, -1, 1, 3, -1, -1, 3]),
+    ("HTHTHSSHSSH", [-1, 0, 3, -1, 0, 3, -1, 1, 3, 1, 1, 3]),
+    ("HTSHTSHSSSHSSH", [-1, 0, 3, -1, 0, 3, 1, -1, 3, -1, -1, 3]),
+    ("SHTSHTSSSHSH", [-1, 0, 3, -1, 0, 3, 1, -1, 3, 1, 1, 3]),
+    ("TSHTHTHSSSHSSHSSH", [-1, 0, 3, -1, 0, 3, 1, 0, 1, -1, 0, 2]),
+    ("TSHTSHTSHSSSHSSH", [-1, 0, 3, -1, 0, 3, 1, 0, 1, 1, 0, 2]),
+    ("THTSHTSHSSSHSSH", [-1, 0, 3, -1, 0, 3, 1, 0, 2, -1, 0, 1]),
+    ("THTSHTSSSHSH", [-1, 0, 3, -1, 0, 3, 1, 0, 2, 1, 0, 1]),
+    ("HTSHTSSSHSH", [-1, 0, 3, -1, 0, 3, 1, 1, 3, -1, 1, 3]),
+    ("SHTSHTSHSSSHSSH", [-1, 0, 3, -1, 0, 3, 1, 1, 3, 1, -1, 3]),
+    ("TSHTSSHSSHSH", [-1, 0, 3, -1, 1, 3, -1, -1, 3, -1, 0, 3]),
+    ("SHTSSHSSHSH", [-1, 0, 3, -1, 1, 3, -1, -1, 3, 1, 0, 3]),
+    ("HTHSSHSSSHSH", [-1, 0, 3, -1, 1, 3, -1, 0, 3, -1, -1, 3]),
+    ("THTSSHSSHSH", [-1, 0, 3, -1, 1, 3, -1, 0, 3, 1, 1, 3]),
+    ("THTHSSHSSSHSH", [-1, 0, 3, -1, 1, 3, 1, 0, 3, -1, -1, 3]),
+    ("HTSSHSSHSH", [-1, 0, 3, -1, 1, 3, 1, 0, 3, 1, 1, 3]),
+    ("SHTHSSHSSSHSH", [-1, 0, 3, -1, 1, 3, 1, 1, 3, -1, 0, 3]),
+    ("TSHTHSSHSSSHSH", [-1, 0, 3, -1, 1, 3, 1, 1, 3, 1, 0, 3]),
+    ("SHTHSHSSHSSH", [-1, 0, 3, 1, -1, 3, -1, -1, 3, -1, 0, 3]),
+    ("THTHSHSSHSSH", [-1, 0, 3, 1, -1, 3, -1, -1, 3, 1, 0, 3]),
+    ("TSHTHSHSSHSSH", [-1, 0, 3, 1, -1, 3, -1, 0, 3, -1, -1, 3]),
+    ("HTHSHSSHSSH", [-1, 0, 3, 1, -1, 3, -1, 0, 3, 1, 1, 3]),
+    ("HTHSSSHSSH", [-1, 0, 3, 1, -1, 3, 1, 0, 3, -1, -1, 3]),
+    ("TSHTHSSSHSSH", [-1, 0, 3, 1, -1, 3, 1, 0, 3, 1, 1, 3]),
+    ("THTHSSSHSSH", [-1, 0, 3, 1, -1, 3, 1, 1, 3, -1, 0, 3]),
+    ("SHTHSSSHSSH", [-1, 0, 3, 1, -1, 3, 1, 1, 3, 1, 0, 3]),
+    ("HTSHTSHSSHSSH", [-1, 0, 3, 1, 0, 3, -1, -1, 3, -1, 1, 3]),
+    ("SHTHTHSSHSSHSH", [-1, 0, 3, 1, 0, 3, -1, -1, 3, 1, -1, 3]),
+    ("THTSHTSHSSHSSH", [-1, 0, 3, 1, 0, 3, -1, 0, 1, -1, 0, 2]),
+    ("THTHTHSSHSSHSH", [-1, 0, 3, 1, 0, 3, -1, 0, 1, 1, 0, 2]),
+    ("TSHTHTHSSHSSHSH", [-1, 0, 3, 1, 0, 3, -1, 0, 2, -1, 0, 1]),
+    ("TSHTHTHSHSSH", [-1, 0, 3, 1, 0, 3, -1, 0, 2, 1, 0, 1]),
+    ("HTSHTSHSSSH", [-1, 0, 3, 1, 0, 3, -1, 1, 3, -1, -1, 3]),
+    ("SHTHTHSHSSH", [-1, 0, 3, 1, 0, 3, -1, 1, 3, 1, 1, 3]),
+    ("SHTSHTSHSSHSSH", [-1, 0, 3, 1, 0, 3, 1, -1, 3, -1, -1, 3]),
+    ("HTHTHSSHSSHSH", [-1, 0, 3, 1, 0, 3, 1, -1, 3, 1, 1, 3]),
+    ("THTSHTSHSSSH", [-1, 0, 3, 1, 0, 3, 1, 0, 1, -1, 0, 2]),
+    ("THTHTHSHSSH", [-1, 0, 3, 1, 0, 3, 1, 0, 1, 1, 0, 2]),
+    ("TSHTSHTSHSSHSSH", [-1, 0, 3, 1, 0, 3, 1, 0, 2, -1, 0, 1]),
+    ("TSHTSHTSHSSSH", [-1, 0, 3, 1, 0, 3, 1, 0, 2, 1, 0, 1]),
+    ("SHTSHTSHSSSH", [-1, 0, 3, 1, 0, 3, 1, 1, 3, -1, 1, 3]),
+    ("HTHTHSHSSH", [-1, 0, 3, 1, 0, 3, 1, 1, 3, 1, -1, 3]),
+    ("TSHTSHSSHSH", [-1, 0, 3, 1, 1, 3, -1, 0, 3, -1, 1, 3]),
+    ("HTHSSHSSHSH", [-1, 0, 3, 1, 1, 3, -1, 0, 3, 1, -1, 3]),
+    ("SHTHSSHSSHSH", [-1, 0, 3, 1, 1, 3, -1, 1, 3, -1, 0, 3]),
+    ("THTSHSSHSH", [-1, 0, 3, 1, 1, 3, -1, 1, 3, 1, 0, 3]),
+    ("THTHSSHSSHSH", [-1, 0, 3, 1, 1, 3, 1, -1, 3, -1, 0, 3]),
+    ("SHTSHSSHSH", [-1, 0, 3, 1, 1, 3, 1, -1, 3, 1, 0, 3]),
+    ("HTSHSSHSH", [-1, 0, 3, 1, 1, 3, 1, 0, 3, -1, 1, 3]),
+    ("TSHTHSSHSSHSH", [-1, 0, 3, 1, 1, 3, 1, 0, 3, 1, -1, 3]),
+    ("THTSHTSSSHSSHSSH", [-1, 1, 3, -1, -1, 3, -1, 0, 2, 0, 0, 0]),
+    ("SHTSHTSSSHSSHSSH", [-1, 1, 3, -1, -1, 3, -1, 0, 3, -1, 0, 3]),
+    ("HTSHTSSSHSSHSSH", [-1, 1, 3, -1, -1, 3, -1, 0, 3, 1, 0, 3]),
+    ("TSHTSHTSSSHSSHSSH", [-1, 1, 3, -1, -1, 3, 0, 0, 0, -1, 0, 2]),
+    ("TSHTHTSSHSSSHSSH", [-1, 1, 3, -1, -1, 3, 0, 0, 0, 1, 0, 2]),
+    ("THTHTSSHSSSHSSH", [-1, 1, 3, -1, -1, 3, 1, 0, 2, 0, 0, 0]),
+    ("HTHTSSHSSSHSSH", [-1, 1, 3, -1, -1, 3, 1, 0, 3, -1, 0, 3]),
+    ("SHTHTSSHSSSHSSH", [-1, 1, 3, -1, -1, 3, 1, 0, 3, 1, 0, 3]),
+    ("HTHSHSSSHSSH", [-1, 1, 3, -1, 0, 3, -1, -1, 3, -1, 0, 3]),
+    ("TSHTSSH", [-1, 1, 3, -1, 0, 3, -1, -1, 3, 1, 0, 3]),
+    ("THTHSHSSSHSSH", [-1, 1, 3, -1, 0, 3, -1, 0, 3, -1, -1, 3]),
+    ("SHTSSH", [-1, 1, 3, -1, 0, 3, -1, 0, 3, 1, 1, 3]),
+    ("SHTHSHSSSHSSH", [-1, 1, 3, -1, 0, 3, 1, 0, 3, -1, -1, 3]),
+    ("THTSSH", [-1, 1, 3, -1, 0, 3, 1, 0, 3, 1, 1, 3]),
+    ("TSHTHSHSSSHSSH", [-1, 1, 3, -1, 0, 3, 1, 1, 3, -1, 0, 3]),
+    ("HTSSH", [-1, 1, 3, -1, 0, 3, 1, 1, 3, 1, 0, 3]),
+    ("THTHSHSSSHSH", [-1, 1, 3, 1, 0, 3, -1, -1, 3, -1, 0, 3]),
+    ("HTHSHSSSHSH", [-1, 1, 3, 1, 0, 3, -1, -1, 3, 1, 0, 3]),
+    ("SHTHSHSSSHSH", [-1, 1, 3, 1, 0, 3, -1, 0, 3, -1, -1, 3]),
+    ("TSHTSH", [-1, 1, 3, 1, 0, 3, -1, 0, 3, 1, 1, 3]),
+    ("TSHTHSHSSSHSH", [-1, 1, 3, 1, 0, 3, 1, 0, 3, -1, -1, 3]),
+    ("SHTSH", [-1, 1, 3, 1, 0, 3, 1, 0, 3, 1, 1, 3]),
+    ("HTSH", [-1, 1, 3, 1, 0, 3, 1, 1, 3, -1, 0, 3]),
+    ("THTSH", [-1, 1, 3, 1, 0, 3, 1, 1, 3, 1, 0, 3]),
+    ("TSHTSHTSHSH", [-1, 1, 3, 1, 1, 3, -1, 0, 2, 0, 0, 0]),
+    ("HTHTSSHSH", [-1, 1, 3, 1, 1, 3, -1, 0, 3, -1, 0, 3]),
+    ("SHTSHTSHSH", [-1, 1, 3, 1, 1, 3, -1, 0, 3, 1, 0, 3]),
+    ("THTHTSSHSH", [-1, 1, 3, 1, 1, 3, 0, 0, 0, -1, 0, 2]),
+    ("THTSHTSHSH", [-1, 1, 3, 1, 1, 3, 0, 0, 0, 1, 0, 2]),
+    ("TSHTHTSSHSH", [-1, 1, 3, 1, 1, 3, 1, 0, 2, 0, 0, 0]),
+    ("SHTHTSSHSH", [-1, 1, 3, 1, 1, 3, 1, 0, 3, -1, 0, 3]),
+    ("HTSHTSHSH", [-1, 1, 3, 1, 1, 3, 1, 0, 3, 1, 0, 3]),
+    ("HSSHSSSHSSH", [0, 0, 0, -1, 0, 0, 0, 0, 0, 0, 0, 0]),
+    ("SHSSSHSSH", [0, 0, 0, -1, 0, 1, -1, 0, 1, 0, 0, 0]),
+    ("TSHSSSHSSH", [0, 0, 0, -1, 0, 1, -1, 0, 2, -1, 0, 2]),
+    ("THSSSHSSH", [0, 0, 0, -1, 0, 1, -1, 0, 2, 1, 0, 2]),
+    ("SSHSSSHSSH", [0, 0, 0, -1, 0, 1, 0, 0, 0, -1, 0, 1]),
+    ("HSSSHSSH", [0, 0, 0, -1, 0, 1, 0, 0, 0, 1, 0, 1]),
+    ("HSSHSSSH", [0, 0, 0, -1, 0, 1, 1, 0, 1, 0, 0, 0]),
+    ("TSSHSSSHSSH", [0, 0, 0, -1, 0, 1, 1, 0, 2, -1, 0, 2]),
+    ("THSSHSSSH", [0, 0, 0, -1, 0, 1, 1, 0, 2, 1, 0, 2]),
+    ("THTHTHSSHSSSHSH", [0, 0, 0, -1, 0, 2, -1, -1, 3, -1, 1, 3]),
+    ("TSHTSHTHSHSSH", [0, 0, 0, -1, 0, 2, -1, -1, 3, 1, -1, 3]),
+    ("SHTHTHSSHSSSHSH", [0, 0, 0, -1, 0, 2, -1, 0, 1, -1, 0, 2]),
+    ("SHTSHTHSHSSH", [0, 0, 0, -1, 0, 2, -1, 0, 1, 1, 0, 2]),
+    ("HTHTSHSSSHSSH", [0, 0, 0, -1, 0, 2, -1, 0, 2, -1, 0, 1]),
+    ("HTHTHSSHSSSHSH", [0, 0, 0, -1, 0, 2, -1, 0, 2, 1, 0, 1]),
+    ("THTHTSHSSSHSSH", [0, 0, 0, -1, 0, 2, -1, 1, 3, -1, -1, 3]),
+    ("TSHTSHTSSHSSSH", [0, 0, 0, -1, 0, 2, -1, 1, 3, 1, 1, 3]),
+    ("TSHTHTHSSHSSSHSH", [0, 0, 0, -1, 0, 2, 1, -1, 3, -1, -1, 3]),
+    ("THTSHTHSHSSH", [0, 0, 0, -1, 0, 2, 1, -1, 3, 1, 1, 3]),
+    ("SHTHTSHSSSHSSH", [0, 0, 0, -1, 0, 2, 1, 0, 1, -1, 0, 2]),
+    ("SHTSHTSSHSSSH", [0, 0, 0, -1, 0, 2, 1, 0, 1, 1, 0, 2]),
+    ("HTSHTSSHSSSH", [0, 0, 0, -1, 0, 2, 1, 0, 2, -1, 0, 1]),
+    ("HTSHTHSHSSH", [0, 0, 0, -1, 0, 2, 1, 0, 2, 1, 0, 1]),
+    ("TSHTHTSHSSSHSSH", [0, 0, 0, -1, 0, 2, 1, 1, 3, -1, 1, 3]),
+    ("THTSHTSSHSSSH", [0, 0, 0, -1, 0, 2, 1, 1, 3, 1, -1, 3]),
+    ("SSHSSH", [0, 0, 0, 0, 0, 0, -1, 0, 0, 0, 0, 0]),
+    ("SSHSSSHSH", [0, 0, 0, 0, 0, 0, -1, 0, 1, -1, 0, 1]),
+    ("SHSSSHSH", [0, 0, 0, 0, 0, 0, -1, 0, 1, 1, 0, 1]),
+    ("SSSHSSH", [0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, 0]),
+    ("SHSSH", [0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0]),
+    ("HSSH", [0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0]),
+    ("HSHSSSH", [0, 0, 0, 0, 0, 0, 1, 0, 1, -1, 0, 1]),
+    ("HSSSHSH", [0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 1]),
+    ("HSHSSHSH", [0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+    ("SSHSSHSH", [0, 0, 0, 1, 0, 1, -1, 0, 1, 0, 0, 0]),
+    ("TSSHSSHSH", [0, 0, 0, 1, 0, 1, -1, 0, 2, -1, 0, 2]),
+    ("TSHSSHSH", [0, 0, 0, 1, 0, 1, -1, 0, 2, 1, 0, 2]),
+    ("HSHSSH", [0, 0, 0, 1, 0, 1, 0, 0, 0, -1, 0, 1]),
+    ("SHSSHSH", [0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1]),
+    ("HSSHSH", [0, 0, 0, 1, 0, 1, 1, 0, 1, 0, 0, 0]),
+    ("THSHSSH", [0, 0, 0, 1, 0, 1, 1, 0, 2, -1, 0, 2]),
+    ("THSSHSH", [0, 0, 0, 1, 0, 1, 1, 0, 2, 1, 0, 2]),
+    ("THTSHTHSSHSSHSH", [0, 0, 0, 1, 0, 2, -1, -1, 3, -1, 1, 3]),
+    ("TSHTHTHSSHSH", [0, 0, 0, 1, 0, 2, -1, -1, 3, 1, -1, 3]),
+    ("SHTSHTHSSHSSHSH", [0, 0, 0, 1, 0, 2, -1, 0, 1, -1, 0, 2]),
+    ("SHTHTHSSHSH", [0, 0, 0, 1, 0, 2, -1, 0, 1, 1, 0, 2]),
+    ("HTSHTHSSSHSSH", [0, 0, 0, 1, 0, 2, -1, 0, 2, -1, 0, 1]),
+    ("HTSHTHSSHSSHSH", [0, 0, 0, 1, 0, 2, -1, 0, 2, 1, 0, 1]),
+    ("THTSHTHSSSHSSH", [0, 0, 0, 1, 0, 2, -1, 1, 3, -1, -1, 3]),
+    ("TSHTHTSSSHSH", [0, 0, 0, 1, 0, 2, -1, 1, 3, 1, 1, 3]),
+    ("TSHTSHTHSSHSSHSH", [0, 0, 0, 1, 0, 2, 1, -1, 3, -1, -1, 3]),
+    ("THTHTHSSHSH", [0, 0, 0, 1, 0, 2, 1, -1, 3, 1, 1, 3]),
+    ("SHTSHTHSSSHSSH", [0, 0, 0, 1, 0, 2, 1, 0, 1, -1, 0, 2]),
+    ("SHTHTSSSHSH", [0, 0, 0, 1, 0, 2, 1, 0, 1, 1, 0, 2]),
+    ("HTHTSSSHSH", [0, 0, 0, 1, 0, 2, 1, 0, 2, -1, 0, 1]),
+    ("HTHTHSSHSH", [0, 0, 0, 1, 0, 2, 1, 0, 2, 1, 0, 1]),
+    ("TSHTSHTHSSSHSSH", [0, 0, 0, 1, 0, 2, 1, 1, 3, -1, 1, 3]),
+    ("THTHTSSSHSH", [0, 0, 0, 1, 0, 2, 1, 1, 3, 1, -1, 3]),
+    ("TSHTHTHSHSSSHSSH", [1, -1, 3, -1, -1, 3, -1, 0, 2, 0, 0, 0]),
+    ("HTSHTSHSSSHSSHSSH", [1, -1, 3, -1, -1, 3, -1, 0, 3, -1, 0, 3]),
+    ("SHTHTHSHSSSHSSH", [1, -1, 3, -1, -1, 3, -1, 0, 3, 1, 0, 3]),
+    ("THTSHTSHSSSHSSHSSH", [1, -1, 3, -1, -1, 3, 0, 0, 0, -1, 0, 2]),
+    ("THTHTHSHSSSHSSH", [1, -1, 3, -1, -1, 3, 0, 0, 0, 1, 0, 2]),
+    ("TSHTSHTSHSSSHSSHSSH", [1, -1, 3, -1, -1, 3, 1, 0, 2, 0, 0, 0]),
+    ("SHTSHTSHSSSHSSHSSH", [1, -1, 3, -1, -1, 3, 1, 0, 3, -1, 0, 3]),
+    ("HTHTHSHSSSHSSH", [1, -1, 3, -1, -1, 3, 1, 0, 3, 1, 0, 3]),
+    ("THTSSSHSSHSSH", [1, -1, 3, -1, 0, 3, -1, -1, 3, -1, 0, 3]),
+    ("HTSSSHSSHSSH", [1, -1, 3, -1, 0, 3, -1, -1, 3, 1, 0, 3]),
+    ("SHTSSSHSSHSSH", [1, -1, 3, -1, 0, 3, -1, 0, 3, -1, -1, 3]),
+    ("TSHTSHSSSH", [1, -1, 3, -1, 0, 3, -1, 0, 3, 1, 1, 3]),
+    ("TSHTSSSHSSHSSH", [1, -1, 3, -1, 0, 3, 1, 0, 3, -1, -1, 3]),
+    ("SHTSHSSSH", [1, -1, 3, -1, 0, 3, 1, 0, 3, 1, 1, 3]),
+    ("HTSHSSSH", [1, -1, 3, -1, 0, 3, 1, 1, 3, -1, 0, 3]),
+    ("THTSHSSSH", [1, -1, 3, -1, 0, 3, 1, 1, 3, 1, 0, 3]),
+    ("HTHSSHSSH", [1, -1, 3, 1, 0, 3, -1, -1, 3, -1, 0, 3]),
+    ("TSHTSSHSH", [1, -1, 3, 1, 0, 3, -1, -1, 3, 1, 0, 3]),
+    ("THTHSSHSSH", [1, -1, 3, 1, 0, 3, -1, 0, 3, -1, -1, 3]),
+    ("SHTSSHSH", [1, -1, 3, 1, 0, 3, -1, 0, 3, 1, 1, 3]),
+    ("SHTHSSHSSH", [1, -1, 3, 1, 0, 3, 1, 0, 3, -1, -1, 3]),
+    ("THTSSHSH", [1, -1, 3, 1, 0, 3, 1, 0, 3, 1, 1, 3]),
+    ("TSHTHSSHSSH", [1, -1, 3, 1, 0, 3, 1, 1, 3, -1, 0, 3]),
+    ("HTSSHSH", [1, -1, 3, 1, 0, 3, 1, 1, 3, 1, 0, 3]),
+    ("THTHTSHSSHSH", [1, -1, 3, 1, 1, 3, -1, 0, 2, 0, 0, 0]),
+    ("SHTHTSHSSHSH", [1, -1, 3, 1, 1, 3, -1, 0, 3, -1, 0, 3]),
+    ("HTHTSHSSHSH", [1, -1, 3, 1, 1, 3, -1, 0, 3, 1, 0, 3]),
+    ("TSHTHTSHSSHSH", [1, -1, 3, 1, 1, 3, 0, 0, 0, -1, 0, 2]),
+    ("TSHTSHTSH", [1, -1, 3, 1, 1, 3, 0, 0, 0, 1, 0, 2]),
+    ("THTSHTSH", [1, -1, 3, 1, 1, 3, 1, 0, 2, 0, 0, 0]),
+    ("HTSHTSH", [1, -1, 3, 1, 1, 3, 1, 0, 3, -1, 0, 3]),
+    ("SHTSHTSH", [1, -1, 3, 1, 1, 3, 1, 0, 3, 1, 0, 3]),
+    ("I", [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    ("HSSSHSSSH", [1, 0, 1, -1, 0, 1, 0, 0, 0, 0, 0, 0]),
+    ("HTSHTHSHSSSHSSH", [1, 0, 1, -1, 0, 2, -1, 0, 2, 0, 0, 0]),
+    ("THTSHTHSHSSSHSSH", [1, 0, 1, -1, 0, 2, -1, 0, 3, -1, 0, 3]),
+    ("TSHTHTSSSHSSSH", [1, 0, 1, -1, 0, 2, -1, 0, 3, 1, 0, 3]),
+    ("SHTSHTHSHSSSHSSH", [1, 0, 1, -1, 0, 2, 0, 0, 0, -1, 0, 2]),
+    ("SHTHTSSSHSSSH", [1, 0, 1, -1, 0, 2, 0, 0, 0, 1, 0, 2]),
+    ("HTHTSSSHSSSH", [1, 0, 1, -1, 0, 2, 1, 0, 2, 0, 0, 0]),
+    ("TSHTSHTHSHSSSHSSH", [1, 0, 1, -1, 0, 2, 1, 0, 3, -1, 0, 3]),
+    ("THTHTSSSHSSSH", [1, 0, 1, -1, 0, 2, 1, 0, 3, 1, 0, 3]),
+    ("SSH", [1, 0, 1, 0, 0, 0, -1, 0, 1, 0, 0, 0]),
+    ("TSSH", [1, 0, 1, 0, 0, 0, -1, 0, 2, -1, 0, 2]),
+    ("TSH", [1, 0, 1, 0, 0, 0, -1, 0, 2, 1, 0, 2]),
+    ("SSSH", [1, 0, 1, 0, 0, 0, 0, 0, 0, -1, 0, 1]),
+    ("SH", [1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1]),
+    ("H", [1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0]),
+    ("TSSSH", [1, 0, 1, 0, 0, 0, 1, 0, 2, -1, 0, 2]),
+    ("TH", [1, 0, 1, 0, 0, 0, 1, 0, 2, 1, 0, 2]),
+    ("HSHSH", [1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0]),
+    ("HTHTSH", [1, 0, 1, 1, 0, 2, -1, 0, 2, 0, 0, 0]),
+    ("THTHTSH", [1, 0, 1, 1, 0, 2, -1, 0, 3, -1, 0, 3]),
+    ("TSHTSHTH", [1, 0, 1, 1, 0, 2, -1, 0, 3, 1, 0, 3]),
+    ("SHTHTSH", [1, 0, 1, 1, 0, 2, 0, 0, 0, -1, 0, 2]),
+    ("SHTSHTH", [1, 0, 1, 1, 0, 2, 0, 0, 0, 1, 0, 2]),
+    ("HTSHTH", [1, 0, 1, 1, 0, 2, 1, 0, 2, 0, 0, 0]),
+    ("TSHTHTSH", [1, 0, 1, 1, 0, 2, 1, 0, 3, -1, 0, 3]),
+    ("THTSHTH", [1, 0, 1, 1, 0, 2, 1, 0, 3, 1, 0, 3]),
+    ("SHTHTHSSHSSSH", [1, 0, 2, -1, 0, 1, -1, 0, 2, 0, 0, 0]),
+    ("TSHTHTHSSHSSSH", [1, 0, 2, -1, 0, 1, -1, 0, 3, -1, 0, 3]),
+    ("THTHTHSSHSSSH", [1, 0, 2, -1, 0, 1, -1, 0, 3, 1, 0, 3]),
+    ("HTSHTSSHSSSHSSH", [1, 0, 2, -1, 0, 1, 0, 0, 0, -1, 0, 2]),
+    ("HTHTHSSHSSSH", [1, 0, 2, -1, 0, 1, 0, 0, 0, 1, 0, 2]),
+    ("SHTSHTSSHSSSHSSH", [1, 0, 2, -1, 0, 1, 1, 0, 2, 0, 0, 0]),
+    ("THTSHTSSHSSSHSSH", [1, 0, 2, -1, 0, 1, 1, 0, 3, -1, 0, 3]),
+    ("TSHTSHTSSHSSSHSSH", [1, 0, 2, -1, 0, 1, 1, 0, 3, 1, 0, 3]),
+    ("TSHSSSH", [1, 0, 2, -1, 0, 2, -1, 0, 1, 0, 0, 0]),
+    ("SSHSSSH", [1, 0, 2, -1, 0, 2, -1, 0, 2, -1, 0, 2]),
+    ("SHSSSH", [1, 0, 2, -1, 0, 2, -1, 0, 2, 1, 0, 2]),
+    ("TSSHSSSH", [1, 0, 2, -1, 0, 2, 0, 0, 0, -1, 0, 1]),
+    ("THSSSH", [1, 0, 2, -1, 0, 2, 0, 0, 0, 1, 0, 1]),
+    ("TSSSHSSSH", [1, 0, 2, -1, 0, 2, 1, 0, 1, 0, 0, 0]),
+    ("SSSHSSSH", [1, 0, 2, -1, 0, 2, 1, 0, 2, -1, 0, 2]),
+    ("HSSSH", [1, 0, 2, -1, 0, 2, 1, 0, 2, 1, 0, 2]),
+    ("TSHTSHTHSH", [1, 0, 2, 0, 0, 0, -1, -1, 3, -1, 1, 3]),
+    ("THTSHTHSSHSSH", [1, 0, 2, 0, 0, 0, -1, -1, 3, 1, -1, 3]),
+    ("HTHTSHSSSH", [1, 0, 2, 0, 0, 0, -1, 0, 1, -1, 0, 2]),
+    ("HTSHTHSSHSSH", [1, 0, 2, 0, 0, 0, -1, 0, 1, 1, 0, 2]),
+    ("SHTSHTHSSHSSH", [1, 0, 2, 0, 0, 0, -1, 0, 2, -1, 0, 1]),
+    ("SHTSHTHSH", [1, 0, 2, 0, 0, 0, -1, 0, 2, 1, 0, 1]),
+    ("TSHTSHTHSSHSSH", [1, 0, 2, 0, 0, 0, -1, 1, 3, -1, -1, 3]),
+    ("THTSHTHSH", [1, 0, 2, 0, 0, 0, -1, 1, 3, 1, 1, 3]),
+    ("THTHTSHSSSH", [1, 0, 2, 0, 0, 0, 1, -1, 3, -1, -1, 3]),
+    ("TSHTHTSSSH", [1, 0, 2, 0, 0, 0, 1, -1, 3, 1, 1, 3]),
+    ("HTHTSSSH", [1, 0, 2, 0, 0, 0, 1, 0, 1, -1, 0, 2]),
+    ("HTSHTHSH", [1, 0, 2, 0, 0, 0, 1, 0, 1, 1, 0, 2]),
+    ("SHTHTSHSSSH", [1, 0, 2, 0, 0, 0, 1, 0, 2, -1, 0, 1]),
+    ("SHTHTSSSH", [1, 0, 2, 0, 0, 0, 1, 0, 2, 1, 0, 1]),
+    ("THTHTSSSH", [1, 0, 2, 0, 0, 0, 1, 1, 3, -1, 1, 3]),
+    ("TSHTHTSHSSSH", [1, 0, 2, 0, 0, 0, 1, 1, 3, 1, -1, 3]),
+    ("SHTHTSHSH", [1, 0, 2, 1, 0, 1, -1, 0, 2, 0, 0, 0]),
+    ("TSHTHTSHSH", [1, 0, 2, 1, 0, 1, -1, 0, 3, -1, 0, 3]),
+    ("THTHTSHSH", [1, 0, 2, 1, 0, 1, -1, 0, 3, 1, 0, 3]),
+    ("HTSHTHSSSH", [1, 0, 2, 1, 0, 1, 0, 0, 0, -1, 0, 2]),
+    ("HTHTSHSH", [1, 0, 2, 1, 0, 1, 0, 0, 0, 1, 0, 2]),
+    ("SHTSHTHSSSH", [1, 0, 2, 1, 0, 1, 1, 0, 2, 0, 0, 0]),
+    ("THTSHTHSSSH", [1, 0, 2, 1, 0, 1, 1, 0, 3, -1, 0, 3]),
+    ("TSHTSHTHSSSH", [1, 0, 2, 1, 0, 1, 1, 0, 3, 1, 0, 3]),
+    ("TSSHSH", [1, 0, 2, 1, 0, 2, -1, 0, 1, 0, 0, 0]),
+    ("SSSHSH", [1, 0, 2, 1, 0, 2, -1, 0, 2, -1, 0, 2]),
+    ("SSHSH", [1, 0, 2, 1, 0, 2, -1, 0, 2, 1, 0, 2]),
+    ("TSSSHSH", [1, 0, 2, 1, 0, 2, 0, 0, 0, -1, 0, 1]),
+    ("TSHSH", [1, 0, 2, 1, 0, 2, 0, 0, 0, 1, 0, 1]),
+    ("THSH", [1, 0, 2, 1, 0, 2, 1, 0, 1, 0, 0, 0]),
+    ("HSH", [1, 0, 2, 1, 0, 2, 1, 0, 2, -1, 0, 2]),
+    ("SHSH", [1, 0, 2, 1, 0, 2, 1, 0, 2, 1, 0, 2]),
+    ("TSHTSSHSSSH", [1, 0, 3, -1, -1, 3, -1, 0, 3, -1, 1, 3]),
+    ("HTSSHSSSHSSH", [1, 0, 3, -1, -1, 3, -1, 0, 3, 1, -1, 3]),
+    ("SHTSSHSSSHSSH", [1, 0, 3, -1, -1, 3, -1, 1, 3, -1, 0, 3]),
+    ("THTSSHSSSH", [1, 0, 3, -1, -1, 3, -1, 1, 3, 1, 0, 3]),
+    ("THTSSHSSSHSSH", [1, 0, 3, -1, -1, 3, 1, -1, 3, -1, 0, 3]),
+    ("SHTSSHSSSH", [1, 0, 3, -1, -1, 3, 1, -1, 3, 1, 0, 3]),
+    ("HTSSHSSSH", [1, 0, 3, -1, -1, 3, 1, 0, 3, -1, 1, 3]),
+    ("TSHTSSHSSSHSSH", [1, 0, 3, -1, -1, 3, 1, 0, 3, 1, -1, 3]),
+    ("HTHTHSSSHSSH", [1, 0, 3, -1, 0, 3, -1, -1, 3, -1, 1, 3]),
+    ("SHTSHTHSHSSSHSH", [1, 0, 3, -1, 0, 3, -1, -1, 3, 1, -1, 3]),
+    ("THTHTHSSSHSSH", [1, 0, 3, -1, 0, 3, -1, 0, 1, -1, 0, 2]),
+    ("THTSHTHSHSSSHSH", [1, 0, 3, -1, 0, 3, -1, 0, 1, 1, 0, 2]),
+    ("TSHTSHTHSHSSSHSH", [1, 0, 3, -1, 0, 3, -1, 0, 2, -1, 0, 1]),
+    ("TSHTSHTSSSH", [1, 0, 3, -1, 0, 3, -1, 0, 2, 1, 0, 1]),
+    ("HTHTSSHSSSH", [1, 0, 3, -1, 0, 3, -1, 1, 3, -1, -1, 3]),
+    ("SHTSHTSSSH", [1, 0, 3, -1, 0, 3, -1, 1, 3, 1, 1, 3]),
+    ("SHTHTHSSSHSSH", [1, 0, 3, -1, 0, 3, 1, -1, 3, -1, -1, 3]),
+    ("HTSHTHSHSSSHSH", [1, 0, 3, -1, 0, 3, 1, -1, 3, 1, 1, 3]),
+    ("THTHTSSHSSSH", [1, 0, 3, -1, 0, 3, 1, 0, 1, -1, 0, 2]),
+    ("THTSHTSSSH", [1, 0, 3, -1, 0, 3, 1, 0, 1, 1, 0, 2]),
+    ("TSHTHTHSSSHSSH", [1, 0, 3, -1, 0, 3, 1, 0, 2, -1, 0, 1]),
+    ("TSHTHTSSHSSSH", [1, 0, 3, -1, 0, 3, 1, 0, 2, 1, 0, 1]),
+    ("SHTHTSSHSSSH", [1, 0, 3, -1, 0, 3, 1, 1, 3, -1, 1, 3]),
+    ("HTSHTSSSH", [1, 0, 3, -1, 0, 3, 1, 1, 3, 1, -1, 3]),
+    ("SHTHSHSSH", [1, 0, 3, -1, 1, 3, -1, -1, 3, -1, 0, 3]),
+    ("THTHSHSSH", [1, 0, 3, -1, 1, 3, -1, -1, 3, 1, 0, 3]),
+    ("TSHTHSHSSH", [1, 0, 3, -1, 1, 3, -1, 0, 3, -1, -1, 3]),
+    ("HTHSHSSH", [1, 0, 3, -1, 1, 3, -1, 0, 3, 1, 1, 3]),
+    ("HTHSSSH", [1, 0, 3, -1, 1, 3, 1, 0, 3, -1, -1, 3]),
+    ("TSHTHSSSH", [1, 0, 3, -1, 1, 3, 1, 0, 3, 1, 1, 3]),
+    ("THTHSSSH", [1, 0, 3, -1, 1, 3, 1, 1, 3, -1, 0, 3]),
+    ("SHTHSSSH", [1, 0, 3, -1, 1, 3, 1, 1, 3, 1, 0, 3]),
+    ("TSHTSSSHSH", [1, 0, 3, 1, -1, 3, -1, -1, 3, -1, 0, 3]),
+    ("SHTSSSHSH", [1, 0, 3, 1, -1, 3, -1, -1, 3, 1, 0, 3]),
+    ("HTHSSHSSSH", [1, 0, 3, 1, -1, 3, -1, 0, 3, -1, -1, 3]),
+    ("THTSSSHSH", [1, 0, 3, 1, -1, 3, -1, 0, 3, 1, 1, 3]),
+    ("THTHSSHSSSH", [1, 0, 3, 1, -1, 3, 1, 0, 3, -1, -1, 3]),
+    ("HTSSSHSH", [1, 0, 3, 1, -1, 3, 1, 0, 3, 1, 1, 3]),
+    ("SHTHSSHSSSH", [1, 0, 3, 1, -1, 3, 1, 1, 3, -1, 0, 3]),
+    ("TSHTHSSHSSSH", [1, 0, 3, 1, -1, 3, 1, 1, 3, 1, 0, 3]),
+    ("SHTSHTHSSHSH", [1, 0, 3, 1, 0, 3, -1, -1, 3, -1, 1, 3]),
+    ("HTSHTHSSHSSSHSH", [1, 0, 3, 1, 0, 3, -1, -1, 3, 1, -1, 3]),
+    ("TSHTSHTHSSHSH", [1, 0, 3, 1, 0, 3, -1, 0, 1, -1, 0, 2]),
+    ("TSHTHTHSH", [1, 0, 3, 1, 0, 3, -1, 0, 1, 1, 0, 2]),
+    ("THTSHTHSSHSSSHSH", [1, 0, 3, 1, 0, 3, -1, 0, 2, -1, 0, 1]),
+    ("THTSHTHSSHSH", [1, 0, 3, 1, 0, 3, -1, 0, 2, 1, 0, 1]),
+    ("SHTSHTHSSHSSSHSH", [1, 0, 3, 1, 0, 3, -1, 1, 3, -1, -1, 3]),
+    ("HTSHTHSSHSH", [1, 0, 3, 1, 0, 3, -1, 1, 3, 1, 1, 3]),
+    ("HTHTSSH", [1, 0, 3, 1, 0, 3, 1, -1, 3, -1, -1, 3]),
+    ("SHTHTHSH", [1, 0, 3, 1, 0, 3, 1, -1, 3, 1, 1, 3]),
+    ("TSHTSHTHSSHSSSHSH", [1, 0, 3, 1, 0, 3, 1, 0, 1, -1, 0, 2]),
+    ("TSHTHTSSH", [1, 0, 3, 1, 0, 3, 1, 0, 1, 1, 0, 2]),
+    ("THTHTSSH", [1, 0, 3, 1, 0, 3, 1, 0, 2, -1, 0, 1]),
+    ("THTHTHSH", [1, 0, 3, 1, 0, 3, 1, 0, 2, 1, 0, 1]),
+    ("HTHTHSH", [1, 0, 3, 1, 0, 3, 1, 1, 3, -1, 1, 3]),
+    ("SHTHTSSH", [1, 0, 3, 1, 0, 3, 1, 1, 3, 1, -1, 3]),
+    ("HTHSSHSH", [1, 0, 3, 1, 1, 3, -1, 0, 3, -1, 1, 3]),
+    ("THTHSSHSH", [1, 0, 3, 1, 1, 3, -1, 0, 3, 1, -1, 3]),
+    ("TSHTHSSHSH", [1, 0, 3, 1, 1, 3, -1, 1, 3, -1, 0, 3]),
+    ("SHTSHSH", [1, 0, 3, 1, 1, 3, -1, 1, 3, 1, 0, 3]),
+    ("SHTHSSHSH", [1, 0, 3, 1, 1, 3, 1, -1, 3, -1, 0, 3]),
+    ("TSHTSHSH", [1, 0, 3, 1, 1, 3, 1, -1, 3, 1, 0, 3]),
+    ("THTSHSH", [1, 0, 3, 1, 1, 3, 1, 0, 3, -1, 1, 3]),
+    ("HTSHSH", [1, 0, 3, 1, 1, 3, 1, 0, 3, 1, -1, 3]),
+    ("SHTSSSH", [1, 1, 3, -1, 0, 3, -1, 0, 3, -1, 1, 3]),
+    ("TSHTSSSH", [1, 1, 3, -1, 0, 3, -1, 0, 3, 1, -1, 3]),
+    ("THTSSSHSSSH", [1, 1, 3, -1, 0, 3, -1, 1, 3, -1, 0, 3]),
+    ("HTSSSH", [1, 1, 3, -1, 0, 3, -1, 1, 3, 1, 0, 3]),
+    ("HTSSSHSSSH", [1, 1, 3, -1, 0, 3, 1, -1, 3, -1, 0, 3]),
+    ("THTSSSH", [1, 1, 3, -1, 0, 3, 1, -1, 3, 1, 0, 3]),
+    ("TSHTSSSHSSSH", [1, 1, 3, -1, 0, 3, 1, 0, 3, -1, 1, 3]),
+    ("SHTSSSHSSSH", [1, 1, 3, -1, 0, 3, 1, 0, 3, 1, -1, 3]),
+    ("TSHTHTH", [1, 1, 3, -1, 1, 3, -1, 0, 2, 0, 0, 0]),
+    ("HTSHTHSSHSSSH", [1, 1, 3, -1, 1, 3, -1, 0, 3, -1, 0, 3]),
+    ("SHTHTH", [1, 1, 3, -1, 1, 3, -1, 0, 3, 1, 0, 3]),
+    ("THTSHTHSSHSSSH", [1, 1, 3, -1, 1, 3, 0, 0, 0, -1, 0, 2]),
+    ("THTHTH", [1, 1, 3, -1, 1, 3, 0, 0, 0, 1, 0, 2]),
+    ("TSHTSHTHSSHSSSH", [1, 1, 3, -1, 1, 3, 1, 0, 2, 0, 0, 0]),
+    ("SHTSHTHSSHSSSH", [1, 1, 3, -1, 1, 3, 1, 0, 3, -1, 0, 3]),
+    ("HTHTH", [1, 1, 3, -1, 1, 3, 1, 0, 3, 1, 0, 3]),
+    ("THTHTHSSSH", [1, 1, 3, 1, -1, 3, -1, 0, 2, 0, 0, 0]),
+    ("SHTHTHSSSH", [1, 1, 3, 1, -1, 3, -1, 0, 3, -1, 0, 3]),
+    ("HTHTHSSSH", [1, 1, 3, 1, -1, 3, -1, 0, 3, 1, 0, 3]),
+    ("TSHTHTHSSSH", [1, 1, 3, 1, -1, 3, 0, 0, 0, -1, 0, 2]),
+    ("TSHTSHTSSSHSSSH", [1, 1, 3, 1, -1, 3, 0, 0, 0, 1, 0, 2]),
+    ("THTSHTSSSHSSSH", [1, 1, 3, 1, -1, 3, 1, 0, 2, 0, 0, 0]),
+    ("HTSHTSSSHSSSH", [1, 1, 3, 1, -1, 3, 1, 0, 3, -1, 0, 3]),
+    ("SHTSHTSSSHSSSH", [1, 1, 3, 1, -1, 3, 1, 0, 3, 1, 0, 3]),
+    ("THTHSH", [1, 1, 3, 1, 0, 3, -1, 0, 3, -1, 1, 3]),
+    ("SHTHSH", [1, 1, 3, 1, 0, 3, -1, 0, 3, 1, -1, 3]),
+    ("HTH", [1, 1, 3, 1, 0, 3, -1, 1, 3, -1, 0, 3]),
+    ("TSHTH", [1, 1, 3, 1, 0, 3, -1, 1, 3, 1, 0, 3]),
+    ("TSHTHSH", [1, 1, 3, 1, 0, 3, 1, -1, 3, -1, 0, 3]),
+    ("HTHSH", [1, 1, 3, 1, 0, 3, 1, -1, 3, 1, 0, 3]),
+    ("SHTH", [1, 1, 3, 1, 0, 3, 1, 0, 3, -1, 1, 3]),
+    ("THTH", [1, 1, 3, 1, 0, 3, 1, 0, 3, 1, -1, 3]),
+];
@@ -21,5 +21,8 @@ fn main() {
 
     // Create the hash table
     // crate::algorithms::exact_synth_hashtable_lookup::generate_gate_table();
+
+    // Write the residual table that exact_synth compiles in
+    // crate::algorithms::exact_synth_hashtable_lookup::write_residual_table();
     
 }
@@ -0,0 +1,489 @@
+// Clifford+T circuits
+//
+// Until now gate sequences were strings of letters, like "HTHT".
+// A Circuit is the same thing with the letters checked: a list of Gate,
+// plus the global phase omega^omega_exp that ExactUniMat keeps track of
+//
+// The gates are in the same order as in the strings, so they are
+// applied right to left, and the circuit "HT" is the matrix H*T
+//
+// Display and FromStr use the letters of ExactUniMat::from_string
+// "W" is a single factor of the global phase omega, and "I" is nothing
+//
+// Circuits over the other gate sets, Clifford+√T and Pauli+V, are
+// Circuit<SqrtTGate> and Circuit<VGate>, in the letters of
+// SqrtTUniMat::from_string and ExactVUniMat::from_string
+
+
+use std::ops::Mul;
+use std::str::FromStr;
+use std::hash::Hash;
+use std::fmt::Debug;
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::sqrt_t_unimat::SqrtTUniMat;
+use crate::structs::v_unimat::ExactVUniMat;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::ParseRingError;
+use crate::structs::rings::parsing;
+
+type ExpInt = u8;
+
+
+// What a circuit needs to know about its gates
+pub trait GateSet: Copy+Eq+Hash+Debug
+{
+    // The global phase of a circuit is a root of unity of this order,
+    // written with "W". It is 1 when the gate strings of the gate set
+    // have no letter for the phase
+    const PHASE_ORDER: ExpInt;
+
+    fn letter(&self) -> char;
+    fn from_letter(letter: char) -> Option<Self>;
+}
+
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum Gate
+{
+    H,
+    T,
+    Tdg,
+    S,
+    Sdg,
+    X,
+    Y,
+    Z,
+}
+
+impl Gate
+{
+    pub const ALL: [Gate; 8] = [Gate::H, Gate::T, Gate::Tdg, Gate::S, Gate::Sdg, Gate::X, Gate::Y, Gate::Z];
+
+    pub fn letter(&self) -> char
+    {
+        return match self
+        {
+            Gate::H => 'H',
+            Gate::T => 'T',
+            Gate::Tdg => 't',
+            Gate::S => 'S',
+            Gate::Sdg => 's',
+            Gate::X => 'X',
+            Gate::Y => 'Y',
+            Gate::Z => 'Z',
+        };
+    }
+
+    pub fn from_letter(letter: char) -> Option<Gate>
+    {
+        return Gate::ALL.iter().copied().find(|gate| gate.letter() == letter);
+    }
+
+    pub fn inverse(&self) -> Gate
+    {
+        return match self
+        {
+            Gate::T => Gate::Tdg,
+            Gate::Tdg => Gate::T,
+            Gate::S => Gate::Sdg,
+            Gate::Sdg => Gate::S,
+            other => *other,
+        };
+    }
+
+    pub fn to_exact_unimat<I: IntBackend>(&self) -> ExactUniMat<I>
+    {
+        return match self
+        {
+            Gate::H => ExactUniMat::h_gate(),
+            Gate::T => ExactUniMat::t_gate(),
+            Gate::Tdg => ExactUniMat::tdg_gate(),
+            Gate::S => ExactUniMat::s_gate(),
+            Gate::Sdg => ExactUniMat::sdg_gate(),
+            Gate::X => ExactUniMat::x_gate(),
+            Gate::Y => ExactUniMat::y_gate(),
+            Gate::Z => ExactUniMat::z_gate(),
+        };
+    }
+
+    pub fn is_clifford(&self) -> bool
+    {
+        return *self != Gate::T && *self != Gate::Tdg;
+    }
+}
+
+impl Display for Gate
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        write!(f,"{}",self.letter())
+    }
+}
+
+impl GateSet for Gate
+{
+    const PHASE_ORDER: ExpInt = 8;
+
+    fn letter(&self) -> char
+    {
+        return Gate::letter(self);
+    }
+
+    fn from_letter(letter: char) -> Option<Self>
+    {
+        return Gate::from_letter(letter);
+    }
+}
+
+
+// H, T and √T, which is written Q
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum SqrtTGate
+{
+    H,
+    T,
+    Q,
+}
+
+impl SqrtTGate
+{
+    pub const ALL: [SqrtTGate; 3] = [SqrtTGate::H, SqrtTGate::T, SqrtTGate::Q];
+
+    pub fn to_sqrt_t_unimat<I: IntBackend>(&self) -> SqrtTUniMat<I>
+    {
+        return match self
+        {
+            SqrtTGate::H => SqrtTUniMat::h_gate(),
+            SqrtTGate::T => SqrtTUniMat::t_gate(),
+            SqrtTGate::Q => SqrtTUniMat::sqrt_t_gate(),
+        };
+    }
+}
+
+impl GateSet for SqrtTGate
+{
+    const PHASE_ORDER: ExpInt = 1;
+
+    fn letter(&self) -> char
+    {
+        return match self
+        {
+            SqrtTGate::H => 'H',
+            SqrtTGate::T => 'T',
+            SqrtTGate::Q => 'Q',
+        };
+    }
+
+    fn from_letter(letter: char) -> Option<Self>
+    {
+        return SqrtTGate::ALL.iter().copied().find(|gate| gate.letter() == letter);
+    }
+}
+
+
+// The Paulis, and the V gates and their inverses
+// V gates are written A, B, C, and their inverses a, b, c
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum VGate
+{
+    X,
+    Y,
+    Z,
+    Vx,
+    Vy,
+    Vz,
+    Vxdg,
+    Vydg,
+    Vzdg,
+}
+
+impl VGate
+{
+    pub const ALL: [VGate; 9] = [VGate::X, VGate::Y, VGate::Z, VGate::Vx, VGate::Vy, VGate::Vz, VGate::Vxdg, VGate::Vydg, VGate::Vzdg];
+
+    pub fn to_v_unimat<I: IntBackend>(&self) -> ExactVUniMat<I>
+    {
+        return match self
+        {
+            VGate::X => ExactVUniMat::x_gate(),
+            VGate::Y => ExactVUniMat::y_gate(),
+            VGate::Z => ExactVUniMat::z_gate(),
+            VGate::Vx => ExactVUniMat::vx_gate(),
+            VGate::Vy => ExactVUniMat::vy_gate(),
+            VGate::Vz => ExactVUniMat::vz_gate(),
+            VGate::Vxdg => ExactVUniMat::vx_gate().inv(),
+            VGate::Vydg => ExactVUniMat::vy_gate().inv(),
+            VGate::Vzdg => ExactVUniMat::vz_gate().inv(),
+        };
+    }
+
+    pub fn is_pauli(&self) -> bool
+    {
+        return *self == VGate::X || *self == VGate::Y || *self == VGate::Z;
+    }
+}
+
+impl GateSet for VGate
+{
+    const PHASE_ORDER: ExpInt = 1;
+
+    fn letter(&self) -> char
+    {
+        return match self
+        {
+            VGate::X => 'X',
+            VGate::Y => 'Y',
+            VGate::Z => 'Z',
+            VGate::Vx => 'A',
+            VGate::Vy => 'B',
+            VGate::Vz => 'C',
+            VGate::Vxdg => 'a',
+            VGate::Vydg => 'b',
+            VGate::Vzdg => 'c',
+        };
+    }
+
+    fn from_letter(letter: char) -> Option<Self>
+    {
+        return VGate::ALL.iter().copied().find(|gate| gate.letter() == letter);
+    }
+}
+
+
+#[derive(Debug,Clone,PartialEq,Eq,Hash)]
+pub struct Circuit<G = Gate>
+{
+    pub gates: Vec<G>,
+    pub omega_exp: ExpInt           // This is a number mod G::PHASE_ORDER
+}
+
+impl<G> Default for Circuit<G>
+{
+    fn default() -> Self
+    {
+        return Self{ gates: Vec::new(), omega_exp: 0 };
+    }
+}
+
+
+// Powers of T, written the way downstream compilers like them
+// T^2 = S and T^4 = Z exactly, not just up to phase
+const T_POWERS: [&[Gate]; 8] = [
+    &[],
+    &[Gate::T],
+    &[Gate::S],
+    &[Gate::S, Gate::T],
+    &[Gate::Z],
+    &[Gate::Z, Gate::T],
+    &[Gate::Sdg],
+    &[Gate::Tdg],
+];
+
+
+impl<G: GateSet> Circuit<G>
+{
+    pub fn one() -> Self
+    {
+        return Self::default();
+    }
+
+    pub fn from_gates(gates: Vec<G>) -> Self
+    {
+        return Self{ gates, omega_exp: 0 };
+    }
+
+    pub fn len(&self) -> usize
+    {
+        return self.gates.len();
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        return self.gates.is_empty();
+    }
+
+    // Appends a gate on the right, so it is applied before the rest
+    pub fn push(&mut self, gate: G)
+    {
+        self.gates.push(gate);
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, G>
+    {
+        return self.gates.iter();
+    }
+}
+
+
+impl Circuit
+{
+    pub fn t_count(&self) -> usize
+    {
+        return self.gates.iter().filter(|gate| !gate.is_clifford()).count();
+    }
+
+    // The gates in the opposite order, each one inverted
+    pub fn inverse(&self) -> Self
+    {
+        return Self
+        {
+            gates: self.gates.iter().rev().map(|gate| gate.inverse()).collect(),
+            omega_exp: (8 - self.omega_exp%8)%8
+        };
+    }
+
+    // Same as inverse, since circuits are unitary
+    pub fn adjoint(&self) -> Self
+    {
+        return self.inverse();
+    }
+
+    pub fn to_exact_unimat<I: IntBackend>(&self) -> ExactUniMat<I>
+    {
+        let mut output = ExactUniMat::one();
+        for gate in self.gates.iter().rev()
+        {
+            output = gate.to_exact_unimat()*output;
+        }
+        for _ in 0..self.omega_exp
+        {
+            output = ExactUniMat::omega_gate()*output;
+        }
+        return output;
+    }
+
+    // Replaces every run of T gates by the shortest word in T_POWERS
+    // The unitary stays exactly the same
+    pub fn collapse_t_runs(&self) -> Self
+    {
+        let mut gates = Vec::<Gate>::new();
+        let mut run = 0;
+        for gate in self.gates.iter()
+        {
+            if *gate == Gate::T
+            {
+                run = run + 1;
+                continue;
+            }
+            gates.extend_from_slice(T_POWERS[run%8]);
+            run = 0;
+            gates.push(*gate);
+        }
+        gates.extend_from_slice(T_POWERS[run%8]);
+        return Self{ gates, omega_exp: self.omega_exp };
+    }
+}
+
+impl Circuit<SqrtTGate>
+{
+    pub fn to_sqrt_t_unimat<I: IntBackend>(&self) -> SqrtTUniMat<I>
+    {
+        let mut output = SqrtTUniMat::one();
+        for gate in self.gates.iter().rev()
+        {
+            output = gate.to_sqrt_t_unimat()*output;
+        }
+        return output;
+    }
+
+    pub fn sqrt_t_count(&self) -> usize
+    {
+        return self.gates.iter().filter(|gate| **gate == SqrtTGate::Q).count();
+    }
+}
+
+impl Circuit<VGate>
+{
+    pub fn to_v_unimat<I: IntBackend>(&self) -> ExactVUniMat<I>
+    {
+        let mut output = ExactVUniMat::one();
+        for gate in self.gates.iter().rev()
+        {
+            output = gate.to_v_unimat()*output;
+        }
+        return output;
+    }
+
+    pub fn v_count(&self) -> usize
+    {
+        return self.gates.iter().filter(|gate| !gate.is_pauli()).count();
+    }
+}
+
+
+// Doing one circuit after the other
+// As with matrices, self*other does other first
+impl<G: GateSet> Mul for Circuit<G>
+{
+    type Output = Self;
+    fn mul(mut self, other: Self) -> Self
+    {
+        self.gates.extend(other.gates);
+        self.omega_exp = (self.omega_exp + other.omega_exp)%G::PHASE_ORDER;
+        return self;
+    }
+}
+
+impl<G: GateSet> IntoIterator for Circuit<G>
+{
+    type Item = G;
+    type IntoIter = std::vec::IntoIter<G>;
+    fn into_iter(self) -> Self::IntoIter
+    {
+        return self.gates.into_iter();
+    }
+}
+
+impl<'a, G: GateSet> IntoIterator for &'a Circuit<G>
+{
+    type Item = &'a G;
+    type IntoIter = std::slice::Iter<'a, G>;
+    fn into_iter(self) -> Self::IntoIter
+    {
+        return self.gates.iter();
+    }
+}
+
+impl<G: GateSet> FromIterator<G> for Circuit<G>
+{
+    fn from_iter<J: IntoIterator<Item = G>>(iter: J) -> Self
+    {
+        return Self::from_gates(iter.into_iter().collect());
+    }
+}
+
+
+// The phase commutes with everything, so it is written first
+impl<G: GateSet> Display for Circuit<G>
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        let phase = "W".repeat((self.omega_exp%G::PHASE_ORDER) as usize);
+        let gates: String = self.gates.iter().map(|gate| gate.letter()).collect();
+        write!(f,"{}{}",phase, gates)
+    }
+}
+
+impl<G: GateSet> FromStr for Circuit<G>
+{
+    type Err = ParseRingError;
+
+    fn from_str(input: &str) -> core::result::Result<Self, Self::Err>
+    {
+        let mut output = Self::one();
+        for letter in input.chars()
+        {
+            match letter
+            {
+                'W' if G::PHASE_ORDER > 1 => output.omega_exp = (output.omega_exp + 1)%G::PHASE_ORDER,
+                'I' => (),
+                _ => output.push(G::from_letter(letter).ok_or(parsing::error("unknown gate letter", input))?),
+            }
+        }
+        return Ok(output);
+    }
+}
@@ -0,0 +1,302 @@
+// The single qubit Clifford group
+//
+// Up to phase there are 24 Cliffords. ExactUniMat keeps the phase omega^k
+// exactly, so here there are 24*8 = 192 of them, and all of them are words
+// in H and S, since (SH)^3 = omega
+//
+// A Clifford is an index into a table that is built once, the first time
+// it is needed. The elements are in the order that a breadth first search
+// over H and S finds them, so Clifford(0) is the identity and the word
+// stored with each element is a shortest word in H and S
+//
+// Cliffords are also the permutations of the Paulis, with signs: C X C^†,
+// C Y C^† and C Z C^† are again Paulis up to a sign. This table only sees
+// the class of C up to phase, so the phase is passed next to it
+
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::ops::Mul;
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Gate;
+use crate::structs::circuit::Circuit;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+
+use num_complex::Complex;
+use num_traits::Pow;
+
+type KMMring<I = Int> = Complex<Local<Zroot2<I>>>;
+type ExpInt = u8;
+
+
+pub const ORDER: usize = 192;
+
+// The images of X, Y and Z under conjugation, each with a sign +1 or -1
+pub type PauliTable = [(i8, Gate); 3];
+
+const PAULIS: [Gate; 3] = [Gate::X, Gate::Y, Gate::Z];
+
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,PartialOrd,Ord)]
+pub struct Clifford(u8);
+
+
+struct CliffordTable
+{
+    elements: Vec<ExactUniMat<Int>>,
+    words: Vec<Circuit>,
+    index: HashMap<(SUniMat<KMMring>, ExpInt), Clifford>,
+    products: Vec<[Clifford; ORDER]>,
+    representatives: Vec<(Clifford, ExpInt)>,   // First element of the class up to phase, and the phase
+    pauli_tables: Vec<PauliTable>,
+}
+
+static TABLE: OnceLock<CliffordTable> = OnceLock::new();
+
+fn table() -> &'static CliffordTable
+{
+    return TABLE.get_or_init(build_table);
+}
+
+fn key(gate: &ExactUniMat<Int>) -> (SUniMat<KMMring>, ExpInt)
+{
+    return (gate.mat, gate.omega_exp%8);
+}
+
+fn build_table() -> CliffordTable
+{
+    let mut elements = vec![ExactUniMat::<Int>::one()];
+    let mut words = vec![Circuit::one()];
+    let mut index = HashMap::from([(key(&elements[0]), Clifford(0))]);
+
+    // Breadth first search, so the words are as short as they can be
+    let mut next = 0;
+    while next < elements.len()
+    {
+        for generator in [Gate::H, Gate::S]
+        {
+            let new_gate = elements[next]*generator.to_exact_unimat();
+            if !index.contains_key(&key(&new_gate))
+            {
+                let mut new_word = words[next].clone();
+                new_word.push(generator);
+                index.insert(key(&new_gate), Clifford(elements.len() as u8));
+                elements.push(new_gate);
+                words.push(new_word);
+            }
+        }
+        next = next + 1;
+    }
+    assert_eq!(elements.len(), ORDER, "H and S should give all the Cliffords");
+
+    let products = elements.iter().map(|a|
+    {
+        let mut row = [Clifford(0); ORDER];
+        for (j, b) in elements.iter().enumerate()
+        {
+            row[j] = index[&key(&(*a*(*b)))];
+        }
+        return row;
+    }).collect();
+
+    // omega^k times an element is the same element with k added to the phase
+    let mut representatives = vec![(Clifford(0), 0); ORDER];
+    let mut done = [false; ORDER];
+    for i in 0..ORDER
+    {
+        if done[i]
+        {
+            continue;
+        }
+        let mut phase = elements[i];
+        for omega_exp in 0..8
+        {
+            let found = index[&key(&phase)];
+            representatives[found.0 as usize] = (Clifford(i as u8), omega_exp);
+            done[found.0 as usize] = true;
+            phase = ExactUniMat::omega_gate()*phase;
+        }
+    }
+
+    let minus_one = ExactUniMat::<Int>::omega_gate().pow(4u32);
+    let pauli_tables = elements.iter().map(|c|
+    {
+        return PAULIS.map(|p|
+        {
+            let image = *c*p.to_exact_unimat()*c.inv();
+            for q in PAULIS
+            {
+                if image == q.to_exact_unimat()
+                {
+                    return (1, q);
+                }
+                if image == minus_one*q.to_exact_unimat()
+                {
+                    return (-1, q);
+                }
+            }
+            panic!("A Clifford took a Pauli to something that is not a Pauli");
+        });
+    }).collect();
+
+    return CliffordTable{ elements, words, index, products, representatives, pauli_tables };
+}
+
+
+impl Clifford
+{
+    pub fn one() -> Self
+    {
+        return Self(0);
+    }
+
+    // All 192 of them, in the order of the table
+    pub fn all() -> impl Iterator<Item = Clifford>
+    {
+        return (0..ORDER).map(|i| Clifford(i as u8));
+    }
+
+    pub fn index(&self) -> usize
+    {
+        return self.0 as usize;
+    }
+
+    // Returns None for gates that are not Clifford
+    pub fn from_exact_unimat<I: IntBackend>(gate: &ExactUniMat<I>) -> Option<Self>
+    {
+        // Cliffords have tiny entries, so anything that does not fit in Int is not one
+        let gate = gate.to_backend::<Int>()?;
+        return table().index.get(&key(&gate)).copied();
+    }
+
+    // Returns None if the gate is not Clifford, so if it is T or T^†
+    pub fn from_gate(gate: Gate) -> Option<Self>
+    {
+        if !gate.is_clifford()
+        {
+            return None;
+        }
+        return Self::from_exact_unimat(&gate.to_exact_unimat::<Int>());
+    }
+
+    // Returns None if the circuit has T gates, even if they cancel
+    pub fn from_circuit(circuit: &Circuit) -> Option<Self>
+    {
+        let mut output = Self::omega().pow(circuit.omega_exp);
+        for gate in circuit.iter()
+        {
+            output = output*Self::from_gate(*gate)?;
+        }
+        return Some(output);
+    }
+
+    pub fn to_exact_unimat<I: IntBackend>(&self) -> ExactUniMat<I>
+    {
+        return table().elements[self.index()].to_backend().expect("Cliffords fit in every backend");
+    }
+
+    // A shortest word in H and S, with no phase
+    pub fn word(&self) -> Circuit
+    {
+        return table().words[self.index()].clone();
+    }
+
+    pub fn omega() -> Self
+    {
+        return Self::from_exact_unimat(&ExactUniMat::<Int>::omega_gate()).unwrap();
+    }
+
+    pub fn pow(&self, exponent: ExpInt) -> Self
+    {
+        let mut output = Self::one();
+        for _ in 0..exponent
+        {
+            output = output*(*self);
+        }
+        return output;
+    }
+
+    pub fn inv(&self) -> Self
+    {
+        let row = &table().products[self.index()];
+        return Self(row.iter().position(|c| *c == Self::one()).unwrap() as u8);
+    }
+
+    // The first element of the class of self up to phase
+    // self = omega^phase() * representative()
+    pub fn representative(&self) -> Self
+    {
+        return table().representatives[self.index()].0;
+    }
+
+    pub fn phase(&self) -> ExpInt
+    {
+        return table().representatives[self.index()].1;
+    }
+
+    // The word of the representative, with the phase written as omega's
+    // Unlike word(), the same class up to phase always has the same gates
+    pub fn canonical_circuit(&self) -> Circuit
+    {
+        return Circuit{ gates: self.representative().word().gates, omega_exp: self.phase() };
+    }
+
+    // What self does to X, Y and Z by conjugation
+    pub fn pauli_table(&self) -> PauliTable
+    {
+        return table().pauli_tables[self.index()];
+    }
+
+    // Returns None if the table is not the table of any Clifford
+    pub fn from_pauli_table(pauli_table: &PauliTable, omega_exp: ExpInt) -> Option<Self>
+    {
+        let representative = Self::all().find(|c| c.phase() == 0 && c.pauli_table() == *pauli_table)?;
+        return Some(Self::omega().pow(omega_exp%8)*representative);
+    }
+
+    // Row i of the table is the products Clifford(i)*Clifford(j)
+    pub fn multiplication_table() -> &'static [[Clifford; ORDER]]
+    {
+        return &table().products;
+    }
+}
+
+
+// As with matrices, self*other does other first
+impl Mul for Clifford
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self
+    {
+        return table().products[self.index()][other.index()];
+    }
+}
+
+
+// Cliffords are named by their shortest word
+impl Display for Clifford
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        if *self == Self::one()
+        {
+            return write!(f,"I");
+        }
+        write!(f,"{}",self.word())
+    }
+}
+
+
+pub fn is_clifford<I: IntBackend>(gate: &ExactUniMat<I>) -> bool
+{
+    return Clifford::from_exact_unimat(gate).is_some();
+}
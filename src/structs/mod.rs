@@ -4,3 +4,7 @@ pub mod rings;
 
 pub mod sunimat;
 pub mod unimat;
+pub mod circuit;
+pub mod clifford;
+pub mod sqrt_t_unimat;
+pub mod v_unimat;
@@ -0,0 +1,165 @@
+// The integer backends that our rings can be built on
+//
+// Int (an i128) is fast and Copy, but overflows once coefficients of
+// Zomega pass roughly 2^16, since norms take fourth powers.
+// BigInt lives on the heap and never overflows.
+//
+// Everything in structs and algorithms is written for any IntBackend,
+// so one can pick the backend by writing Zroot2<BigInt> instead of Zroot2
+
+
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::Float;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::CheckedRing;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::EuclideanDomain;
+
+use num_traits::ToPrimitive;
+
+
+impl IntBackend for Int
+{
+    fn trailing_zeros(&self) -> u32
+    {
+        // Equal to 128 when self is zero
+        return Int::trailing_zeros(*self);
+    }
+
+    fn from_int(input: Int) -> Self
+    {
+        return input;
+    }
+
+    fn to_bigint(&self) -> BigInt
+    {
+        return BigInt::from(*self);
+    }
+
+    fn from_bigint(input: &BigInt) -> Option<Self>
+    {
+        return input.to_i128();
+    }
+
+    fn to_float(&self) -> Float
+    {
+        return *self as Float;
+    }
+
+    fn checked_shl(&self, n: u32) -> Option<Self>
+    {
+        // Shifting back must give the same number
+        // otherwise some bits (or the sign) fell off
+        if n >= Int::BITS
+        {
+            return if *self == 0 { Some(0) } else { None };
+        }
+        let shifted = *self << n;
+        if shifted >> n == *self { Some(shifted) } else { None }
+    }
+}
+
+
+impl IntBackend for BigInt
+{
+    fn trailing_zeros(&self) -> u32
+    {
+        // BigInt says None when self is zero
+        // We say something bigger than any actual answer
+        return match BigInt::trailing_zeros(self)
+        {
+            Some(trailing) => trailing.try_into().unwrap_or(u32::MAX),
+            None => u32::MAX,
+        };
+    }
+
+    fn from_int(input: Int) -> Self
+    {
+        return BigInt::from(input);
+    }
+
+    fn to_bigint(&self) -> BigInt
+    {
+        return self.clone();
+    }
+
+    fn from_bigint(input: &BigInt) -> Option<Self>
+    {
+        return Some(input.clone());
+    }
+
+    fn to_float(&self) -> Float
+    {
+        // Saturates to infinity for really big numbers
+        return self.to_f64().unwrap_or(Float::INFINITY);
+    }
+
+    fn checked_shl(&self, n: u32) -> Option<Self>
+    {
+        return Some(self.clone() << n);
+    }
+}
+
+
+// Plain integers are the simplest checked rings
+impl CheckedRing for Int
+{
+    fn checked_add(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Int::checked_add(*self, *other).ok_or(ArithmeticOverflow);
+    }
+
+    fn checked_sub(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Int::checked_sub(*self, *other).ok_or(ArithmeticOverflow);
+    }
+
+    fn checked_mul(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Int::checked_mul(*self, *other).ok_or(ArithmeticOverflow);
+    }
+}
+
+
+// Nothing ever fails for BigInt
+impl CheckedRing for BigInt
+{
+    fn checked_add(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Ok(self + other);
+    }
+
+    fn checked_sub(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Ok(self - other);
+    }
+
+    fn checked_mul(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Ok(self * other);
+    }
+}
+
+
+// Truncating division already leaves a remainder smaller than the divisor
+// Canonical associates are the non-negative integers
+impl<I: IntBackend> EuclideanDomain for I
+{
+    fn div_rem(&self, other: &Self) -> (Self, Self)
+    {
+        let quotient = self.clone()/other.clone();
+        let remainder = self.clone() - quotient.clone()*other.clone();
+        return (quotient, remainder);
+    }
+
+    fn is_unit(&self) -> bool
+    {
+        return self.abs().is_one();
+    }
+
+    fn normalizing_unit(&self) -> Self
+    {
+        if self.is_negative() { -Self::one() } else { Self::one() }
+    }
+}
@@ -8,13 +8,16 @@
 
 //Integer type is set globally
 use crate::structs::rings::Int; 
+use crate::structs::rings::BigInt; 
+use crate::structs::rings::IntBackend; 
 use crate::structs::rings::LogDepInt; 
 use crate::structs::rings::Localizable; 
+use crate::structs::rings::ArithmeticOverflow; 
 
 
 impl Localizable for Int
 {
-    fn is_divisible(self) -> bool
+    fn is_divisible(&self) -> bool
     {
         return self%2==0
     }
@@ -29,4 +32,41 @@ impl Localizable for Int
     {
         return self << n;
     }
+
+    fn checked_perform_n_multiplications(self, n: LogDepInt) -> Result<Self, ArithmeticOverflow>
+    {
+        let n: u32 = n.try_into().map_err(|_| ArithmeticOverflow)?;
+        return IntBackend::checked_shl(&self, n).ok_or(ArithmeticOverflow);
+    }
+
+    const GENERATOR: &'static str = "2";
+}
+
+
+// Same as above, but for the heap allocated integers
+impl Localizable for BigInt
+{
+    fn is_divisible(&self) -> bool
+    {
+        return !self.bit(0);
+    }
+
+    fn reduce_by_dividing(self) -> (Self,LogDepInt)
+    {
+        let trailing = IntBackend::trailing_zeros(&self);
+        return (self >> trailing, trailing.try_into().unwrap());
+    }
+    
+    fn perform_n_multiplications(self, n: LogDepInt) -> Self
+    {
+        return self << n;
+    }
+
+    fn checked_perform_n_multiplications(self, n: LogDepInt) -> Result<Self, ArithmeticOverflow>
+    {
+        let n: u32 = n.try_into().map_err(|_| ArithmeticOverflow)?;
+        return IntBackend::checked_shl(&self, n).ok_or(ArithmeticOverflow);
+    }
+
+    const GENERATOR: &'static str = "2";
 }
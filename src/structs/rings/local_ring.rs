@@ -18,6 +18,8 @@ use std::ops::Mul;
 use std::ops::Div; 
 use std::ops::Rem; 
 use std::cmp::PartialEq; 
+use std::cmp::Ordering;
+use std::str::FromStr;
 
 // For display
 use std::fmt::Result;
@@ -30,6 +32,7 @@ use num_traits::Zero;
 use num_traits::One;
 use num_traits::ToPrimitive;
 use num_traits::NumCast;
+use num_traits::Pow;
 
 
 // // Localization trait. 
@@ -37,8 +40,23 @@ use num_traits::NumCast;
 // Or Zroot2
 use crate::structs::rings::Localizable; 
 use crate::structs::rings::Int; 
+use crate::structs::rings::Float; 
+use crate::algorithms::inexact_synth::SQRT2;
+use crate::structs::rings::IntBackend; 
+use crate::structs::rings::BigInt; 
+use crate::structs::rings::zroot2::Zroot2; 
 use crate::structs::rings::Conj; //Conjugation trait
+use crate::structs::rings::LocalizableConj;
 use crate::structs::rings::LocalizableNorm; //Norm trait
+use crate::structs::rings::CheckedRing; //Overflow-checked arithmetic
+use crate::structs::rings::CheckedNorm;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::ParseRingError;
+use crate::structs::rings::parsing;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
 
 //Integer type is set globally
 // use crate::structs::rings::Int; 
@@ -47,7 +65,7 @@ use crate::structs::rings::LogDepInt;
 // Ring of numbers of the form a/2^n for integers a and n
 // These are dyadic integers
 // Each integer looks something like
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
 pub struct Local<T> 
 {
     pub num: T,
@@ -64,7 +82,7 @@ pub struct Local<T>
 // Internal function
 // Used to make the numerator independent of denominator
 impl<T> Local<T> 
-where T: Localizable+PartialEq+Copy,
+where T: Localizable+PartialEq+Clone,
       T: Num
 
 {
@@ -78,7 +96,7 @@ where T: Localizable+PartialEq+Copy,
             {
                 // println!("Number is divisible");
                 let pow: LogDepInt;
-                (self.num, pow) = self.num.reduce_by_dividing();
+                (self.num, pow) = self.num.clone().reduce_by_dividing();
                 // println!("Self obtained after it performed {} divisions",pow);
                 // println!("log_den is {}",self.log_den);
                 self.log_den = self.log_den - pow;
@@ -87,7 +105,7 @@ where T: Localizable+PartialEq+Copy,
             // println!("log_den is {}",self.log_den);
 
             // Return ownership
-            return *self;
+            return self.clone();
         }
         else
         {
@@ -95,7 +113,7 @@ where T: Localizable+PartialEq+Copy,
             self.log_den=0;
 
             // Return ownership
-            return *self;
+            return self.clone();
         }
     }
 
@@ -130,7 +148,7 @@ where T:Neg<Output=T>
 
 // Teaching rust how to add Local<T> elements
 impl<T> Add for Local<T> 
-where T: Localizable+PartialEq+Copy,
+where T: Localizable+PartialEq+Clone,
       T: Num
 {
     type Output = Self;
@@ -184,7 +202,7 @@ where T: Localizable+PartialEq+Copy,
 
 // Teaching rust how to multiply Local elements
 impl<T> Mul for Local<T> 
-where T: Mul<Output=T>+PartialEq+Copy,
+where T: Mul<Output=T>+PartialEq+Clone,
       T: Localizable,
       T: Num
 {
@@ -213,12 +231,47 @@ where T: Mul<Output=T>+PartialEq+Copy,
 }
 
 
+// Powers by square-and-multiply
+impl<T> Pow<u32> for Local<T>
+where T: Mul<Output=T>+PartialEq+Clone,
+      T: Localizable,
+      T: Num
+{
+    type Output = Self;
+    fn pow(self, p: u32) -> Self
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers exist when the numerator is a unit
+// This includes all powers of the generator, like √2 = 1/√2^(-1)
+impl<T> Pow<i32> for Local<T>
+where T: Mul<Output=T>+PartialEq+Clone,
+      T: Localizable+EuclideanDomain,
+      T: Num
+{
+    type Output = Self;
+    fn pow(self, p: i32) -> Self
+    {
+        return signed_pow(self, p, Self::one(), |mut x| {
+            x.fix();
+            return Self
+            {
+                num: x.num.unit_inverse().expect("Negative power of a non-invertible element"),
+                log_den: -x.log_den
+            };
+        });
+    }
+}
+
+
 
 // Teaching rust how to subtract Dyad elements
 impl<T> Sub for Local<T> 
 where T: Num,
       T: Localizable,
-      T: Copy
+      T: Clone
 {
 
     type Output = Self;
@@ -238,14 +291,15 @@ where T: Num,
 // Nicely display Local<T> elements
 impl<T> Display for Local<T>
 where T: Display+PartialEq,
-      T: Zero+One
+      T: Zero+One,
+      T: Localizable
 {
     fn fmt(&self, f: &mut Formatter) -> Result{
         if self.log_den>0  
         {   
             if self.num== T::one()
             {
-                write!(f,"√2^(-{})", self.log_den)
+                write!(f,"{}^(-{})", T::GENERATOR, self.log_den)
             }
             else if self.num == T::zero()
             {
@@ -253,14 +307,14 @@ where T: Display+PartialEq,
             }
             else 
             {
-                write!(f,"({})*√2^(-{})",self.num, self.log_den)
+                write!(f,"({})*{}^(-{})",self.num, T::GENERATOR, self.log_den)
             }
         }        
         else if self.log_den<0  
         { 
             if self.num== T::one()
             {
-                write!(f,"√2^({})",-self.log_den)
+                write!(f,"{}^({})", T::GENERATOR, -self.log_den)
             }
             else if self.num == T::zero()
             {
@@ -268,7 +322,7 @@ where T: Display+PartialEq,
             }
             else 
             {
-                write!(f,"({})*√2^({})",self.num, -self.log_den)
+                write!(f,"({})*{}^({})",self.num, T::GENERATOR, -self.log_den)
             }
         }
         else 
@@ -282,6 +336,55 @@ where T: Display+PartialEq,
 
 
 
+// Reads back what Display writes, like (3+2√2)*√2^(-5), √2^(2) or (7)
+// and also the form 3+2√2 / √2^5
+impl<T> FromStr for Local<T>
+where T: FromStr<Err=ParseRingError>,
+      T: Localizable+PartialEq+Clone,
+      T: Num
+{
+    type Err = ParseRingError;
+
+    fn from_str(input: &str) -> core::result::Result<Self, ParseRingError>
+    {
+        let normalized = parsing::normalize(input);
+        let generator = parsing::normalize(T::GENERATOR);
+
+        // Split off the power of the generator, if there is one
+        let (numerator, log_den) = match parsing::find_last_top_level(&normalized, '^')
+        {
+            Some(caret) if normalized[..caret].ends_with(&generator) =>
+            {
+                let exponent = parsing::parse_exponent(&normalized[caret..])?;
+                let rest = &normalized[..caret-generator.len()];
+                if let Some(numerator) = rest.strip_suffix('/')
+                {
+                    (numerator, exponent)
+                }
+                else
+                {
+                    let numerator = rest.strip_suffix('*').unwrap_or(rest);
+                    if parsing::strip_brackets(numerator, '(', ')') == numerator && parsing::signed_terms(numerator).map_or(false, |terms| terms.len() > 1)
+                    {
+                        return Err(parsing::error("put the numerator in brackets", input));
+                    }
+                    (numerator, -exponent)
+                }
+            },
+            _ => (&normalized[..], 0),
+        };
+
+        let num = if numerator.is_empty() { T::one() } else { parsing::strip_brackets(numerator, '(', ')').parse()? };
+        let den = Self
+        {
+            num: T::one(),
+            log_den
+        };
+        return Ok(Self::from_base(num)*den);
+    }
+}
+
+
 impl<T> ToPrimitive for Local<T>
 {
     // Rust needs this. 
@@ -303,7 +406,7 @@ impl<T> NumCast for Local<T>
 where T: NumCast,
       T: Localizable,
       T: PartialEq,
-      T: Copy,
+      T: Clone,
       T: Num
 {
     fn from<E>(given: E) -> Option<Self>
@@ -348,7 +451,7 @@ where T: NumCast,
 // To construct Local<T> from T
 impl<T> Local<T> 
 where T: Localizable,
-      T: PartialEq+Copy,
+      T: PartialEq+Clone,
       T: Num
 {
     pub fn from_base(input : T) -> Self 
@@ -359,7 +462,7 @@ where T: Localizable,
             log_den: 0
         };
 
-        if input!=T::one() && input!=T::zero()
+        if out.num!=T::one() && out.num!=T::zero()
         {
             out.fix();
         }
@@ -369,25 +472,171 @@ where T: Localizable,
 }
 
 
-// Conjugate Complex elements
+// Moving between integer backends
+// Returns None if the numerator does not fit in J
+impl<I: IntBackend> Local<Zroot2<I>>
+{
+    pub fn to_backend<J: IntBackend>(&self) -> Option<Local<Zroot2<J>>>
+    {
+        return Some(Local
+        {
+            num: self.num.to_backend()?,
+            log_den: self.log_den
+        });
+    }
+
+    // (a+b√2)/√2^k as a float
+    // This is the first embedding, see GaloisAutomorphism
+    pub fn to_float(&self) -> Float
+    {
+        return self.embeddings().0;
+    }
+
+    // Every finite float is m*2^e, which is m/√2^(-2e) exactly
+    // None for infinities and NaN
+    pub fn from_float_exactly(x: Float) -> Option<Self>
+    {
+        if !x.is_finite()
+        {
+            return None;
+        }
+        let (mantissa, exponent, sign) = num_traits::float::FloatCore::integer_decode(x);
+        let numerator = I::from_int(sign as Int * mantissa as Int);
+        return Some(Self::from_base(Zroot2(numerator, I::zero()))*Local{ num: Zroot2::one(), log_den: -2*exponent as LogDepInt });
+    }
+
+    // √2^k is positive, so only the numerator matters
+    pub fn sign(&self) -> Ordering
+    {
+        return self.num.sign();
+    }
+
+    pub fn signum(&self) -> Self
+    {
+        return Self::from_base(self.num.signum());
+    }
+
+    pub fn abs(&self) -> Self
+    {
+        return Local
+        {
+            num: self.num.abs(),
+            log_den: self.log_den
+        };
+    }
+
+    // The √2-conjugate of x/√2^k is conj(x)/(-√2)^k
+    pub fn is_doubly_positive(&self) -> bool
+    {
+        return self.sign() != Ordering::Less && self.conj().sign() != Ordering::Less;
+    }
+
+    // Writing x/√2^k as y/2^n with y in Zroot2,
+    // floor(y/2^n) = floor(floor(y)/2^n) which is a shift
+    pub fn floor(&self) -> I
+    {
+        if self.log_den <= 0
+        {
+            return self.num.clone().perform_n_multiplications(-self.log_den).floor();
+        }
+        let y = self.num.clone().perform_n_multiplications(self.log_den%2);
+        let n: u32 = ((self.log_den+1)/2).try_into().unwrap();
+        return y.floor() >> n;
+    }
+
+    // Nearest integer, rounding halves up, towards +∞, so -1/2 rounds to 0
+    // where f64::round would give -1
+    pub fn round(&self) -> I
+    {
+        let half = Local
+        {
+            num: Zroot2::one(),
+            log_den: 2
+        };
+        return (self.clone()+half).floor();
+    }
+}
+
+impl<I: IntBackend> Ord for Local<Zroot2<I>>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        return match self.checked_sub(other)
+        {
+            Ok(difference) => difference.sign(),
+            Err(_) => (self.to_backend::<BigInt>().unwrap() - other.to_backend::<BigInt>().unwrap()).sign(),
+        };
+    }
+}
+
+impl<I: IntBackend> PartialOrd for Local<Zroot2<I>>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        return Some(self.cmp(other));
+    }
+}
+
+
+// Conjugate Local elements
+// The denominator g^k gets conjugated too, see LocalizableConj
 impl<T> Conj for Local<T> 
-where T: Conj+Copy
+where T: LocalizableConj+Clone
 {
     fn conj(&self) -> Self {
-        let temp_num = self.num;
-        temp_num.conj();
         Self{
-            num: temp_num,
+            num: self.num.conj().multiply_by_generator_ratio(self.log_den),
             log_den: self.log_den
         }
     }
 }
 
 
+// σ(x/g^n) = σ(x)/σ(g)^n, where g = T::one().perform_n_multiplications(1)
+// is the generator. σ(g) is g times a unit, so σ(x/g^n) = σ(x)*u^n/g^n
+// with u = g/σ(g). Only the numerator changes, by a unit, and nothing
+// is ever divided by a norm
+impl<T> GaloisAutomorphism for Local<T>
+where T: GaloisAutomorphism,
+      T: Localizable+PartialEq+Clone+Num,
+      T::Embedding: Num+Clone
+{
+    type Embedding = T::Embedding;
+
+    fn galois(&self, k: u8) -> Self
+    {
+        let generator = T::one().perform_n_multiplications(1);
+        let image_of_generator = generator.galois(k);
+
+        // Exact divisions of two associates of g, so these are units
+        let unit = generator.clone()/image_of_generator.clone();
+        let unit_power = signed_pow(unit, self.log_den, T::one(), |_| image_of_generator/generator);
+
+        let mut out = Self
+        {
+            num: self.num.galois(k)*unit_power,
+            log_den: self.log_den
+        };
+        out.fix();
+        return out;
+    }
+
+    fn embeddings(&self) -> (T::Embedding, T::Embedding)
+    {
+        let (x, x_bullet) = self.num.embeddings();
+        let (g, g_bullet) = T::one().perform_n_multiplications(1).embeddings();
+        let one = T::Embedding::one();
+        let scaling = signed_pow(g, -self.log_den, one.clone(), |g| one.clone()/g);
+        let scaling_bullet = signed_pow(g_bullet, -self.log_den, one.clone(), |g| one.clone()/g);
+        return (x*scaling, x_bullet*scaling_bullet);
+    }
+}
+
+
 // Teaching rust how to multiply Local elements
 impl<T> Div for Local<T> 
 where T: Num,
-      T: PartialEq+Copy,
+      T: PartialEq+Clone,
       T: Localizable,
 {
     type Output = Self;
@@ -419,7 +668,7 @@ where T: Num,
 }
 
 impl<T> One for Local<T>
-where T: Copy+PartialEq,
+where T: Clone+PartialEq,
       T: Num,
       T: Localizable,
 {
@@ -436,7 +685,7 @@ where T: Copy+PartialEq,
 }
 
 impl<T> Zero for Local<T>
-where T: Copy+PartialEq,
+where T: Clone+PartialEq,
       T: Num,
       T: Localizable,
 {
@@ -474,7 +723,7 @@ impl<T> Rem for Local<T>
 
 impl<T> Num for Local<T>
 where T: Num,
-      T: Copy,
+      T: Clone,
       T: Localizable+Conj,
 
 {
@@ -493,17 +742,115 @@ where T: Num,
 
 
 impl<T> Local<T>
-where T: LocalizableNorm
+where T: LocalizableNorm+Localizable+One,
+      T::Integer: Localizable+Num+PartialEq+Clone
 {
-    pub fn norm(&self) -> Local::<Int>
+    pub fn norm(&self) -> Local::<T::Integer>
     {
-        return Local::<Int>
+        return Self::divide_by_generator_norm(self.num.norm(), self.log_den);
+    }
+
+    // N(x/g^k) = N(x)/N(g)^k
+    // The generators we localize at have norm ±2^m,
+    // so this is a sign and a shift of the denominator
+    fn divide_by_generator_norm(num: T::Integer, log_den: LogDepInt) -> Local::<T::Integer>
+    {
+        let generator_norm = Local::<T::Integer>::from_base(T::one().perform_n_multiplications(1).norm());
+
+        let mut out = Local::<T::Integer>
         {
-            num: self.num.norm(),
-            log_den: 2*self.log_den
+            num,
+            log_den: -generator_norm.log_den*log_den
+        };
+        if log_den.rem_euclid(2) == 1 && generator_norm.num != T::Integer::one()
+        {
+            out.num = T::Integer::zero() - out.num;
         }
+        out.fix();
+        return out;
     }
 
 }
 
 
+
+impl<T> CheckedNorm for Local<T>
+where T: CheckedNorm+Localizable+One,
+      T::Integer: Localizable+Num+PartialEq+Clone
+{
+    fn checked_norm(&self) -> core::result::Result<Local::<T::Integer>, ArithmeticOverflow>
+    {
+        return Ok(Self::divide_by_generator_norm(self.num.checked_norm()?, self.log_den));
+    }
+}
+
+impl<T> LocalizableNorm for Local<T>
+where T: LocalizableNorm+Localizable+One,
+      T::Integer: Localizable+Num+PartialEq+Clone
+{
+    type Integer = Local::<T::Integer>;
+
+    fn norm(&self) -> Local::<T::Integer>
+    {
+        return Local::norm(self);
+    }
+}
+
+
+// Same as +, - and * but never wraps around
+// Bringing two numbers to the same denominator is where Add usually overflows
+impl<T> CheckedRing for Local<T>
+where T: CheckedRing,
+      T: Localizable+PartialEq+Clone,
+      T: Num
+{
+    fn checked_add(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        if other.num==T::zero()
+        {
+            return Ok(self.clone());
+        }
+        if self.num==T::zero()
+        {
+            return Ok(other.clone());
+        }
+
+        let log_den = self.log_den.max(other.log_den);
+        let self_temp = self.num.clone().checked_perform_n_multiplications(log_den-self.log_den)?;
+        let other_temp = other.num.clone().checked_perform_n_multiplications(log_den-other.log_den)?;
+
+        let mut temp = Self
+        {
+            num: self_temp.checked_add(&other_temp)?,
+            log_den
+        };
+        temp.fix();
+        return Ok(temp);
+    }
+
+    fn checked_sub(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        let other_neg = Self
+        {
+            num: T::zero().checked_sub(&other.num)?,
+            log_den: other.log_den,
+        };
+        return self.checked_add(&other_neg);
+    }
+
+    fn checked_mul(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        if self.num == T::zero() || other.num == T::zero()
+        {
+            return Ok(Self::zero());
+        }
+
+        let mut temp = Self
+        {
+            num: self.num.checked_mul(&other.num)?,
+            log_den: self.log_den+other.log_den
+        };
+        temp.fix();
+        return Ok(temp);
+    }
+}
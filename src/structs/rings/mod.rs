@@ -7,6 +7,99 @@ pub type Float = f64;
 // Will need some debugging
 // pub type Float = f128::f128;
 
+// Heap allocated integers
+// Slower than Int, but they never overflow
+// Use Zroot2<BigInt>, Zomega<BigInt>, ExactUniMat<BigInt>, etc.
+// when coefficients grow past what Int can hold
+pub type BigInt = num::BigInt;
+
+
+use std::hash::Hash;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::ops::Shl;
+use std::ops::Shr;
+use num_traits::Signed;
+use num_traits::FromPrimitive;
+use num_traits::ToPrimitive;
+
+
+// Supertrait
+// The integers that all our rings are built out of
+// Int is the fast path, BigInt is the one that never overflows
+// See int_backend.rs for the implementations
+pub trait IntBackend
+where Self: num_traits::Num+Signed+Clone+Ord+Hash+Debug+Display+'static,
+      Self: FromPrimitive+ToPrimitive,
+      Self: CheckedRing+Localizable,
+      Self: Shl<u32,Output=Self>+Shr<u32,Output=Self>
+{
+    // Number of times 2 divides self
+    // For zero, this is some number at least as big as the bit size
+    fn trailing_zeros(&self) -> u32;
+
+    // Every backend can hold an Int
+    fn from_int(input: Int) -> Self;
+
+    // Lossless passage between backends goes through BigInt
+    fn to_bigint(&self) -> BigInt;
+    fn from_bigint(input: &BigInt) -> Option<Self>;
+
+    // Nearest float, used for the geometric part of the algorithms
+    fn to_float(&self) -> Float;
+
+    // Multiplication by 2^n, or None if the result does not fit
+    fn checked_shl(&self, n: u32) -> Option<Self>;
+}
+
+
+// What the checked arithmetic below returns when an Int is too small
+// Switching to BigInt makes these go away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArithmeticOverflow;
+
+impl Display for ArithmeticOverflow
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        write!(f, "arithmetic overflow in ring operation")
+    }
+}
+
+impl std::error::Error for ArithmeticOverflow {}
+
+// What FromStr returns when a string is not a ring element
+// The string says what went wrong
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRingError(pub String);
+
+impl Display for ParseRingError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        write!(f, "could not parse ring element: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseRingError {}
+
+// Supertrait
+// Ring operations that report overflows instead of panicking (debug builds)
+// or silently wrapping around (release builds)
+pub trait CheckedRing
+where Self: Sized
+{
+    fn checked_add(&self, other: &Self) -> Result<Self, ArithmeticOverflow>;
+    fn checked_sub(&self, other: &Self) -> Result<Self, ArithmeticOverflow>;
+    fn checked_mul(&self, other: &Self) -> Result<Self, ArithmeticOverflow>;
+}
+
+// Supertrait
+// Same as LocalizableNorm, but reports overflows
+pub trait CheckedNorm: LocalizableNorm
+{
+    fn checked_norm(&self) -> Result<Self::Integer, ArithmeticOverflow>;
+}
 
 // Supertrait
 // They become various conjugation operators on our rings
@@ -14,20 +107,185 @@ pub trait Conj{
     fn conj(&self) -> Self;
 }
 
+// Supertrait
+// Conjugating x/g^k also conjugates the ideal generator g
+// and conj(g) is g times a unit. This multiplies by n copies of g/conj(g)
+// so that conj(x/g^k) = conj(x)*(g/conj(g))^k / g^k
+pub trait LocalizableConj: Conj
+{
+    fn multiply_by_generator_ratio(self, n: LogDepInt) -> Self;
+}
+
+// Supertrait
+// Automorphisms of the eighth cyclotomic field Q(ω)
+// The Galois group is {σ_k : ω ↦ ω^k} for k = 1,3,5,7
+// σ_7 is complex conjugation and σ_5 is the √2-conjugation x ↦ x•,
+// which sends √2 to -√2 and fixes i
+//
+// All our rings live inside Q(ω), so all of them get these.
+// The exception is Zzeta16, in Q(ζ) with ζ^2 = ω. There σ_k is ζ ↦ ζ^k and
+// k is read mod 16 instead of mod 8, which restricts to the same σ_k on Q(ω).
+// σ_15 is complex conjugation in both readings
+pub trait GaloisAutomorphism: Sized
+{
+    // Where the embeddings land, Float for real rings and Complex<Float> otherwise
+    type Embedding;
+
+    // σ_k, for odd k
+    fn galois(&self, k: u8) -> Self;
+
+    fn complex_conj(&self) -> Self
+    {
+        return self.galois(15);
+    }
+
+    fn bullet(&self) -> Self
+    {
+        return self.galois(5);
+    }
+
+    // self and self• as floats
+    // Over Q(ω), every other embedding is a complex conjugate of one of these
+    // Zzeta16 has two more, see zzeta16.rs
+    fn embeddings(&self) -> (Self::Embedding, Self::Embedding);
+}
+
+// k mod 8, after checking that ω ↦ ω^k is an automorphism
+pub fn galois_exponent(k: u8) -> u8
+{
+    if k%2 == 0
+    {
+        panic!("ω ↦ ω^{} is not an automorphism", k);
+    }
+    return k%8;
+}
+
 // Supertrait
 // They become various conjugation operators on our rings
 pub trait LocalizableNorm{
-    fn norm(&self) -> Int;
+    type Integer;
+    fn norm(&self) -> Self::Integer;
 }
 
 
+// Supertrait
+// Rings where division with remainder makes sense
+// Implemented for the integers, Zroot2 and Zomega
+//
+// Only div_rem, is_unit and normalizing_unit have to be written,
+// the gcd algorithms come for free
+pub trait EuclideanDomain
+where Self: Sized+Clone+PartialEq,
+      Self: num_traits::Zero+num_traits::One,
+      Self: std::ops::Sub<Output=Self>+std::ops::Mul<Output=Self>+std::ops::Div<Output=Self>
+{
+    // Returns (q,r) such that self = q*other + r
+    // and the remainder r is smaller than other in the Euclidean norm
+    fn div_rem(&self, other: &Self) -> (Self, Self);
+
+    // Same as div_rem, but reports when the quotient does not fit
+    // Rings whose div_rem can overflow on its own override this
+    fn checked_div_rem(&self, other: &Self) -> Result<(Self, Self), ArithmeticOverflow>
+    {
+        return Ok(self.div_rem(other));
+    }
+
+    fn is_unit(&self) -> bool;
+
+    // Inverse of a unit, None for everything else
+    fn unit_inverse(&self) -> Option<Self>
+    {
+        if !self.is_unit()
+        {
+            return None;
+        }
+        let (quotient, _) = Self::one().div_rem(self);
+        return Some(quotient);
+    }
+
+    // The unit u such that u*self is the canonical associate of self
+    // For zero, this is one
+    fn normalizing_unit(&self) -> Self;
+
+    // Canonical associate
+    // Two elements are associates exactly when they normalize to the same thing
+    fn normalize(&self) -> Self
+    {
+        return self.normalizing_unit()*self.clone();
+    }
+
+    // Normalized gcd
+    fn gcd(&self, other: &Self) -> Self
+    {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero()
+        {
+            let (_, remainder) = a.div_rem(&b);
+            a = b;
+            b = remainder;
+        }
+        return a.normalize();
+    }
+
+    // Same as gcd, but stops at the first overflowing division
+    fn checked_gcd(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero()
+        {
+            let (_, remainder) = a.checked_div_rem(&b)?;
+            a = b;
+            b = remainder;
+        }
+        return Ok(a.normalize());
+    }
+
+    // Returns (g,s,t) such that g = s*self + t*other
+    // and g is the normalized gcd
+    fn extended_gcd(&self, other: &Self) -> (Self, Self, Self)
+    {
+        let (mut old_r, mut r) = (self.clone(), other.clone());
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+
+        while !r.is_zero()
+        {
+            let (quotient, remainder) = old_r.div_rem(&r);
+            old_r = r;
+            r = remainder;
+
+            let new_s = old_s - quotient.clone()*s.clone();
+            old_s = s;
+            s = new_s;
+
+            let new_t = old_t - quotient*t.clone();
+            old_t = t;
+            t = new_t;
+        }
+
+        let unit = old_r.normalizing_unit();
+        return (unit.clone()*old_r, unit.clone()*old_s, unit*old_t);
+    }
+
+    // Normalized lcm
+    fn lcm(&self, other: &Self) -> Self
+    {
+        if self.is_zero() || other.is_zero()
+        {
+            return Self::zero();
+        }
+        let gcd = self.gcd(other);
+        return ( (self.clone()/gcd)*other.clone() ).normalize();
+    }
+}
+
 // Supertrait
 // Allows you to define a possible prime ideal for localization
 // See Zroot2 for an implementation
 // Also see Int for an implementation
 pub trait Localizable{
     // one should be able to check if the divisibility by ideal exists
-    fn is_divisible(self) -> bool;
+    fn is_divisible(&self) -> bool;
     // if it is divisible, we perform the division
     // We return the number of times we divided
     fn reduce_by_dividing(self) -> (Self,LogDepInt)
@@ -36,6 +294,13 @@ pub trait Localizable{
     // Multiply the ideal generator by the number of times given by n
     // WARNING: Bit Overflow may occur here if we perform too much multiplication
     fn perform_n_multiplications(self,_:LogDepInt) -> Self;
+
+    // Same as above, but reports the overflow
+    fn checked_perform_n_multiplications(self,_:LogDepInt) -> Result<Self, ArithmeticOverflow>
+    where Self: Sized;
+
+    // How the ideal generator is printed in Local<T>
+    const GENERATOR: &'static str = "√2";
     
 
 }
@@ -54,29 +319,95 @@ pub trait Localizable{
 
 pub mod zroot2;
 pub mod zomega;
+pub mod zzeta16;
+pub mod zi;
 pub mod int_localization;
+pub mod int_backend;
 pub mod local_ring;
 pub mod special_values;
-// pub mod quaternion;
+pub mod quaternion;
+pub mod parsing;
 
 use std::ops::Mul;
+use num_traits::One;
 
+// Square-and-multiply
+// Computes t^p with about 2*log2(p) multiplications
+//
+// The identity is passed in, so that this also works for
+// types like ExactUniMat which do not implement One
+pub fn binary_pow<T>(t: T, p: u32, one: T) -> T
+where T: Mul<Output=T>+Clone
+{
+    let mut out = one;
+    let mut square = t;
+    let mut p = p;
+    while p > 0
+    {
+        if p%2 == 1
+        {
+            out = out*square.clone();
+        }
+        p = p/2;
+        if p > 0
+        {
+            square = square.clone()*square;
+        }
+    }
+    return out;
+}
+
+// Non-negative powers
+// For negative powers of invertible elements, use num_traits::Pow<i32>
 pub fn pow<T>(t: T, p: Int) -> T
-where T: Mul<Output=T>+Copy
+where T: Mul<Output=T>+Clone+One
 {
-    if p<=0
+    if p<0
     {
-        panic!("Power on a non-positive integer");
+        panic!("Power on a negative integer");
     }
+    let p: u32 = p.try_into().expect("Exponent too large");
+    return binary_pow(t, p, T::one());
+}
 
-    let mut out = t;
-    // TODO: Smarter implementation using 
-    // binary expansion of p is possible here
-    for i in 1..p
+// Same as pow, but reports overflows
+pub fn checked_pow<T>(t: &T, p: Int) -> Result<T, ArithmeticOverflow>
+where T: CheckedRing+Clone+One
+{
+    if p<0
     {
-        out=out*t;
+        panic!("Power on a negative integer");
     }
-    return out;
+
+    let mut out = T::one();
+    let mut square = t.clone();
+    let mut p = p;
+    while p > 0
+    {
+        if p%2 == 1
+        {
+            out = out.checked_mul(&square)?;
+        }
+        p = p/2;
+        if p > 0
+        {
+            square = square.checked_mul(&square)?;
+        }
+    }
+    return Ok(out);
+}
+
+// Powers with a sign
+// Negative powers take the inverse first, which must exist
+pub fn signed_pow<T, F>(t: T, p: i32, one: T, inverse: F) -> T
+where T: Mul<Output=T>+Clone,
+      F: FnOnce(T) -> T
+{
+    if p < 0
+    {
+        return binary_pow(inverse(t), p.unsigned_abs(), one);
+    }
+    return binary_pow(t, p as u32, one);
 }
 
 
@@ -86,15 +417,78 @@ use num_traits::Num;
 // Implementing Conjuation for complex numbers
 impl<T> Conj for num_complex::Complex<T>
 where T: Neg<Output=T>,
-      T: Copy,
+      T: Clone,
       T: Num
 {
     fn conj(&self) -> Self
     {
         return Self
         {
-            re: self.re,
-            im: -self.im,
+            re: self.re.clone(),
+            im: -self.im.clone(),
         };
     }
 }
+
+// The real and imaginary parts are real, and σ_k(i) = i^k
+impl<T> GaloisAutomorphism for num_complex::Complex<T>
+where T: GaloisAutomorphism<Embedding=Float>,
+      T: Neg<Output=T>
+{
+    type Embedding = num_complex::Complex<Float>;
+
+    fn galois(&self, k: u8) -> Self
+    {
+        let re = self.re.galois(k);
+        let im = self.im.galois(k);
+        if galois_exponent(k)%4 == 1
+        {
+            return num_complex::Complex{ re, im };
+        }
+        return num_complex::Complex{ re, im: -im };
+    }
+
+    fn embeddings(&self) -> (Self::Embedding, Self::Embedding)
+    {
+        let (re, re_bullet) = self.re.embeddings();
+        let (im, im_bullet) = self.im.embeddings();
+        return (num_complex::Complex::new(re, im), num_complex::Complex::new(re_bullet, im_bullet));
+    }
+}
+
+// Checked arithmetic for complex numbers
+impl<T> CheckedRing for num_complex::Complex<T>
+where T: CheckedRing
+{
+    fn checked_add(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Self
+        {
+            re: self.re.checked_add(&other.re)?,
+            im: self.im.checked_add(&other.im)?,
+        });
+    }
+
+    fn checked_sub(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Self
+        {
+            re: self.re.checked_sub(&other.re)?,
+            im: self.im.checked_sub(&other.im)?,
+        });
+    }
+
+    fn checked_mul(&self, other: &Self) -> Result<Self, ArithmeticOverflow>
+    {
+        // (a+ib)(c+id) = (ac-bd) + i(ad+bc)
+        let ac = self.re.checked_mul(&other.re)?;
+        let bd = self.im.checked_mul(&other.im)?;
+        let ad = self.re.checked_mul(&other.im)?;
+        let bc = self.im.checked_mul(&other.re)?;
+        return Ok(Self
+        {
+            re: ac.checked_sub(&bd)?,
+            im: ad.checked_add(&bc)?,
+        });
+    }
+}
@@ -0,0 +1,158 @@
+// Helpers for the FromStr implementations of our rings
+//
+// Every string is first normalized: whitespace is dropped and the ASCII
+// spellings sqrt2, sqrt(2) and omega become √2 and w. After that, the
+// parsers only ever see what our Display implementations write out
+
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::ParseRingError;
+
+
+pub fn normalize(input: &str) -> String
+{
+    let mut out: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    for (from, to) in [("sqrt(2)", "√2"), ("sqrt2", "√2"), ("omega", "w"), ("ω", "w")]
+    {
+        out = out.replace(from, to);
+    }
+
+    // 2*√2 is the same as 2√2 and 3*w^2 is the same as 3w^2
+    // A * after anything else multiplies by a power of the generator, so it stays
+    let chars: Vec<char> = out.chars().collect();
+    let mut kept = String::new();
+    for i in 0..chars.len()
+    {
+        let is_coefficient_star = chars[i] == '*'
+            && i > 0 && chars[i-1].is_ascii_digit()
+            && i+1 < chars.len() && (chars[i+1] == '√' || chars[i+1] == 'w');
+        if !is_coefficient_star
+        {
+            kept.push(chars[i]);
+        }
+    }
+    return kept;
+}
+
+pub fn error(what: &str, input: &str) -> ParseRingError
+{
+    return ParseRingError(format!("{} in \"{}\"", what, input));
+}
+
+pub fn parse_integer<I: IntBackend>(input: &str) -> Result<I, ParseRingError>
+{
+    return I::from_str_radix(input, 10).map_err(|_| error("expected an integer", input));
+}
+
+
+// Splits at sep, ignoring anything inside brackets
+pub fn split_top_level(input: &str, sep: char) -> Vec<&str>
+{
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in input.char_indices()
+    {
+        match c
+        {
+            '(' | '[' => depth = depth + 1,
+            ')' | ']' => depth = depth - 1,
+            _ if c == sep && depth == 0 =>
+            {
+                parts.push(&input[start..i]);
+                start = i + c.len_utf8();
+            },
+            _ => {},
+        }
+    }
+    parts.push(&input[start..]);
+    return parts;
+}
+
+// Byte position of the last c outside of all brackets
+pub fn find_last_top_level(input: &str, c: char) -> Option<usize>
+{
+    let mut depth = 0;
+    let mut found = None;
+    for (i, d) in input.char_indices()
+    {
+        match d
+        {
+            '(' | '[' => depth = depth + 1,
+            ')' | ']' => depth = depth - 1,
+            _ if d == c && depth == 0 => found = Some(i),
+            _ => {},
+        }
+    }
+    return found;
+}
+
+// Removes one pair of brackets, if they enclose everything
+pub fn strip_brackets(input: &str, open: char, close: char) -> &str
+{
+    if !input.starts_with(open) || !input.ends_with(close)
+    {
+        return input;
+    }
+    let mut depth = 0;
+    for (i, c) in input.char_indices()
+    {
+        if c == open { depth = depth + 1; }
+        if c == close { depth = depth - 1; }
+        if depth == 0 && i + c.len_utf8() < input.len()
+        {
+            // The first bracket closes before the end, like in (1)*(2)
+            return input;
+        }
+    }
+    return &input[open.len_utf8()..input.len()-close.len_utf8()];
+}
+
+// Splits a sum like 3-2√2 or 1+-2w into terms, each with its sign
+// Returns (is_negative, term)
+pub fn signed_terms(input: &str) -> Result<Vec<(bool, &str)>, ParseRingError>
+{
+    let mut terms = vec![];
+    let mut negative = false;
+    let mut start: Option<usize> = None;
+    let mut previous = ' ';
+    for (i, c) in input.char_indices()
+    {
+        if (c == '+' || c == '-') && previous != '^'
+        {
+            if let Some(s) = start
+            {
+                terms.push((negative, &input[s..i]));
+                start = None;
+                negative = false;
+            }
+            if c == '-'
+            {
+                negative = !negative;
+            }
+        }
+        else if start == None
+        {
+            start = Some(i);
+        }
+        previous = c;
+    }
+    match start
+    {
+        Some(s) => terms.push((negative, &input[s..])),
+        None => return Err(error("expected a term after the sign", input)),
+    }
+    return Ok(terms);
+}
+
+// Exponents are written as ^k, ^-k, ^(k) or ^(-k)
+// A missing exponent means 1
+pub fn parse_exponent(input: &str) -> Result<i32, ParseRingError>
+{
+    if input.is_empty()
+    {
+        return Ok(1);
+    }
+    let exponent = input.strip_prefix('^').ok_or(error("expected ^", input))?;
+    let exponent = strip_brackets(exponent, '(', ')');
+    return exponent.parse().map_err(|_| error("expected an integer exponent", input));
+}
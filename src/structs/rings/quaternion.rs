@@ -0,0 +1,392 @@
+// Take a ring
+// And add i, j, k etc to it
+// This will create a Quaternion algebra over anything
+//
+// Over Local<Zroot2> this is an exact version of SU(2):
+// the matrix
+// /            \
+// | u    -t^*  |
+// | t     u^*  |
+// \            /
+// with u = a+bi and t = c-di is the quaternion a+bI+cJ+dK
+// so that multiplying matrices is the same as multiplying quaternions
+
+use crate::structs::rings::Conj; //Conjugation trait
+
+use crate::structs::rings::Int; //Integer type standard
+use crate::structs::rings::Float;
+use crate::structs::rings::IntBackend;
+use num_complex::Complex;
+
+// Num traits
+use num_traits::Num;
+use num_traits::Zero;
+use num_traits::One;
+use num_traits::Pow;
+
+// To construct gates
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::rings::special_values::mu_8;
+
+// We bring them in so that we can overload the operators
+// Rust must learn how to do arithmetics in our rings
+use std::ops::Neg;
+use std::ops::Add;
+use std::ops::Sub;
+use std::ops::Mul;
+use std::ops::Div;
+
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+
+// Quaternions.
+// They are of the form a+ib+cj+dk
+// where a,b,c,d are some ring elements of type T
+// It is required that T has implementations of Add,Sub,Mult,Neg,conj, etc.
+#[derive(Debug,Copy,Clone,Hash,PartialEq,Eq)]
+pub struct Quaternion<T>(pub T,pub T,pub T,pub T);
+
+
+// Nicely display Quaternions
+impl<T> Display for Quaternion<T>
+where T:Display
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        write!(f,"{}+{}*I+{}*J+{}*K", self.0, self.1 , self.2,self.3)
+    }
+}
+
+impl<T> Neg for Quaternion<T>
+where T: Neg<Output=T>
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self{
+            0: -self.0,
+            1: -self.1,
+            2: -self.2,
+            3: -self.3
+        }
+    }
+}
+
+// Conjugate Quaternion elements
+// For unit quaternions, this is the same as taking an inverse
+impl<T> Conj for Quaternion<T>
+where T: Neg<Output=T>,
+      T: Clone
+{
+    fn conj(&self) -> Self {
+        Self{
+            0: self.0.clone(),
+            1: -self.1.clone(),
+            2: -self.2.clone(),
+            3: -self.3.clone(),
+        }
+    }
+}
+
+
+
+
+// Teaching rust how to multiply Quaternion<T> elements
+// Also see this for why it looks so weird:
+// https://stackoverflow.com/questions/39169795/error-when-using-operators-with-a-generic-type
+impl<T> Mul for Quaternion<T>
+where T: Clone+Mul<Output=T>+Add<Output=T>+Sub<Output=T>
+{
+    type Output = Quaternion<T>;
+    fn mul(self, other: Quaternion<T>) -> Quaternion<T>
+    {
+        // NAIVE ALGORITHM:
+        //
+        // One can reduce the number of multiplications:
+        // https://math.stackexchange.com/questions/1103399/alternative-quaternion-multiplication-method
+        let (a0,a1,a2,a3) = (self.0, self.1, self.2, self.3);
+        let (b0,b1,b2,b3) = (other.0, other.1, other.2, other.3);
+        Self{
+            0: a0.clone()*b0.clone()-a1.clone()*b1.clone()-a2.clone()*b2.clone()-a3.clone()*b3.clone(),
+            1: a0.clone()*b1.clone()+a1.clone()*b0.clone()+a2.clone()*b3.clone()-a3.clone()*b2.clone(),
+            2: a0.clone()*b2.clone()-a1.clone()*b3.clone()+a2.clone()*b0.clone()+a3.clone()*b1.clone(),
+            3: a0*b3+a1*b2-a2*b1+a3*b0,
+        }
+    }
+}
+
+
+
+// Get zero and one as Quaternions
+impl<T> Quaternion<T>
+where T: Zero+One
+{
+    pub fn zero() -> Self
+    {
+        return Quaternion(T::zero(), T::zero(), T::zero(), T::zero());
+    }
+
+    pub fn one() -> Self
+    {
+        return Quaternion(T::one(), T::zero(), T::zero(), T::zero());
+    }
+}
+
+
+// Teaching rust how to add Quaternion elements
+impl<T> Add for Quaternion<T>
+where T: Add<Output=T>
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Quaternion(self.0+other.0,self.1+other.1,self.2+other.2,self.3+other.3)
+    }
+}
+
+
+// Teaching rust how to subtract Quaternion elements
+impl<T> Sub for Quaternion<T>
+where T: Sub<Output=T>
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self{
+            0: self.0-other.0,
+            1: self.1-other.1,
+            2: self.2-other.2,
+            3: self.3-other.3,
+        }
+    }
+}
+
+
+
+impl<T> Quaternion<T>
+where T: Mul<Output=T> + Add<Output=T>,
+      T: Clone
+{
+    // reduced square norm of our quaternion algebra
+    // Equal to whatever is the output here
+    pub fn rsqnorm(&self) -> T
+    {
+        let (a,b,c,d) = (self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone());
+        return a.clone()*a+b.clone()*b+c.clone()*c+d.clone()*d;
+    }
+}
+
+
+
+//Will work only if T has a division implementation
+impl<T> Quaternion<T>
+where T: Num+Neg<Output=T>+Clone
+{
+
+    pub fn inv(&self) -> Self
+    {
+        let norm = self.rsqnorm();
+        if norm.is_zero()
+        {
+            panic!("Division by zero in Quaternions!!!!");
+        }
+        else
+        {
+            Self{
+               0:  self.0.clone()/norm.clone(),
+               1: -self.1.clone()/norm.clone(),
+               2: -self.2.clone()/norm.clone(),
+               3: -self.3.clone()/norm,
+            }
+        }
+
+
+    }
+
+}
+
+//Will work only if T has a division implementation
+impl<T> Div for Quaternion<T>
+where T: Num+Neg<Output=T>+Clone
+{
+
+    type Output = Self;
+    fn div(self, other: Self ) -> Self
+    {
+        return self.mul(other.inv())
+    }
+}
+
+
+// Give two complex numbers over T
+// Writing a+bI+cJ+dK = (a+bI)+(c+dI)J
+// Here (a+bI) = z and (c+dI) = w
+impl <T> Quaternion<T>
+where T: Clone
+{
+    pub fn z(&self) -> Complex<T>
+    {
+        return Complex::<T>{
+            re: self.0.clone(),
+            im: self.1.clone()
+        };
+    }
+
+    pub fn w(&self) -> Complex<T>
+    {
+        return Complex::<T>{
+            re: self.2.clone(),
+            im: self.3.clone()
+        };
+    }
+
+    pub fn from_complex_pair(z: Complex<T>, w: Complex<T>) -> Self
+    {
+        return Quaternion(z.re, z.im, w.re, w.im);
+    }
+}
+
+
+
+// Moving between quaternions and special unitaries
+// The matrix with entries u and t is z+wJ with z = u and w = t^*
+impl<T> From<SUniMat<Complex<T>>> for Quaternion<T>
+where T: Neg<Output=T>+Clone+Num
+{
+    fn from(input: SUniMat<Complex<T>>) -> Self
+    {
+        return Quaternion::from_complex_pair(input.u, input.t.conj());
+    }
+}
+
+impl<T> From<Quaternion<T>> for SUniMat<Complex<T>>
+where T: Neg<Output=T>+Clone+Num
+{
+    fn from(input: Quaternion<T>) -> Self
+    {
+        return SUniMat
+        {
+            u: input.z(),
+            t: input.w().conj()
+        };
+    }
+}
+
+
+
+impl Quaternion<Float>
+{
+    // Scale to a unit quaternion
+    pub fn normalized(&self) -> Self
+    {
+        let length = self.rsqnorm().sqrt();
+        return Quaternion(self.0/length, self.1/length, self.2/length, self.3/length);
+    }
+
+    // Unit quaternions are rotations of the Bloch sphere
+    // Returns the angle of rotation and the unit vector (x,y,z) it rotates around,
+    // so that the gate is exp(-i angle/2 (xX+yY+zZ)) up to phase
+    //
+    // The axis is in Bloch sphere coordinates, not in the I, J, K frame.
+    // Comparing a+bI+cJ+dK with the matrix, x = d, y = c and z = -b, up to the sine
+    // The identity returns the angle zero around the z axis
+    pub fn angle_and_axis(&self) -> (Float, [Float;3])
+    {
+        let unit = self.normalized();
+        let sine = ( unit.1*unit.1 + unit.2*unit.2 + unit.3*unit.3 ).sqrt();
+        if sine == 0.0
+        {
+            return (0.0, [0.0, 0.0, 1.0]);
+        }
+        let angle = 2.0*sine.atan2(unit.0);
+        return (angle, [unit.3/sine, unit.2/sine, -unit.1/sine]);
+    }
+}
+
+
+
+// Better looking code
+type Loc<I = Int> = Local<Zroot2<I>>;
+type Quat<I = Int> = Quaternion<Loc<I>>;
+
+impl<I: IntBackend> Quat<I>
+{
+    pub fn to_float(&self) -> Quaternion<Float>
+    {
+        return Quaternion(self.0.to_float(), self.1.to_float(), self.2.to_float(), self.3.to_float());
+    }
+
+    // Same as to_float, but scaled to unit length
+    pub fn to_float_unit_quaternion(&self) -> Quaternion<Float>
+    {
+        return self.to_float().normalized();
+    }
+
+    // The T gate diag(1,ω) is ω^(1/2) times a special unitary
+    // which is not defined over our ring.
+    // This is the same gate scaled by 1+ω^(-1), see ExactUniMat::to_quaternion
+    pub fn t_gate() -> Quat<I>
+    {
+        return ExactUniMat::<I>::t_gate().to_quaternion();
+    }
+
+    pub fn h_gate() -> Quat<I>
+    {
+        return ExactUniMat::<I>::h_gate().to_quaternion();
+    }
+}
+
+
+// An ExactUniMat with phase ω^k has determinant ω^k
+// Scaling by c = ω^(-k/2) when k is even, and c = 1+ω^(-k) when k is odd
+// makes the determinant c^2 ω^k real and positive. This scaled matrix is a quaternion
+// with rsqnorm 1, 2+√2 or 2-√2
+impl<I: IntBackend> ExactUniMat<I>
+{
+    fn quaternion_scaling(omega_exp: u8) -> Complex<Loc<I>>
+    {
+        let omega_exp = omega_exp%8;
+        if omega_exp%2 == 0
+        {
+            return mu_8().pow((8-omega_exp/2)%8);
+        }
+        return Complex::<Loc<I>>::one() + mu_8().pow(8-omega_exp);
+    }
+
+    pub fn to_quaternion(&self) -> Quat<I>
+    {
+        let c = Self::quaternion_scaling(self.omega_exp);
+        return Quaternion::from(SUniMat
+        {
+            u: self.mat.u.clone()*c.clone(),
+            t: self.mat.t.clone()*c
+        });
+    }
+
+    // Undoes to_quaternion, given the phase of the gate
+    pub fn from_quaternion(input: &Quat<I>, omega_exp: u8) -> Self
+    {
+        let c = Self::quaternion_scaling(omega_exp);
+        let c_inv = c.conj()*Complex::from(Loc::one()/c.norm_sqr());
+        let mat = SUniMat::<Complex<Loc<I>>>::from(input.clone());
+        return ExactUniMat
+        {
+            mat: SUniMat
+            {
+                u: mat.u*c_inv.clone(),
+                t: mat.t*c_inv
+            },
+            omega_exp: omega_exp%8
+        };
+    }
+
+    // A unit quaternion for the gate, forgetting its global phase
+    pub fn to_float_unit_quaternion(&self) -> Quaternion<Float>
+    {
+        return self.to_quaternion().to_float_unit_quaternion();
+    }
+}
@@ -1,7 +1,9 @@
 use crate::structs::rings::local_ring::Local;
 use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
 use crate::structs::rings::zroot2::Zroot2;
 use crate::structs::rings::zomega::Zomega;
+use crate::structs::rings::zzeta16::Zzeta16;
 use num_complex::Complex;
 
 use num_traits::One;
@@ -9,11 +11,13 @@ use num_traits::Zero;
 
 
 // Returning the eigth root of unity
-type Loc = Local<Zroot2>;
-type Comp = Complex<Loc>;
+// These are written for every integer backend
+// The backend is Int unless the caller asks for another one
+type Loc<I = Int> = Local<Zroot2<I>>;
+type Comp<I = Int> = Complex<Loc<I>>;
 
 
-pub fn onebyroot2comp() -> Comp
+pub fn onebyroot2comp<I: IntBackend>() -> Comp<I>
 {
     return Comp
     {
@@ -33,7 +37,7 @@ pub fn onebyroot2comp() -> Comp
 
 }
 
-pub fn sqrt2loc() -> Loc
+pub fn sqrt2loc<I: IntBackend>() -> Loc<I>
 {
     return Loc
         {
@@ -43,22 +47,28 @@ pub fn sqrt2loc() -> Loc
 
 }
 
-pub fn iota_zomega() -> Zomega
+pub fn iota_zomega<I: IntBackend>() -> Zomega<I>
 {
-    return Zomega(0,0,1,0);
+    return Zomega(I::zero(),I::zero(),I::one(),I::zero());
 }
 
-pub fn sqrt2_zomega() -> Zomega
+pub fn sqrt2_zomega<I: IntBackend>() -> Zomega<I>
 {
-    return Zomega(0,1,0,-1);
+    return Zomega(I::zero(),I::one(),I::zero(),-I::one());
 }
 
-pub fn omega() -> Zomega
+pub fn omega<I: IntBackend>() -> Zomega<I>
 {
-    return Zomega(0,1,0,0);
+    return Zomega(I::zero(),I::one(),I::zero(),I::zero());
 }
 
-pub fn onebyroot2loc() -> Loc
+// The sixteenth root of unity, for the Clifford+√T gates
+pub fn zeta16<I: IntBackend>() -> Zzeta16<I>
+{
+    return Zzeta16(Zomega::zero(), Zomega::one());
+}
+
+pub fn onebyroot2loc<I: IntBackend>() -> Loc<I>
 {
     return Loc
         {
@@ -69,7 +79,7 @@ pub fn onebyroot2loc() -> Loc
 }
 
 
-pub fn mu_8() -> Comp
+pub fn mu_8<I: IntBackend>() -> Comp<I>
 {
     return Comp
     {
@@ -87,7 +97,7 @@ pub fn mu_8() -> Comp
     };
 }
 
-pub fn sqrt2() -> Comp
+pub fn sqrt2<I: IntBackend>() -> Comp<I>
 {
     return Comp
     {
@@ -99,7 +109,7 @@ pub fn sqrt2() -> Comp
     };
 }
 
-pub fn sqrtminus1() -> Comp
+pub fn sqrtminus1<I: IntBackend>() -> Comp<I>
 {
     return Comp{
         re: Loc::zero(),
@@ -109,11 +119,11 @@ pub fn sqrtminus1() -> Comp
 
 
 
-pub fn sqrt2plus1() -> Zroot2
+pub fn sqrt2plus1<I: IntBackend>() -> Zroot2<I>
 {
     return Zroot2{
-        0: Int::one(),
-        1: Int::one(),
+        0: I::one(),
+        1: I::one(),
     };
 
 }
@@ -0,0 +1,349 @@
+// The Gaussian integers Z[i]
+// This is where the Pauli+V gates live, like Z[ω] is where the Clifford+T gates live
+//
+// The V gates have 1/√5 in them, and 5 = (2+i)(2-i) splits in Z[i].
+// Localizing at 2+i alone would not be closed under complex conjugation,
+// which sends 2+i to 2-i, so Local<Zi> inverts 5 instead
+
+
+use crate::structs::rings::Conj;
+use crate::structs::rings::LocalizableConj;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::Localizable;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::CheckedRing;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::structs::rings::galois_exponent;
+use crate::structs::rings::Float;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
+
+use crate::algorithms::near_int::nearest_integer;
+
+use num_complex::Complex;
+
+use num_traits::Num;
+use num_traits::Zero;
+use num_traits::One;
+
+// We bring them in so that we can overload the operators
+// Rust must learn how to do arithmetics in our rings
+use std::ops::Neg;
+use std::ops::Add;
+use std::ops::Sub;
+use std::ops::Mul;
+use num_traits::Pow;
+use std::ops::Div;
+use std::ops::Rem;
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+
+// Ring of numbers of the form a+bi
+#[derive(Debug,Copy, Clone)]
+pub struct Zi<I = Int>(pub I, pub I);
+
+
+// Conjugate Zi elements
+impl<I: IntBackend> Conj for Zi<I> {
+    fn conj(&self) -> Zi<I> {
+        return Zi(self.0.clone(), -self.1.clone());
+    }
+}
+
+// i = ω^2, so σ_k fixes i when k = 1 mod 4 and conjugates it otherwise
+// The bullet fixes i, so both embeddings are the same
+impl<I: IntBackend> GaloisAutomorphism for Zi<I>
+{
+    type Embedding = Complex<Float>;
+
+    fn galois(&self, k: u8) -> Self
+    {
+        if galois_exponent(k)%4 == 1
+        {
+            return self.clone();
+        }
+        return self.conj();
+    }
+
+    fn embeddings(&self) -> (Complex<Float>, Complex<Float>)
+    {
+        let x = Complex::new(self.0.to_float(), self.1.to_float());
+        return (x, x);
+    }
+}
+
+// Nicely display Zi elements
+impl<I: IntBackend> Display for Zi<I>
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        write!(f,"{}+{}i",self.0, self.1)
+    }
+}
+
+// Negatation on Zi
+impl<I: IntBackend> Neg for Zi<I>{
+    type Output = Zi<I>;
+
+    fn neg(self) -> Zi<I> {
+        Zi(-self.0,-self.1)
+    }
+}
+
+// Teaching rust how to add Zi elements
+impl<I: IntBackend> Add for Zi<I> {
+    type Output = Zi<I>;
+
+    fn add(self, other: Zi<I>) -> Zi<I> {
+        Zi(self.0+other.0, self.1+other.1)
+    }
+}
+
+// Teaching rust how to subtract Zi elements
+impl<I: IntBackend> Sub for Zi<I> {
+    type Output = Zi<I>;
+
+    fn sub(self, other: Zi<I>) -> Zi<I> {
+        self+(-other)
+    }
+}
+
+// Teaching rust how to multiply Zi elements
+impl<I: IntBackend> Mul for Zi<I> {
+    type Output = Zi<I>;
+
+    fn mul(self, other: Zi<I>) -> Zi<I> {
+        let (a, b) = (self.0, self.1);
+        let (c, d) = (other.0, other.1);
+        Zi(a.clone()*c.clone() - b.clone()*d.clone(), a*d + b*c)
+    }
+}
+
+// Powers by square-and-multiply
+impl<I: IntBackend> Pow<u32> for Zi<I>
+{
+    type Output = Self;
+    fn pow(self, p: u32) -> Self
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers only exist for the units ±1 and ±i
+impl<I: IntBackend> Pow<i32> for Zi<I>
+{
+    type Output = Self;
+    fn pow(self, p: i32) -> Self
+    {
+        return signed_pow(self, p, Self::one(), |x| x.unit_inverse().expect("Negative power of a non-unit"));
+    }
+}
+
+// Localizing at 5, which is (2+i)(2-i)
+// Since 5 is an integer, it divides a+bi exactly when it divides a and b
+impl<I: IntBackend> Localizable for Zi<I>
+{
+    fn is_divisible(&self) -> bool
+    {
+        let five = I::from_int(5);
+        return (self.0.clone()%five.clone()).is_zero() && (self.1.clone()%five).is_zero();
+    }
+
+    fn reduce_by_dividing(mut self) -> ( Self, LogDepInt )
+    {
+        let five = I::from_int(5);
+        let mut pow: LogDepInt = 0;
+        while !self.is_zero() && self.is_divisible()
+        {
+            self = Zi(self.0/five.clone(), self.1/five.clone());
+            pow = pow + 1;
+        }
+        return (self, pow);
+    }
+
+    fn perform_n_multiplications(mut self, n: LogDepInt) -> Self
+    {
+        let five = I::from_int(5);
+        for _ in 0..n
+        {
+            self = Zi(self.0*five.clone(), self.1*five.clone());
+        }
+        return self;
+    }
+
+    fn checked_perform_n_multiplications(mut self, n: LogDepInt) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        let five = I::from_int(5);
+        for _ in 0..n
+        {
+            self = Zi(self.0.checked_mul(&five)?, self.1.checked_mul(&five)?);
+        }
+        return Ok(self);
+    }
+
+    const GENERATOR: &'static str = "5";
+}
+
+// 5 is real, so conjugation leaves the denominator alone
+impl<I: IntBackend> LocalizableConj for Zi<I>
+{
+    fn multiply_by_generator_ratio(self, _: LogDepInt) -> Self
+    {
+        return self;
+    }
+}
+
+// Teaching rust how to compare these ring elements
+impl<I: IntBackend> PartialEq for Zi<I>
+{
+    fn eq(&self, other: &Self) -> bool {
+        return self.0==other.0 && self.1==other.1;
+    }
+}
+
+impl<I: IntBackend> Zero for Zi<I>
+{
+    fn zero() -> Self
+    {
+        return Zi(I::zero(), I::zero());
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        return self.0.is_zero() && self.1.is_zero();
+    }
+}
+
+impl<I: IntBackend> One for Zi<I>
+{
+    fn one() -> Self
+    {
+        return Zi(I::one(), I::zero());
+    }
+}
+
+impl<I: IntBackend> Num for Zi<I>
+{
+    // Some rust requirements to make a Local<Zi> ring
+    type FromStrRadixErr = std::num::ParseIntError  ;
+    fn from_str_radix(_: &str, _: u32) -> core::result::Result<Self, Self::FromStrRadixErr>
+    {
+        panic!("Unimplimented or impossible");
+    }
+}
+
+// Not a LocalizableNorm: Local::norm assumes the generator has norm ±2^m
+// while here it is 25
+impl<I: IntBackend> Zi<I>
+{
+    pub fn norm(&self) -> I
+    {
+        return self.0.clone()*self.0.clone() + self.1.clone()*self.1.clone();
+    }
+
+    // Moving between integer backends
+    // Returns None if the coefficients do not fit in J
+    pub fn to_backend<J: IntBackend>(&self) -> Option<Zi<J>>
+    {
+        return Some(Zi(
+                J::from_bigint(&self.0.to_bigint())?,
+                J::from_bigint(&self.1.to_bigint())?
+                ));
+    }
+}
+
+// Same as +, - and * but never wraps around
+impl<I: IntBackend> CheckedRing for Zi<I>
+{
+    fn checked_add(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Zi(self.0.checked_add(&other.0)?, self.1.checked_add(&other.1)?));
+    }
+
+    fn checked_sub(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Zi(self.0.checked_sub(&other.0)?, self.1.checked_sub(&other.1)?));
+    }
+
+    fn checked_mul(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        let (a, b) = (&self.0, &self.1);
+        let (c, d) = (&other.0, &other.1);
+        return Ok(Zi(
+                a.checked_mul(c)?.checked_sub(&b.checked_mul(d)?)?,
+                a.checked_mul(d)?.checked_add(&b.checked_mul(c)?)?
+                ));
+    }
+}
+
+
+// Euclidean division
+// Rounding x*ȳ/N(y) coordinate-wise leaves a remainder r with N(r) <= N(y)/2
+impl<I: IntBackend> EuclideanDomain for Zi<I>
+{
+    fn div_rem(&self, other: &Self) -> (Self, Self)
+    {
+        if other.is_zero()
+        {
+            println!("Wanted to divide {} with {}",self,other);
+            panic!("Division impossible");
+        }
+        let numerator = self.clone()*other.conj();
+        let denominator = other.norm();
+        let quotient = Zi(
+            nearest_integer(numerator.0, denominator.clone()),
+            nearest_integer(numerator.1, denominator)
+            );
+        let remainder = self.clone() - quotient.clone()*other.clone();
+        return (quotient, remainder);
+    }
+
+    fn is_unit(&self) -> bool
+    {
+        return self.norm() == I::one();
+    }
+
+    // The associate with positive real part and non-negative imaginary part
+    fn normalizing_unit(&self) -> Self
+    {
+        let mut unit = Self::one();
+        let mut x = self.clone();
+        for _ in 0..4
+        {
+            if x.is_zero() || (x.0.is_positive() && !x.1.is_negative())
+            {
+                break;
+            }
+            // Multiplying by i
+            x = Zi(-x.1, x.0);
+            unit = Zi(-unit.1, unit.0);
+        }
+        return unit;
+    }
+}
+
+impl<I: IntBackend> Div for Zi<I>
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self
+    {
+        // When other divides self, this is exact
+        // Otherwise it is the quotient of Euclidean division
+        return self.div_rem(&other).0;
+    }
+}
+
+impl<I: IntBackend> Rem for Zi<I>
+{
+    type Output = Self;
+    fn rem(self, other: Self) -> Self
+    {
+        return self.div_rem(&other).1;
+    }
+}
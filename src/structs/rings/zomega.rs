@@ -6,16 +6,36 @@
 
 
 use crate::structs::rings::Conj; //Conjugation trait
+use crate::structs::rings::LocalizableConj;
+use crate::structs::rings::LogDepInt;
 use crate::structs::rings::Localizable;
 use crate::structs::rings::Int; //Conjugation trait
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::LocalizableNorm;
+use crate::structs::rings::CheckedRing;
+use crate::structs::rings::CheckedNorm;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::structs::rings::galois_exponent;
+use crate::structs::rings::Float;
+use crate::structs::rings::ParseRingError;
+use crate::structs::rings::parsing;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
 use crate::structs::rings::zroot2::Zroot2;
 use crate::structs::rings::local_ring::Local;
 
 use crate::structs::rings::special_values::onebyroot2loc;
+use crate::structs::rings::special_values::iota_zomega;
+use crate::structs::rings::special_values::sqrt2_zomega;
 
-use crate::algorithms::near_int::nearest_integer;
+use num_complex::Complex;
 
 
+
+use num_traits::Num;
 use num_traits::NumCast;
 use num_traits::ToPrimitive;
 use num_traits::Zero;
@@ -27,8 +47,10 @@ use std::ops::Neg;
 use std::ops::Add; 
 use std::ops::Sub; 
 use std::ops::Mul; 
+use num_traits::Pow;
 use std::ops::Div; 
 use std::ops::Rem; 
+use std::str::FromStr;
 
 // For display
 use std::fmt::Result;
@@ -38,82 +60,267 @@ use std::fmt::Formatter;
 
 // Ring of numbers of the form a+b\omega+c\omega^2+d\omega^3
 // where \omega is the eighth root of unity
+// As with Zroot2, write Zomega<BigInt> to never overflow
 #[derive(Debug,Copy, Clone)]
-pub struct Zomega(pub Int, pub Int, pub Int, pub Int); 
+pub struct Zomega<I = Int>(pub I, pub I, pub I, pub I); 
 
 
 
 // Conjugate Zomega elements
-impl Conj for Zomega {
-    fn conj(&self) -> Zomega {
-        return Zomega(self.0,-self.3, -self.2, -self.1);
+impl<I: IntBackend> Conj for Zomega<I> {
+    fn conj(&self) -> Zomega<I> {
+        return Zomega(self.0.clone(),-self.3.clone(), -self.2.clone(), -self.1.clone());
     }
 }
 
 // Conjugate sqrt2 in Zomega elements 
-impl Zomega 
+// σ_k sends the coefficient of ω^j to ω^(jk), and ω^4 = -1
+impl<I: IntBackend> GaloisAutomorphism for Zomega<I>
+{
+    type Embedding = Complex<Float>;
+
+    fn galois(&self, k: u8) -> Self
+    {
+        let k = galois_exponent(k) as usize;
+        let coefficients = [self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone()];
+        let mut out = [I::zero(), I::zero(), I::zero(), I::zero()];
+        for (j, coefficient) in coefficients.into_iter().enumerate()
+        {
+            let power = (j*k)%8;
+            if power < 4
+            {
+                out[power] = out[power].clone() + coefficient;
+            }
+            else
+            {
+                out[power-4] = out[power-4].clone() - coefficient;
+            }
+        }
+        let [a, b, c, d] = out;
+        return Zomega(a, b, c, d);
+    }
+
+    // With ω = (1+i)/√2, ω^2 = i and ω^3 = (-1+i)/√2, the real part is
+    // (2a+(b-d)√2)/2 and the imaginary part is (2c+(b+d)√2)/2
+    // σ_5 sends ω to -ω, which is the bullet on both parts
+    fn embeddings(&self) -> (Complex<Float>, Complex<Float>)
+    {
+        let (a, b, c, d) = (self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone());
+        let (re, re_bullet) = Zroot2(a.clone()+a, b.clone()-d.clone()).embeddings();
+        let (im, im_bullet) = Zroot2(c.clone()+c, b+d).embeddings();
+        return (Complex::new(re/2.0, im/2.0), Complex::new(re_bullet/2.0, im_bullet/2.0));
+    }
+}
+
+impl<I: IntBackend> Zomega<I> 
 {
-    fn conj_rt2(&self) -> Zomega 
+    pub fn conj_rt2(&self) -> Zomega<I> 
     {
-        return Zomega(self.0,-self.1, self.2, -self.3);
+        return Zomega(self.0.clone(),-self.1.clone(), self.2.clone(), -self.3.clone());
     }
 }
 
 // Nicely display Zomega Matrices
-impl Display for Zomega
+impl<I: IntBackend> Display for Zomega<I>
 {
     fn fmt(&self, f: &mut Formatter) -> Result{
         write!(f,"{}+{}w+{}w^2+{}w^3",self.0, self.1, self.2,self.3)
     }
 }
 
+// Reads back what Display writes, like 1+-2w+0w^2+3w^3
+// Also takes the tuple (1,-2,0,3), ω or omega in place of w, any power of w, and √2
+impl<I: IntBackend> FromStr for Zomega<I>
+{
+    type Err = ParseRingError;
+
+    fn from_str(input: &str) -> core::result::Result<Self, ParseRingError>
+    {
+        let normalized = parsing::normalize(input);
+
+        let tuple = parsing::strip_brackets(&normalized, '(', ')');
+        if tuple.len() < normalized.len() && tuple.contains(',')
+        {
+            let coefficients = parsing::split_top_level(tuple, ',');
+            if coefficients.len() != 4
+            {
+                return Err(parsing::error("expected four coefficients", input));
+            }
+            return Ok(Zomega(
+                    parsing::parse_integer(coefficients[0])?,
+                    parsing::parse_integer(coefficients[1])?,
+                    parsing::parse_integer(coefficients[2])?,
+                    parsing::parse_integer(coefficients[3])?
+                    ));
+        }
+
+        let mut out = Zomega::zero();
+        for (negative, term) in parsing::signed_terms(&normalized)?
+        {
+            let mut value = if let Some(coefficient) = term.strip_suffix("√2")
+            {
+                let c: I = if coefficient.is_empty() { I::one() } else { parsing::parse_integer(coefficient)? };
+                Zomega(I::zero(), c.clone(), I::zero(), -c)
+            }
+            else if let Some(position) = term.find('w')
+            {
+                let coefficient = &term[..position];
+                let c: I = if coefficient.is_empty() { I::one() } else { parsing::parse_integer(coefficient)? };
+                let power = parsing::parse_exponent(&term[position+1..])?;
+
+                // ω^4 = -1
+                let mut monomial = Zomega(c, I::zero(), I::zero(), I::zero());
+                for _ in 0..power.rem_euclid(8)
+                {
+                    monomial = Zomega(-monomial.3, monomial.0, monomial.1, monomial.2);
+                }
+                monomial
+            }
+            else
+            {
+                Zomega(parsing::parse_integer(term)?, I::zero(), I::zero(), I::zero())
+            };
+            if negative
+            {
+                value = -value;
+            }
+            out = out + value;
+        }
+        return Ok(out);
+    }
+}
+
 // Negatation on Zomega
-impl Neg for Zomega{
-    type Output = Zomega;
+impl<I: IntBackend> Neg for Zomega<I>{
+    type Output = Zomega<I>;
 
-    fn neg(self) -> Zomega {
+    fn neg(self) -> Zomega<I> {
         Zomega(-self.0,-self.1,-self.2,-self.3)
     }
 }
 
 
 // Teaching rust how to add Zomega elements
-impl Add for Zomega {
-    type Output = Zomega;
+impl<I: IntBackend> Add for Zomega<I> {
+    type Output = Zomega<I>;
 
-    fn add(self, other: Zomega) -> Zomega {
+    fn add(self, other: Zomega<I>) -> Zomega<I> {
         Zomega(self.0+other.0,self.1+other.1,self.2+other.2,self.3+other.3)
     }
 }
 
 // Teaching rust how to subtract Zomega elements
-impl Sub for Zomega {
-    type Output = Zomega;
+impl<I: IntBackend> Sub for Zomega<I> {
+    type Output = Zomega<I>;
 
-    fn sub(self, other: Zomega) -> Zomega {
+    fn sub(self, other: Zomega<I>) -> Zomega<I> {
         self+(-other) //subtraction is just adding the additive inverse
     }
 }
 
 
 // Teaching rust how to multiply Zomega elements
-impl Mul for Zomega {
-    type Output = Zomega;
+impl<I: IntBackend> Mul for Zomega<I> {
+    type Output = Zomega<I>;
 
-    fn mul(self, other: Zomega) -> Zomega {
+    fn mul(self, other: Zomega<I>) -> Zomega<I> {
+        let (x0,x1,x2,x3) = (self.0, self.1, self.2, self.3);
+        let (y0,y1,y2,y3) = (other.0, other.1, other.2, other.3);
         Zomega(
-            other.0*self.0 - other.1*self.3 - other.2*self.2 - other.3*self.1,
-            other.0*self.1 + other.1*self.0 - other.2*self.3 - other.3*self.2,
-            other.0*self.2 + other.1*self.1 + other.2*self.0 - other.3*self.3,
-            other.0*self.3 + other.1*self.2 + other.2*self.1 + other.3*self.0
+            y0.clone()*x0.clone() - y1.clone()*x3.clone() - y2.clone()*x2.clone() - y3.clone()*x1.clone(),
+            y0.clone()*x1.clone() + y1.clone()*x0.clone() - y2.clone()*x3.clone() - y3.clone()*x2.clone(),
+            y0.clone()*x2.clone() + y1.clone()*x1.clone() + y2.clone()*x0.clone() - y3.clone()*x3.clone(),
+            y0*x3 + y1*x2 + y2*x1 + y3*x0
             )
     }
 }
 
-// impl Localizable for Zomega{
+// Powers by square-and-multiply
+impl<I: IntBackend> Pow<u32> for Zomega<I>
+{
+    type Output = Self;
+    fn pow(self, p: u32) -> Self
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
 
-//         todo!();
-// }
+// Negative powers only exist for units, like ω and 1+√2
+impl<I: IntBackend> Pow<i32> for Zomega<I>
+{
+    type Output = Self;
+    fn pow(self, p: i32) -> Self
+    {
+        return signed_pow(self, p, Self::one(), |x| x.unit_inverse().expect("Negative power of a non-unit"));
+    }
+}
+
+// Localizing at the prime 1+ω, which lies over 2
+// Write δ = 1+ω. Then δ*(1-ω+ω^2-ω^3) = 2, so δ divides x exactly
+// when x*(1-ω+ω^2-ω^3) has even coefficients, which happens exactly
+// when a+b+c+d is even
+impl<I: IntBackend> Localizable for Zomega<I>
+{
+    fn is_divisible(&self) -> bool
+    {
+        let sum = self.0.clone() + self.1.clone() + self.2.clone() + self.3.clone();
+        return sum.trailing_zeros() > 0;
+    }
+
+    fn reduce_by_dividing(mut self) -> ( Self, LogDepInt )
+    {
+        let mut pow: LogDepInt = 0;
+        while !self.is_zero() && self.is_divisible()
+        {
+            let (a,b,c,d) = (self.0, self.1, self.2, self.3);
+            self = Zomega(
+                (a.clone() + b.clone() - c.clone() + d.clone()) >> 1,
+                (b.clone() - a.clone() + c.clone() - d.clone()) >> 1,
+                (a.clone() - b.clone() + c.clone() + d.clone()) >> 1,
+                (d - c + b - a) >> 1,
+                );
+            pow = pow + 1;
+        }
+        return (self, pow);
+    }
+
+    // (a+bω+cω^2+dω^3)(1+ω) = (a-d) + (a+b)ω + (b+c)ω^2 + (c+d)ω^3
+    fn perform_n_multiplications(mut self, n: LogDepInt) -> Self
+    {
+        for _ in 0..n
+        {
+            let (a,b,c,d) = (self.0, self.1, self.2, self.3);
+            self = Zomega(a.clone() - d.clone(), a + b.clone(), b + c.clone(), c + d);
+        }
+        return self;
+    }
+
+    fn checked_perform_n_multiplications(mut self, n: LogDepInt) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        for _ in 0..n
+        {
+            let (a,b,c,d) = (&self.0, &self.1, &self.2, &self.3);
+            self = Zomega(a.checked_sub(d)?, a.checked_add(b)?, b.checked_add(c)?, c.checked_add(d)?);
+        }
+        return Ok(self);
+    }
+
+    const GENERATOR: &'static str = "(1+ω)";
+}
+
+// The complex conjugate of 1+ω is 1+ω^7 = (1+ω)/ω
+impl<I: IntBackend> LocalizableConj for Zomega<I>
+{
+    fn multiply_by_generator_ratio(self, n: LogDepInt) -> Self
+    {
+        let mut out = self;
+        for _ in 0..n.rem_euclid(8)
+        {
+            out = Zomega(-out.3, out.0, out.1, out.2);
+        }
+        return out;
+    }
+}
 
 // Get zero and one as Zomega numbers
 // impl<T> Constructs<T> for Zomega
@@ -132,7 +339,7 @@ impl Mul for Zomega {
 
 
 // Teaching rust how to compare these ring elements
-impl PartialEq for Zomega
+impl<I: IntBackend> PartialEq for Zomega<I>
 {
     fn eq(&self, other: &Self) -> bool {
         // println!("{},{},{},{}",self.0,self.1,self.2,self.3);
@@ -143,7 +350,7 @@ impl PartialEq for Zomega
 
 
 
-impl ToPrimitive for Zomega
+impl<I: IntBackend> ToPrimitive for Zomega<I>
 {
     // Rust needs this. 
     // The NumCast library does not work otherwise
@@ -160,137 +367,494 @@ impl ToPrimitive for Zomega
 
 }
 
-impl NumCast for Zomega
+impl<I: IntBackend> NumCast for Zomega<I>
 {
     fn from<T>(given: T) -> Option<Self>
         where T: ToPrimitive
         {
             return Some(Self
                         {
-                            0: given.to_i128().unwrap(),
-                            1: 0,
-                            2: 0,
-                            3: 0
+                            0: I::from_int(given.to_i128().unwrap()),
+                            1: I::zero(),
+                            2: I::zero(),
+                            3: I::zero()
                         });
         }
 }
 
 
-impl Zero for Zomega
+impl<I: IntBackend> Zero for Zomega<I>
 {
     fn zero() -> Self
     {
-        return Zomega(0,0,0,0);
+        return Zomega(I::zero(),I::zero(),I::zero(),I::zero());
     }
 
     fn is_zero(&self) -> bool
     {
-        return self.1==0 && self.0 == 0 && self.2==0 && self.3==0;
+        return self.1.is_zero() && self.0.is_zero() && self.2.is_zero() && self.3.is_zero();
     }
 }
 
-impl One for Zomega
+impl<I: IntBackend> One for Zomega<I>
 {
     fn one() -> Self
     {
-        return Zomega(1,0,0,0);
+        return Zomega(I::one(),I::zero(),I::zero(),I::zero());
+    }
+}
+
+impl<I: IntBackend> Num for Zomega<I>
+{
+    // Some rust requirements to make a Local<Zomega> ring
+    type FromStrRadixErr = std::num::ParseIntError  ;
+    fn from_str_radix(_: &str, _: u32) -> core::result::Result<Self, Self::FromStrRadixErr>
+    {
+        panic!("Unimplimented or impossible");
     }
 }
 
-impl Zomega
+impl<I: IntBackend> Zomega<I>
 {
-    pub fn norm(&self) -> Int
+    pub fn norm(&self) -> I
+    {
+        let (x0,x1,x2,x3) = (self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone());
+        let term1 = x0.clone()*x0.clone() + x1.clone()*x1.clone() + x2.clone()*x2.clone() + x3.clone()*x3.clone();
+        let term2 = x0.clone()*x1.clone() + x1*x2.clone() - x0*x3.clone() + x2*x3;
+        let term2_squared = term2.clone()*term2;
+        return term1.clone()*term1 - term2_squared.clone() - term2_squared;
+    }
+
+    // Moving between integer backends
+    // Returns None if the coefficients do not fit in J
+    pub fn to_backend<J: IntBackend>(&self) -> Option<Zomega<J>>
     {
-        let term1 = (self.0*self.0 + self.1*self.1 + self.2*self.2 + self.3*self.3);
-        let term2 = (self.0*self.1 + self.1*self.2 - self.0*self.3 + self.2*self.3);
-        return term1*term1 - 2*term2*term2;
+        return Some(Zomega(
+                J::from_bigint(&self.0.to_bigint())?,
+                J::from_bigint(&self.1.to_bigint())?,
+                J::from_bigint(&self.2.to_bigint())?,
+                J::from_bigint(&self.3.to_bigint())?
+                ));
     }
 
 }
 
+impl<I: IntBackend> LocalizableNorm for Zomega<I>
+{
+    type Integer = I;
+
+    fn norm(&self) -> I
+    {
+        return Zomega::norm(self);
+    }
+}
 
-// This code here has been the culprit for a lot of integer overflows
-// This is basically because calculating norm is like calculating the 
-// fourth powers of some integers; 
-impl Div for Zomega 
+// Same formula as norm, but stops at the first overflow
+impl<I: IntBackend> CheckedNorm for Zomega<I>
 {
+    fn checked_norm(&self) -> core::result::Result<I, ArithmeticOverflow>
+    {
+        let (x0,x1,x2,x3) = (&self.0, &self.1, &self.2, &self.3);
+        let term1 = x0.checked_mul(x0)?
+            .checked_add(&x1.checked_mul(x1)?)?
+            .checked_add(&x2.checked_mul(x2)?)?
+            .checked_add(&x3.checked_mul(x3)?)?;
+        let term2 = x0.checked_mul(x1)?
+            .checked_add(&x1.checked_mul(x2)?)?
+            .checked_sub(&x0.checked_mul(x3)?)?
+            .checked_add(&x2.checked_mul(x3)?)?;
+        let term2_squared = term2.checked_mul(&term2)?;
+        return term1.checked_mul(&term1)?.checked_sub(&term2_squared)?.checked_sub(&term2_squared);
+    }
+}
 
-    type Output = Self;
-    fn div(self, other: Self) -> Self
+// Same as +, - and * but never wraps around
+impl<I: IntBackend> CheckedRing for Zomega<I>
+{
+    fn checked_add(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
     {
+        return Ok(Zomega(
+                self.0.checked_add(&other.0)?,
+                self.1.checked_add(&other.1)?,
+                self.2.checked_add(&other.2)?,
+                self.3.checked_add(&other.3)?
+                ));
+    }
 
-        // Zomega is norm-Euclidean
-        let nor = other.norm();
-        if nor==1 
+    fn checked_sub(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Zomega(
+                self.0.checked_sub(&other.0)?,
+                self.1.checked_sub(&other.1)?,
+                self.2.checked_sub(&other.2)?,
+                self.3.checked_sub(&other.3)?
+                ));
+    }
+
+    fn checked_mul(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        // Same formula as in Mul
+        let (x0,x1,x2,x3) = (&self.0, &self.1, &self.2, &self.3);
+        let (y0,y1,y2,y3) = (&other.0, &other.1, &other.2, &other.3);
+        return Ok(Zomega(
+            y0.checked_mul(x0)?.checked_sub(&y1.checked_mul(x3)?)?.checked_sub(&y2.checked_mul(x2)?)?.checked_sub(&y3.checked_mul(x1)?)?,
+            y0.checked_mul(x1)?.checked_add(&y1.checked_mul(x0)?)?.checked_sub(&y2.checked_mul(x3)?)?.checked_sub(&y3.checked_mul(x2)?)?,
+            y0.checked_mul(x2)?.checked_add(&y1.checked_mul(x1)?)?.checked_add(&y2.checked_mul(x0)?)?.checked_sub(&y3.checked_mul(x3)?)?,
+            y0.checked_mul(x3)?.checked_add(&y1.checked_mul(x2)?)?.checked_add(&y2.checked_mul(x1)?)?.checked_add(&y3.checked_mul(x0)?)?
+            ));
+    }
+}
+
+
+// Euclidean division
+//
+// Writing ȳ for the complex conjugate and n = y*ȳ for the relative norm,
+// which lives in Z[√2], we have x/y = x*ȳ/n. The quotient is the element
+// of Z[ω] nearest to x/y, coordinate-wise in the basis 1, ω, ω², ω³, which
+// gives a remainder r with |N(r)| <= 9/16 |N(y)|, so the remainder's norm
+// is always smaller than the divisor's.
+//
+// The rounding is done in Z[√2], without forming N(y) in Z[ω]. Z[ω] is
+// Z[√2][i] together with ω + Z[√2][i], and in each of the two the nearest
+// element comes from dividing two Zroot2 coordinates by n. The better of the
+// two is the nearest element of Z[ω].
+//
+// When the rounding overflows, it is redone over BigInt. The quotient
+// itself is about the size of x/y, and only it and q*y have to fit back in I
+impl<I: IntBackend> Zomega<I>
+{
+    // Returns (q,r) such that self = q*other + r and |N(r)| < |N(other)|
+    //
+    // Requires q and q*other to fit in I, which checked_div_rem reports
+    // instead. Like *, this panics when they do not
+    pub fn div_rem(&self, other: &Self) -> (Self, Self)
+    {
+        return match self.checked_div_rem(other)
+        {
+            Ok(quotient_and_remainder) => quotient_and_remainder,
+            Err(ArithmeticOverflow) => panic!("Quotient of {} by {} does not fit in the integer backend", self, other),
+        };
+    }
+
+    // Same as div_rem, but returns an error when q or q*other overflows I
+    pub fn checked_div_rem(&self, other: &Self) -> core::result::Result<(Self, Self), ArithmeticOverflow>
+    {
+        if other.is_zero()
         {
-            return self*other.conj()*other.conj_rt2()*other.conj_rt2().conj();
+            println!("Wanted to divide {} with {}",self,other);
+            panic!("Division impossible");
         }
-        else if nor == -1
+
+        let quotient = match self.checked_euclidean_quotient(other)
+        {
+            Ok(quotient) => quotient,
+            // Only the rounding step got too big for I
+            Err(ArithmeticOverflow) => 
+            {
+                let self_big = self.to_backend::<BigInt>().unwrap();
+                let other_big = other.to_backend::<BigInt>().unwrap();
+                let quotient_big = self_big.checked_euclidean_quotient(&other_big)?;
+                quotient_big.to_backend::<I>().ok_or(ArithmeticOverflow)?
+            }
+        };
+
+        let remainder = self.checked_sub(&quotient.checked_mul(other)?)?;
+        return Ok((quotient, remainder));
+    }
+
+    fn checked_euclidean_quotient(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        let other_conj = other.conj();
+
+        // y*ȳ is real, so it is of the form a + b(ω - ω³) = a + b√2
+        let relative_norm = other.checked_mul(&other_conj)?;
+        let relative_norm = Zroot2(relative_norm.0, relative_norm.1);
+
+        let numerator = self.checked_mul(&other_conj)?;
+        let in_zroot2_i = round_div_in_zroot2_i(&numerator, &relative_norm)?;
+
+        // Same with x*ȳ/n - ω, and ω added back
+        let omega = Zomega(I::zero(), I::one(), I::zero(), I::zero());
+        let shifted = numerator.checked_sub(&omega.checked_mul(&Zomega::from_zroot2(relative_norm.clone()))?)?;
+        let in_other_coset = round_div_in_zroot2_i(&shifted, &relative_norm)?.checked_add(&omega)?;
+
+        // |x/y - q|² = r*r̄/n for the remainder r = x - q*y. Summing over both
+        // embeddings gives the squared distance in the coordinates, and the
+        // sign of a difference of those is the sign of the integer part of
+        // (r1*r̄1 - r2*r̄2)*n•
+        let squared_distance = |quotient: &Self| -> core::result::Result<Zroot2<I>, ArithmeticOverflow>
+        {
+            let remainder = self.checked_sub(&quotient.checked_mul(other)?)?;
+            let times_conj = remainder.checked_mul(&remainder.conj())?;
+            return Ok(Zroot2(times_conj.0, times_conj.1));
+        };
+        let difference = squared_distance(&in_zroot2_i)?.checked_sub(&squared_distance(&in_other_coset)?)?;
+        if difference.checked_mul(&relative_norm.conj())?.0 > I::zero()
         {
-            return -self*other.conj()*other.conj_rt2()*other.conj_rt2().conj();
+            return Ok(in_other_coset);
         }
-        else if nor!=0
+        return Ok(in_zroot2_i);
+    }
+}
+
+// Nearest element of Z[√2][i] to m/n, for n in Z[√2]
+// m = a + bω + cω² + dω³ is ( 2a + (b-d)√2 + (2c + (b+d)√2) i )/2, and both
+// of these Zroot2 coordinates are rounded by the Zroot2 division by 2n
+fn round_div_in_zroot2_i<I: IntBackend>(m: &Zomega<I>, n: &Zroot2<I>) -> core::result::Result<Zomega<I>, ArithmeticOverflow>
+{
+    let twice_n = n.checked_add(n)?;
+    let real = Zroot2(m.0.checked_add(&m.0)?, m.1.checked_sub(&m.3)?);
+    let imaginary = Zroot2(m.2.checked_add(&m.2)?, m.1.checked_add(&m.3)?);
+
+    let (real, _) = real.checked_div_rem(&twice_n)?;
+    let (imaginary, _) = imaginary.checked_div_rem(&twice_n)?;
+
+    let i = Zomega(I::zero(), I::zero(), I::one(), I::zero());
+    return Zomega::from_zroot2(real).checked_add(&i.checked_mul(&Zomega::from_zroot2(imaginary))?);
+}
+
+// The units of Zomega are ω^j λ^k where λ = 1+√2
+impl<I: IntBackend> EuclideanDomain for Zomega<I>
+{
+    fn div_rem(&self, other: &Self) -> (Self, Self)
+    {
+        return Zomega::div_rem(self, other);
+    }
+
+    fn checked_div_rem(&self, other: &Self) -> core::result::Result<(Self, Self), ArithmeticOverflow>
+    {
+        return Zomega::checked_div_rem(self, other);
+    }
+
+    // Units divide one
+    // Unlike the norm, this never overflows
+    fn is_unit(&self) -> bool
+    {
+        if self.is_zero()
         {
-            // WARNING: This is bad mathematics 
-            //          because when self/other is not exactly in Zroot2
-            //          the output of this will be self = q*other + r
-            //          where absolute value of r.norm() is less than
-            //          absolute value of other.norm().
-            //
-            //          Basically, this does Euclidean division
-            //          and finds q given above
-            let numerator =  self*other.conj()*other.conj_rt2()*other.conj_rt2().conj();
-            let denominator = nor;
+            return false;
+        }
+        return Zomega::div_rem(&Self::one(), self).1.is_zero();
+    }
 
+    // First we fix the power of λ:
+    // The relative norm n = x*x̄ is doubly positive, and multiplying x by λ
+    // multiplies the ratio of the two embeddings of n by λ^4. 
+    // We make that ratio land in [1,λ^4).
+    //
+    // Then we fix the power of ω:
+    // Among x, xω, ..., xω^7 we take the one with the largest coefficients,
+    // compared lexicographically
+    fn normalizing_unit(&self) -> Self
+    {
+        if self.is_zero()
+        {
+            return Self::one();
+        }
 
-            return Zomega( nearest_integer(numerator.0,nor),  nearest_integer(numerator.1, nor) , nearest_integer( numerator.2, nor) , nearest_integer( numerator.3, nor) );
+        let lambda = Zomega::from_zroot2(Zroot2(I::one(), I::one()));
+        let lambda_inv = Zomega::from_zroot2(Zroot2(-I::one(), I::one()));
+        let lambda_squared = Zroot2(I::from_int(3), I::from_int(2));
+        let lambda_inv_squared = Zroot2(I::from_int(3), I::from_int(-2));
 
+        let relative_norm = self.clone()*self.conj();
+        let mut n = Zroot2(relative_norm.0, relative_norm.1);
+        let mut unit = Self::one();
+
+        loop
+        {
+            // The ratio is smaller than 1
+            if n.1.is_negative()
+            {
+                n = n*lambda_squared.clone();
+                unit = unit*lambda.clone();
+                continue;
+            }
+
+            // The ratio is at least λ^4
+            // n*λ^(-2) = (3n0-4n1) + (3n1-2n0)√2
+            let three = I::from_int(3);
+            let two = I::from_int(2);
+            if !(three*n.1.clone() - two*n.0.clone()).is_negative()
+            {
+                n = n*lambda_inv_squared.clone();
+                unit = unit*lambda_inv.clone();
+                continue;
+            }
+
+            break;
         }
-        else 
+
+        let x = unit.clone()*self.clone();
+        let mut best = x.clone();
+        let mut best_power = Self::one();
+        let mut candidate = x;
+        let mut candidate_power = Self::one();
+        for _ in 1..8
         {
-            println!("Wanted to divide {} with {}",self,other);
-            panic!("Division impossible");
+            // Multiplying by ω shifts the coefficients
+            candidate = Zomega(-candidate.3, candidate.0, candidate.1, candidate.2);
+            candidate_power = Zomega(-candidate_power.3, candidate_power.0, candidate_power.1, candidate_power.2);
+
+            let candidate_key = (&candidate.0, &candidate.1, &candidate.2, &candidate.3);
+            let best_key = (&best.0, &best.1, &best.2, &best.3);
+            if candidate_key > best_key
+            {
+                best = candidate.clone();
+                best_power = candidate_power.clone();
+            }
         }
 
+        return unit*best_power;
     }
 }
 
+impl<I: IntBackend> Div for Zomega<I> 
+{
 
+    type Output = Self;
+    fn div(self, other: Self) -> Self
+    {
+        // When other divides self, this is exact
+        // Otherwise it is the quotient of Euclidean division
+        return self.div_rem(&other).0;
+    }
+}
 
-impl Rem for Zomega 
+
+
+impl<I: IntBackend> Rem for Zomega<I> 
 {
     type Output = Self;
     fn rem(self, other: Self) -> Self
     {
-        let q = self/other;
-        return self-q*other;
+        return self.div_rem(&other).1;
     }
 
 }
 
 
-type Loc = Local::<Zroot2>;
-impl Zomega
+type Loc<I> = Local::<Zroot2<I>>;
+impl<I: IntBackend> Zomega<I>
 {
     // Spit out real and imaginary parts as Local::Zroot2 elements
     // a + b*omega + c*omega^2 + d*omega^3 
     // =  ( b-d + a*sqrt2 )/ sqrt2 + i (b+d + c * sqrt2) / sqrt2
-    pub fn real_part(&self) -> Loc
+    pub fn real_part(&self) -> Loc<I>
     {
-        let zrt = Zroot2( self.1 - self.3 , self.0 );
+        let zrt = Zroot2( self.1.clone() - self.3.clone() , self.0.clone() );
         let term = Loc::from_base(zrt);
         return term*onebyroot2loc();
     }
-    pub fn imag_part(&self) -> Loc
+    pub fn imag_part(&self) -> Loc<I>
     {
-        let zrt = Zroot2( self.1 + self.3 , self.2 );
+        let zrt = Zroot2( self.1.clone() + self.3.clone() , self.2.clone() );
         let term = Loc::from_base(zrt);
         return term*onebyroot2loc();
     }
 
-    pub fn from_zroot2(input: Zroot2) -> Zomega 
+    pub fn from_zroot2(input: Zroot2<I>) -> Zomega<I> 
+    {
+        return Zomega(input.0, input.1.clone(), I::zero() ,-input.1);
+    }
+
+}
+
+
+
+// D[ω] = Z[ω] localized at δ = 1+ω
+// Every element is x/δ^k for a single k, where Complex<Local<Zroot2>> needs
+// one denominator for the real part and another for the imaginary part
+//
+// The identities used below are
+//      δ*conj(δ) = √2λ,  δ^2 = √2λω,  δ/σ(δ) = ω^2*λ
+// where λ = 1+√2 and σ is the √2-conjugation
+type DLoc<I> = Local::<Zomega<I>>;
+
+impl<I: IntBackend> Local<Zomega<I>>
+{
+    // The smallest denominator exponent
+    // Since |x/δ^k|^2 = x*conj(x)/(√2λ)^k and x*conj(x) is not divisible by √2
+    // this is the same sde as the one in exact_synth, which looks at |x|^2
+    pub fn sde(&self) -> LogDepInt
+    {
+        return self.log_den;
+    }
+
+    // The √2-Galois automorphism, sending ω to -ω
+    pub fn conj_rt2(&self) -> Self
+    {
+        // ω^2*λ and its inverse -ω^2*λ^(-1)
+        let ratio = Zomega(I::zero(), I::one(), I::one(), I::one());
+        let ratio_inv = Zomega(I::zero(), -I::one(), I::one(), -I::one());
+
+        let mut num = self.num.conj_rt2();
+        for _ in 0..self.log_den.abs()
+        {
+            num = num * if self.log_den > 0 { ratio.clone() } else { ratio_inv.clone() };
+        }
+        return Local
+        {
+            num,
+            log_den: self.log_den
+        };
+    }
+
+    // a/√2^k = a*(λω)^k/δ^(2k)
+    fn from_zroot2_local(input: Loc<I>) -> Self
     {
-        return Zomega(input.0, input.1, 0 ,-input.1);
+        let lambda_omega = Zomega(I::one(), I::one(), I::one(), I::zero());
+
+        let mut num = Zomega::from_zroot2(input.num);
+        for _ in 0..input.log_den.abs()
+        {
+            num = num * if input.log_den > 0 { lambda_omega.clone() } else { sqrt2_zomega() };
+        }
+        let den = Local
+        {
+            num: Zomega::one(),
+            log_den: 2*input.log_den.max(0)
+        };
+        return Local::from_base(num)*den;
     }
+}
+
 
+// Lossless conversions between the two ways of writing exact gate entries
+impl<I: IntBackend> From<Complex<Loc<I>>> for DLoc<I>
+{
+    fn from(input: Complex<Loc<I>>) -> Self
+    {
+        let iota = Local::from_base(iota_zomega());
+        return Self::from_zroot2_local(input.re) + iota*Self::from_zroot2_local(input.im);
+    }
+}
+
+impl<I: IntBackend> From<DLoc<I>> for Complex<Loc<I>>
+{
+    // x/δ^k = x*conj(δ)^k/(√2λ)^k = x*(conj(δ)/λ)^k / √2^k
+    fn from(input: DLoc<I>) -> Self
+    {
+        let conj_delta_by_lambda = Zomega(I::one(), I::zero(), I::zero(), -I::one())*Zomega(-I::one(), I::one(), I::zero(), -I::one());
+
+        let mut num = input.num;
+        if input.log_den < 0
+        {
+            num = num.perform_n_multiplications(-input.log_den);
+        }
+        for _ in 0..input.log_den
+        {
+            num = num*conj_delta_by_lambda.clone();
+        }
+        let den = Loc
+        {
+            num: Zroot2::one(),
+            log_den: input.log_den.max(0)
+        };
+        return Complex
+        {
+            re: num.real_part()*den.clone(),
+            im: num.imag_part()*den
+        };
+    }
 }
@@ -1,6 +1,19 @@
 use crate::structs::rings::Localizable; //Localizing trait
 use crate::structs::rings::Conj; //Conjugation trait
+use crate::structs::rings::LocalizableConj;
 use crate::structs::rings::LocalizableNorm; //Norm trait
+use crate::structs::rings::CheckedRing; //Overflow-checked arithmetic
+use crate::structs::rings::CheckedNorm;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::structs::rings::galois_exponent;
+use crate::algorithms::inexact_synth::SQRT2;
+use crate::structs::rings::ParseRingError;
+use crate::structs::rings::parsing;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
+use crate::structs::rings::checked_pow;
 use crate::algorithms::near_int::nearest_integer;
 
 
@@ -10,9 +23,12 @@ use std::ops::Neg;
 use std::ops::Add; 
 use std::ops::Sub; 
 use std::ops::Mul; 
+use num_traits::Pow;
 use std::ops::Div; 
 use std::ops::Rem; 
 use std::cmp::PartialEq; 
+use std::cmp::Ordering;
+use std::str::FromStr;
 
 // For display
 use std::fmt::Result;
@@ -24,6 +40,8 @@ use std::fmt::Formatter;
 use crate::structs::rings::Int;
 use crate::structs::rings::Float;
 use crate::structs::rings::LogDepInt;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::BigInt;
 
 // Num traits
 use num_traits::Num;
@@ -32,27 +50,31 @@ use num_traits::One;
 use num_traits::NumOps;
 use num_traits::NumCast;
 use num_traits::ToPrimitive;
+use num_traits::Signed;
 
 
 // Quadratic number field with root 2
 // This struct assumes that you are going to localize it 
 // at sqrt(2)
-#[derive(Copy,Debug,Clone,PartialEq,PartialOrd,Hash,Eq)]
-pub struct Zroot2(pub Int,pub Int); //a+b\sqrt(2)
+//
+// The coefficients are Int unless asked otherwise
+// Write Zroot2<BigInt> to never overflow
+#[derive(Copy,Debug,Clone,PartialEq,Hash,Eq)]
+pub struct Zroot2<I = Int>(pub I,pub I); //a+b\sqrt(2)
 
 // Rust must know how to diplay elements of this ring
 // Rust could learn some latex
-impl Display for Zroot2{
+impl<I: IntBackend> Display for Zroot2<I>{
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        if self.0!=0
+        if !self.0.is_zero()
         {
-            if self.1<0 
+            if self.1.is_negative()
             {
                 write!(f, "{}{}√2", self.0, self.1)
             }
-            else if self.1>0
+            else if self.1.is_positive()
             {
-                if self.1==1
+                if self.1.is_one()
                 {
                     write!(f, "{}+√2", self.0)
                 }
@@ -68,7 +90,7 @@ impl Display for Zroot2{
         }
         else
         {
-            if self.1!=0
+            if !self.1.is_zero()
             {
                 write!(f, "{}√2", self.1)
             }
@@ -80,31 +102,52 @@ impl Display for Zroot2{
     }
 }
 
-// Teaching rust how to add Zroot2 elements
-impl Add for Zroot2 {
-    type Output = Zroot2;
+// Reads back what Display writes, like 3-2√2, √2 or -5
+// The ASCII spelling 3-2*sqrt2 works too
+impl<I: IntBackend> FromStr for Zroot2<I>
+{
+    type Err = ParseRingError;
 
-    fn add(self, other: Zroot2) -> Zroot2 {
-        Zroot2(self.0 + other.0, self.1 + other.1)
+    fn from_str(input: &str) -> core::result::Result<Self, ParseRingError>
+    {
+        let normalized = parsing::normalize(input);
+        let mut out = Zroot2(I::zero(), I::zero());
+        for (negative, term) in parsing::signed_terms(&normalized)?
+        {
+            let mut value = match term.strip_suffix("√2")
+            {
+                Some("") => Zroot2(I::zero(), I::one()),
+                Some(coefficient) => Zroot2(I::zero(), parsing::parse_integer(coefficient)?),
+                None => Zroot2(parsing::parse_integer(term)?, I::zero()),
+            };
+            if negative
+            {
+                value = -value;
+            }
+            out = out + value;
+        }
+        return Ok(out);
     }
 }
 
-// Conjugate Zroot2 elements
-// The only non-trivial element in the Galois group
-impl Zroot2 {
-    pub fn conj(self) -> Zroot2 {
-        Zroot2(self.0,-self.1)
+// Teaching rust how to add Zroot2 elements
+impl<I: IntBackend> Add for Zroot2<I> {
+    type Output = Zroot2<I>;
+
+    fn add(self, other: Zroot2<I>) -> Zroot2<I> {
+        Zroot2(self.0 + other.0, self.1 + other.1)
     }
 }
 
+
 // Allows localization
 // Hence, we can have numbers like a+bsqrt(2)/sqrt(2)^k
 // See code in local_ring.rs
 // And the comments in mod.rs
-impl Localizable for Zroot2
+impl<I: IntBackend> Localizable for Zroot2<I>
 {
-    fn is_divisible(self) -> bool{
-        if self.0%2==0 { true }
+    fn is_divisible(&self) -> bool{
+        if self.0.trailing_zeros() > 0 { true }
         else { false }
     }
     fn reduce_by_dividing(mut self) -> ( Self, LogDepInt )
@@ -148,7 +191,7 @@ impl Localizable for Zroot2
     {
         // (  a+bsqrt(2) )*2^(n/2) = (a*2^(n/2) +b*( k/2 + 1/2) ) 
         // println!("Will multiply {} times",n);
-        let ntemp=n >> 1;
+        let ntemp: u32 = (n >> 1).try_into().unwrap();
         self.0 = self.0 << ntemp;
         self.1 = self.1 << ntemp;
 
@@ -160,83 +203,443 @@ impl Localizable for Zroot2
         return self;
     }
 
+    fn checked_perform_n_multiplications(mut self, n: LogDepInt) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        // Same as above, one shift at a time
+        let ntemp: u32 = (n >> 1).try_into().map_err(|_| ArithmeticOverflow)?;
+        self.0 = self.0.checked_shl(ntemp).ok_or(ArithmeticOverflow)?;
+        self.1 = self.1.checked_shl(ntemp).ok_or(ArithmeticOverflow)?;
+
+        if n%2==1
+        {
+            (self.0,self.1) = (self.1.checked_shl(1).ok_or(ArithmeticOverflow)?,self.0);
+        }
+
+        return Ok(self);
+    }
+
 }
 
 
-impl LocalizableNorm for Zroot2
+impl<I: IntBackend> LocalizableNorm for Zroot2<I>
 {
+    type Integer = I;
     
-    fn norm(&self) -> Int
+    fn norm(&self) -> I
+    {
+        let square_of_b = self.1.clone()*self.1.clone();
+        return self.0.clone()*self.0.clone()-square_of_b.clone()-square_of_b;
+    }
+
+}
+
+impl<I: IntBackend> CheckedNorm for Zroot2<I>
+{
+    fn checked_norm(&self) -> core::result::Result<I, ArithmeticOverflow>
+    {
+        let square_of_b = self.1.checked_mul(&self.1)?;
+        return self.0.checked_mul(&self.0)?.checked_sub(&square_of_b)?.checked_sub(&square_of_b);
+    }
+}
+
+// Same as +, - and * but never wraps around
+impl<I: IntBackend> CheckedRing for Zroot2<I>
+{
+    fn checked_add(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Zroot2(self.0.checked_add(&other.0)?, self.1.checked_add(&other.1)?));
+    }
+
+    fn checked_sub(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Zroot2(self.0.checked_sub(&other.0)?, self.1.checked_sub(&other.1)?));
+    }
+
+    fn checked_mul(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        // (a+b√2)(c+d√2) = (ac+2bd) + (ad+bc)√2
+        let b_times_d = other.1.checked_mul(&self.1)?;
+        let first = other.0.checked_mul(&self.0)?.checked_add(&b_times_d)?.checked_add(&b_times_d)?;
+        let second = other.0.checked_mul(&self.1)?.checked_add(&other.1.checked_mul(&self.0)?)?;
+        return Ok(Zroot2(first, second));
+    }
+}
+
+// Exact signs and ordering by real value
+// a+b√2 with a and b of opposite signs has the sign of the bigger one
+// of a^2 and 2b^2, so we never need to know √2
+impl<I: IntBackend> Zroot2<I>
+{
+    pub fn sign(&self) -> Ordering
+    {
+        let sign_a = self.0.cmp(&I::zero());
+        let sign_b = self.1.cmp(&I::zero());
+        if sign_a == sign_b || sign_b == Ordering::Equal
+        {
+            return sign_a;
+        }
+        if sign_a == Ordering::Equal
+        {
+            return sign_b;
+        }
+
+        let squares = self.0.checked_mul(&self.0)
+            .and_then(|a_squared| Ok((a_squared, self.1.checked_mul(&self.1)?)))
+            .and_then(|(a_squared, b_squared)| Ok((a_squared, b_squared.checked_add(&b_squared)?)));
+        let a_squared_vs_twice_b_squared = match squares
+        {
+            Ok((a_squared, twice_b_squared)) => a_squared.cmp(&twice_b_squared),
+            Err(_) =>
+            {
+                let (a, b) = (self.0.to_bigint(), self.1.to_bigint());
+                (a.clone()*a).cmp(&(BigInt::from(2)*b.clone()*b))
+            }
+        };
+
+        return match a_squared_vs_twice_b_squared
+        {
+            Ordering::Greater => sign_a,
+            _ => sign_b,
+        };
+    }
+
+    // Returns -1, 0 or 1
+    pub fn signum(&self) -> Self
+    {
+        return match self.sign()
+        {
+            Ordering::Greater => Self::one(),
+            Ordering::Less => -Self::one(),
+            Ordering::Equal => Self::zero(),
+        };
+    }
+
+    pub fn abs(&self) -> Self
+    {
+        if self.sign() == Ordering::Less { -self.clone() } else { self.clone() }
+    }
+
+    // Both a+b√2 and a-b√2 are non-negative
+    pub fn is_doubly_positive(&self) -> bool
+    {
+        return self.sign() != Ordering::Less && self.clone().conj().sign() != Ordering::Less;
+    }
+
+    // floor(a+b√2) = a + floor(b√2), and floor(b√2) is an integer square root
+    pub fn floor(&self) -> I
+    {
+        let b = self.1.to_bigint();
+        let mut floor_of_b_root2 = (BigInt::from(2)*b.clone()*b.clone()).sqrt();
+        if b.is_negative()
+        {
+            floor_of_b_root2 = -floor_of_b_root2 - BigInt::one();
+        }
+        return self.0.clone() + I::from_bigint(&floor_of_b_root2).unwrap();
+    }
+
+    // Nearest integer, rounding halves up
+    // a+b√2 is never exactly a half, so there are no ties
+    pub fn round(&self) -> I
     {
-        return self.0*self.0-2*self.1*self.1;
+        let doubled = Zroot2(self.0.clone()+self.0.clone()+I::one(), self.1.clone()+self.1.clone());
+        return doubled.floor() >> 1;
     }
+}
+
+impl<I: IntBackend> Ord for Zroot2<I>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        return match self.checked_sub(other)
+        {
+            Ok(difference) => difference.sign(),
+            Err(_) => (self.to_backend::<BigInt>().unwrap() - other.to_backend::<BigInt>().unwrap()).sign(),
+        };
+    }
+}
 
+impl<I: IntBackend> PartialOrd for Zroot2<I>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        return Some(self.cmp(other));
+    }
 }
 
-impl Zroot2
+// Zroot2 is norm-Euclidean
+// The units are ±λ^k where λ = 1+√2
+impl<I: IntBackend> EuclideanDomain for Zroot2<I>
 {
+    fn div_rem(&self, other: &Self) -> (Self, Self)
+    {
+        let quotient = self.clone()/other.clone();
+        let remainder = self.clone() - quotient.clone()*other.clone();
+        return (quotient, remainder);
+    }
+
+    // Same rounding as Div, with every step checked
+    fn checked_div_rem(&self, other: &Self) -> core::result::Result<(Self, Self), ArithmeticOverflow>
+    {
+        if other.is_zero()
+        {
+            println!("Wanted to divide {} with {}",self,other);
+            panic!("Division impossible");
+        }
+
+        let nor = other.checked_norm()?;
+        let numerator = self.checked_mul(&Conj::conj(other))?;
+
+        // nearest_integer doubles the numerator, make sure there is room for it
+        numerator.0.checked_shl(1).ok_or(ArithmeticOverflow)?;
+        numerator.1.checked_shl(1).ok_or(ArithmeticOverflow)?;
+
+        let quotient = Zroot2( nearest_integer(numerator.0, nor.clone()), nearest_integer(numerator.1, nor) );
+        let remainder = self.checked_sub(&quotient.checked_mul(other)?)?;
+        return Ok((quotient, remainder));
+    }
 
     // Should check if norm is a unit 
-    fn is_unit(self) -> bool
+    // Units can have huge coefficients, so the norm falls back to BigInt
+    fn is_unit(&self) -> bool
+    {
+        return match self.checked_norm()
+        {
+            Ok(norm) => norm.abs().is_one(),
+            Err(_) => self.to_big().norm().abs().is_one(),
+        };
+    }
+
+    // Multiplying by λ multiplies |a+b√2|/|a-b√2| by λ^2
+    // The canonical associate is the one where this ratio is in [1,λ^2)
+    // and a+b√2 is positive
+    //
+    // Everything is decided by signs, so there are no floats involved
+    fn normalizing_unit(&self) -> Self
     {
-        if self.norm()==1 || self.norm()==-1
+        if self.is_zero()
         {
-            return true;
+            return Self::one();
         }
-        else
+
+        let lambda = Zroot2(I::one(), I::one());
+        let lambda_inv = Zroot2(-I::one(), I::one());
+
+        let mut x = self.clone();
+        let mut unit = Self::one();
+        loop
+        {
+            // a*b < 0 exactly when the ratio is smaller than 1
+            if (x.0.signum()*x.1.signum()).is_negative()
+            {
+                x = x*lambda.clone();
+                unit = unit*lambda.clone();
+                continue;
+            }
+
+            // Ratio is at least λ^2 exactly when it is at least 1 after dividing by λ
+            let y = x.clone()*lambda_inv.clone();
+            if !(y.0.signum()*y.1.signum()).is_negative()
+            {
+                x = y;
+                unit = unit*lambda_inv.clone();
+                continue;
+            }
+
+            break;
+        }
+
+        // Now a and b have the same sign
+        if x.0.is_negative() || x.1.is_negative()
+        {
+            unit = -unit;
+        }
+        return unit;
+    }
+}
+
+// The units of Z[√2] are exactly ±λ^k where λ = 1+√2
+//
+// Everything here is exact. The exponents are found with
+// comparisons in BigInt, so huge units are not a problem
+impl<I: IntBackend> Zroot2<I>
+{
+    fn to_big(&self) -> Zroot2<BigInt>
+    {
+        return Zroot2(self.0.to_bigint(), self.1.to_bigint());
+    }
+
+    // λ^k, for any integer k
+    // λ^(-1) = √2-1 is -conj(λ), so λ^(-k) = (-1)^k conj(λ^k)
+    pub fn lambda_pow(k: LogDepInt) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        let lambda_to_abs_k = checked_pow(&Zroot2(I::one(), I::one()), k.unsigned_abs().into())?;
+        if k >= 0
+        {
+            return Ok(lambda_to_abs_k);
+        }
+        let inverse = lambda_to_abs_k.conj();
+        return Ok( if k%2 == 0 { inverse } else { -inverse } );
+    }
+
+    // Writes a unit u as sign*λ^k, and returns (sign, k)
+    // sign is 1 or -1. Returns None if u is not a unit
+    pub fn unit_decompose(&self) -> Option<(Self, LogDepInt)>
+    {
+        if !self.is_unit()
+        {
+            return None;
+        }
+        let sign = self.signum();
+        let u = self.to_big().abs();
+
+        // λ^k < 1 exactly when k < 0, and then u^(-1) = λ^(-k) > 1
+        if u < Zroot2::one()
+        {
+            let inverse = u.unit_inverse().unwrap();
+            return Some((sign, -positive_unit_exponent(inverse)));
+        }
+        return Some((sign, positive_unit_exponent(u)));
+    }
+
+    // The square root of a unit in Z[√2], if there is one
+    // These are the doubly positive units λ^(2k)
+    pub fn unit_sqrt(&self) -> Option<Self>
+    {
+        let (sign, k) = self.unit_decompose()?;
+        if sign != Self::one() || k%2 != 0
+        {
+            return None;
+        }
+        // λ^(k/2) has smaller coefficients than λ^k, so this fits
+        return Self::lambda_pow(k/2).ok();
+    }
+
+    // Returns (self*λ^m, m) where the embeddings x and x• of self*λ^m
+    // are as balanced as they can be: λ^(-1) <= |x/x•| < λ
+    //
+    // Multiplying by λ multiplies |x/x•| by λ^2. Writing N = x*x•,
+    // |x/x•| = x^2/|N|, so the check is λ^(-1)|N| <= x^2 < λ|N|
+    pub fn reduce_by_units(&self) -> core::result::Result<(Self, LogDepInt), ArithmeticOverflow>
+    {
+        if self.is_zero()
+        {
+            return Ok((self.clone(), 0));
+        }
+
+        let lambda = Zroot2(BigInt::one(), BigInt::one());
+        let lambda_inv = Zroot2(-BigInt::one(), BigInt::one());
+        let lambda_squared = lambda.clone()*lambda.clone();
+        let lambda_inv_squared = lambda_inv.clone()*lambda_inv.clone();
+
+        let x = self.to_big();
+        let abs_norm = Zroot2(x.norm().abs(), BigInt::zero());
+        let upper = lambda.clone()*abs_norm.clone();
+        let lower = lambda_inv.clone()*abs_norm;
+
+        let mut square = x.clone()*x.clone();
+        let mut m: LogDepInt = 0;
+        while square >= upper
+        {
+            square = square*lambda_inv_squared.clone();
+            m = m - 1;
+        }
+        while square < lower
+        {
+            square = square*lambda_squared.clone();
+            m = m + 1;
+        }
+
+        let unit = Zroot2::<BigInt>::lambda_pow(m)?;
+        let reduced = (x*unit).to_backend().ok_or(ArithmeticOverflow)?;
+        return Ok((reduced, m));
+    }
+}
+
+// For a unit u = λ^k >= 1, finds k
+// Finds the powers λ^(2^j) up to u, and then the binary digits of k
+fn positive_unit_exponent(u: Zroot2<BigInt>) -> LogDepInt
+{
+    let mut powers = vec![Zroot2(BigInt::one(), BigInt::one())];
+    while powers.last().unwrap() <= &u
+    {
+        let last = powers.last().unwrap().clone();
+        powers.push(last.clone()*last);
+    }
+
+    let mut k: LogDepInt = 0;
+    let mut rest = u;
+    for j in (0..powers.len()).rev()
+    {
+        if powers[j] <= rest
         {
-            return false;
+            rest = rest*powers[j].unit_inverse().unwrap();
+            k = k + (1 << j);
         }
     }
+    return k;
 }
 
-impl From<Int> for Zroot2 {
+impl<I: IntBackend> From<Int> for Zroot2<I> {
     fn from(int: Int) -> Self {
-        Zroot2(int.try_into().unwrap(),0)
+        Zroot2(I::from_int(int),I::zero())
+    }
+}
+
+
+// Moving between integer backends
+// Returns None if the coefficients do not fit in J
+impl<I: IntBackend> Zroot2<I>
+{
+    pub fn to_backend<J: IntBackend>(&self) -> Option<Zroot2<J>>
+    {
+        return Some(Zroot2(
+                J::from_bigint(&self.0.to_bigint())?,
+                J::from_bigint(&self.1.to_bigint())?
+                ));
     }
 }
 
 
 
 
-impl Zero for Zroot2
+impl<I: IntBackend> Zero for Zroot2<I>
 {
     fn zero() -> Self
     {
-        return Zroot2(0,0);
+        return Zroot2(I::zero(),I::zero());
     }
 
     fn is_zero(&self) -> bool
     {
-        return self.0==0 && self.1==0;
+        return self.0.is_zero() && self.1.is_zero();
     }
 
 }
 
-impl One for Zroot2
+impl<I: IntBackend> One for Zroot2<I>
 {
     fn one() -> Self
     {
-        return Zroot2(1,0);
+        return Zroot2(I::one(),I::zero());
     }
 }
 
 
 // Negatation on Zroot2
-impl Neg for Zroot2
+impl<I: IntBackend> Neg for Zroot2<I>
 {
-    type Output = Zroot2;
+    type Output = Zroot2<I>;
 
-    fn neg(self) -> Zroot2 {
+    fn neg(self) -> Zroot2<I> {
         Zroot2(-self.0,-self.1)
     }
 }
 
 // Teaching rust how to subtract Zroot2 elements
-impl Sub for Zroot2 {
-    type Output = Zroot2;
+impl<I: IntBackend> Sub for Zroot2<I> {
+    type Output = Zroot2<I>;
 
-    fn sub(self, other: Zroot2) -> Zroot2 {
+    fn sub(self, other: Zroot2<I>) -> Zroot2<I> {
         self+(-other) //subtraction is just adding the additive inverse
     }
 }
@@ -244,39 +647,104 @@ impl Sub for Zroot2 {
 
 
 // Teaching rust how to multiply Zroot2 elements
-impl Mul for Zroot2 {
-    type Output = Zroot2;
+impl<I: IntBackend> Mul for Zroot2<I> {
+    type Output = Zroot2<I>;
 
-    fn mul(self, other: Zroot2) -> Zroot2 {
+    fn mul(self, other: Zroot2<I>) -> Zroot2<I> {
         // Naive multiplication, for now
+        let b_times_d = other.1.clone()*self.1.clone();
         Zroot2(
-            other.0*self.0 + 2*other.1*self.1,
+            other.0.clone()*self.0.clone() + b_times_d.clone() + b_times_d,
             other.0*self.1 + other.1*self.0 
             )
     }
 }
 
+// Powers by square-and-multiply
+impl<I: IntBackend> Pow<u32> for Zroot2<I>
+{
+    type Output = Self;
+    fn pow(self, p: u32) -> Self
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers only exist for units, like λ = 1+√2 and its powers
+impl<I: IntBackend> Pow<i32> for Zroot2<I>
+{
+    type Output = Self;
+    fn pow(self, p: i32) -> Self
+    {
+        return signed_pow(self, p, Self::one(), |x| x.unit_inverse().expect("Negative power of a non-unit"));
+    }
+}
+
 // Conjugate Zroot2 elements
-impl Conj for Zroot2 {
+impl<I: IntBackend> Conj for Zroot2<I> {
     fn conj(&self) -> Self 
     {
-        Zroot2(self.0,-self.1)
+        Zroot2(self.0.clone(),-self.1.clone())
+    }
+}
+
+// σ_k(√2) = ω^k + ω^(-k), which is √2 for k = 1,7 and -√2 for k = 3,5
+// So the conjugation above is the only non-trivial element in the Galois group
+impl<I: IntBackend> GaloisAutomorphism for Zroot2<I>
+{
+    type Embedding = Float;
+
+    fn galois(&self, k: u8) -> Self
+    {
+        let k = galois_exponent(k);
+        if k == 3 || k == 5 { self.conj() } else { self.clone() }
+    }
+
+    // When a and b have opposite signs, a+b√2 loses digits to cancellation
+    // but a-b√2 does not. Then we use a+b√2 = N/(a-b√2) with the exact norm N
+    fn embeddings(&self) -> (Float, Float)
+    {
+        if self.is_zero()
+        {
+            return (0.0, 0.0);
+        }
+        let (a, b) = (self.0.to_float(), self.1.to_float());
+        let norm = match self.checked_norm()
+        {
+            Ok(norm) => norm.to_float(),
+            Err(_) => self.to_big().norm().to_float(),
+        };
+        if (self.0.signum()*self.1.signum()).is_negative()
+        {
+            let minus = a - SQRT2*b;
+            return (norm/minus, minus);
+        }
+        let plus = a + SQRT2*b;
+        return (plus, norm/plus);
+    }
+}
+
+// The conjugate of √2 is -√2
+impl<I: IntBackend> LocalizableConj for Zroot2<I> {
+    fn multiply_by_generator_ratio(self, n: LogDepInt) -> Self
+    {
+        if n.rem_euclid(2) == 1 { -self } else { self }
     }
 }
 
 
-impl Rem for Zroot2 
+impl<I: IntBackend> Rem for Zroot2<I> 
 {
     type Output = Self;
     fn rem(self, other: Self) -> Self
     {
-        let q = self/other;
+        let q = self.clone()/other.clone();
         return self-q*other;
     }
 
 }
 
-impl Div for Zroot2 
+impl<I: IntBackend> Div for Zroot2<I> 
 {
     type Output = Self;
 
@@ -336,9 +804,9 @@ impl Div for Zroot2
 
         // Zroot2 is norm-Euclidean
         // Therefore a division algorithm is very much possible for Zroot2
-        let mut nor = other.norm();
+        let nor = other.norm();
         
-        if nor!=0
+        if !nor.is_zero()
         {
             // WARNING: This is bad mathematics 
             //          because when self/other is not exactly in Zroot2
@@ -352,9 +820,8 @@ impl Div for Zroot2
 
             // A previous version used self*other.conj()
 
-            let numerator = self*other.conj();
-            let denominator = nor;
-            return Zroot2( nearest_integer(numerator.0,nor),  nearest_integer(numerator.1, nor) );
+            let numerator = self*Conj::conj(&other);
+            return Zroot2( nearest_integer(numerator.0,nor.clone()),  nearest_integer(numerator.1, nor) );
 
         }
         else 
@@ -368,7 +835,7 @@ impl Div for Zroot2
 
 
 
-impl Num for Zroot2
+impl<I: IntBackend> Num for Zroot2<I>
 {
     // Some rust requirements to make a Complex compatible local
     type FromStrRadixErr = std::num::ParseIntError  ;
@@ -382,7 +849,7 @@ impl Num for Zroot2
     }
 }
 
-impl ToPrimitive for Zroot2
+impl<I: IntBackend> ToPrimitive for Zroot2<I>
 {
     // Rust needs this. 
     // The NumCast library does not work otherwise
@@ -399,15 +866,15 @@ impl ToPrimitive for Zroot2
 
 }
 
-impl NumCast for Zroot2
+impl<I: IntBackend> NumCast for Zroot2<I>
 {
     fn from<T>(given: T) -> Option<Self>
         where T: ToPrimitive
         {
             return Some(Self
                         {
-                            0: given.to_i128().unwrap(),
-                            1: 0
+                            0: I::from_int(given.to_i128().unwrap()),
+                            1: I::zero()
                         });
         }
 }
@@ -0,0 +1,511 @@
+// The sixteenth cyclotomic field Q(ζ), with ζ^8 = -1,
+// and its ring of integers Z[ζ]
+// This is where the Clifford+√T gates live, like Z[ω] is where the Clifford+T gates live
+//
+// Since ζ^2 = ω, every element is a + ζb for a and b in Z[ω]
+// so the arithmetic here is done with Zomega coefficients
+
+
+use crate::structs::rings::Conj;
+use crate::structs::rings::LocalizableConj;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::Localizable;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::LocalizableNorm;
+use crate::structs::rings::CheckedRing;
+use crate::structs::rings::CheckedNorm;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::structs::rings::galois_exponent;
+use crate::structs::rings::Float;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::rings::local_ring::Local;
+
+use num_complex::Complex;
+
+use num_traits::Num;
+use num_traits::Zero;
+use num_traits::One;
+
+// We bring them in so that we can overload the operators
+// Rust must learn how to do arithmetics in our rings
+use std::ops::Neg;
+use std::ops::Add;
+use std::ops::Sub;
+use std::ops::Mul;
+use num_traits::Pow;
+use std::ops::Div;
+use std::ops::Rem;
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+
+// Ring of numbers of the form a + ζb where a, b are in Zomega
+// and ζ is the sixteenth root of unity e^(iπ/8)
+// In the power basis, the coefficient of ζ^(2j) is a.j and the
+// coefficient of ζ^(2j+1) is b.j
+#[derive(Debug,Copy, Clone)]
+pub struct Zzeta16<I = Int>(pub Zomega<I>, pub Zomega<I>);
+
+
+impl<I: IntBackend> Zzeta16<I>
+{
+    pub fn from_zomega(input: Zomega<I>) -> Self
+    {
+        return Zzeta16(input, Zomega::zero());
+    }
+
+    // The coefficients of 1, ζ, ζ^2, ..., ζ^7
+    pub fn coefficients(&self) -> [I; 8]
+    {
+        let (a, b) = (self.0.clone(), self.1.clone());
+        return [a.0, b.0, a.1, b.1, a.2, b.2, a.3, b.3];
+    }
+
+    pub fn from_coefficients(input: [I; 8]) -> Self
+    {
+        let [x0, x1, x2, x3, x4, x5, x6, x7] = input;
+        return Zzeta16(Zomega(x0, x2, x4, x6), Zomega(x1, x3, x5, x7));
+    }
+
+    // ζ(a + ζb) = ωb + ζa
+    pub fn multiply_by_zeta(self, n: LogDepInt) -> Self
+    {
+        let mut out = self;
+        for _ in 0..n.rem_euclid(16)
+        {
+            out = Zzeta16(out.1.multiply_by_generator_ratio(1), out.0);
+        }
+        return out;
+    }
+
+    // σ_9 sends ζ to -ζ and fixes ω
+    // It is the only automorphism of Q(ζ) that fixes Q(ω)
+    pub fn conj_zeta(&self) -> Self
+    {
+        return Zzeta16(self.0.clone(), -self.1.clone());
+    }
+
+    // The norm down to Z[ω]
+    // (a + ζb)(a - ζb) = a^2 - ωb^2
+    pub fn relative_norm(&self) -> Zomega<I>
+    {
+        let (a, b) = (self.0.clone(), self.1.clone());
+        return a.clone()*a - (b.clone()*b).multiply_by_generator_ratio(1);
+    }
+
+    // The norm down to Z, which is the Zomega norm of the relative norm
+    pub fn norm(&self) -> I
+    {
+        return self.relative_norm().norm();
+    }
+
+    // Units are the elements whose relative norm is a unit of Z[ω]
+    pub fn is_unit(&self) -> bool
+    {
+        return self.relative_norm().is_unit();
+    }
+
+    // 1/x = σ_9(x)/(x*σ_9(x)), and the denominator is a unit of Z[ω]
+    pub fn unit_inverse(&self) -> Option<Self>
+    {
+        let inverse_norm = self.relative_norm().unit_inverse()?;
+        let numerator = self.conj_zeta();
+        return Some(Zzeta16(numerator.0*inverse_norm.clone(), numerator.1*inverse_norm));
+    }
+
+    // Moving between integer backends
+    // Returns None if the coefficients do not fit in J
+    pub fn to_backend<J: IntBackend>(&self) -> Option<Zzeta16<J>>
+    {
+        return Some(Zzeta16(self.0.to_backend()?, self.1.to_backend()?));
+    }
+
+    // Every σ_k for k = 1,3,5,7 as a float
+    // The other four embeddings are their complex conjugates
+    pub fn all_embeddings(&self) -> [Complex<Float>; 4]
+    {
+        return [1, 3, 5, 7].map(|k| self.galois(k).embeddings().0);
+    }
+}
+
+
+// Complex conjugation sends ζ to ζ^(-1) = -ζω^3
+impl<I: IntBackend> Conj for Zzeta16<I> {
+    fn conj(&self) -> Zzeta16<I> {
+        return Zzeta16(self.0.conj(), -self.1.conj().multiply_by_generator_ratio(3));
+    }
+}
+
+// σ_k sends ζ to ζ^k = ζω^((k-1)/2) and restricts to σ_k on Z[ω]
+// Here k is read mod 16, so σ_15 is complex conjugation while σ_7 is not
+impl<I: IntBackend> GaloisAutomorphism for Zzeta16<I>
+{
+    type Embedding = Complex<Float>;
+
+    fn galois(&self, k: u8) -> Self
+    {
+        // Panics for even k
+        galois_exponent(k);
+        let omega_power = ((k%16 - 1)/2) as LogDepInt;
+        return Zzeta16(self.0.galois(k), self.1.galois(k).multiply_by_generator_ratio(omega_power));
+    }
+
+    // σ_1 and σ_5, each with the matching embedding of the Zomega parts
+    fn embeddings(&self) -> (Complex<Float>, Complex<Float>)
+    {
+        let (a, a_bullet) = self.0.embeddings();
+        let (b, b_bullet) = self.1.embeddings();
+        let zeta = Complex::from_polar(1.0, std::f64::consts::PI/8.0);
+        let norm = self.checked_relative_norm().ok().map(|n| n.embeddings());
+        return (
+            without_cancellation(a, zeta*b, norm.map(|n| n.0)),
+            without_cancellation(a_bullet, zeta.powu(5)*b_bullet, norm.map(|n| n.1))
+            );
+    }
+}
+
+// a + ζb loses digits when the two terms nearly cancel, but then a - ζb does not
+// and a + ζb = (a^2 - ωb^2)/(a - ζb), where the numerator is the exact relative norm
+fn without_cancellation(a: Complex<Float>, zeta_b: Complex<Float>, relative_norm: Option<Complex<Float>>) -> Complex<Float>
+{
+    let (plus, minus) = (a + zeta_b, a - zeta_b);
+    return match relative_norm
+    {
+        Some(n) if minus.norm() > plus.norm() => n/minus,
+        _ => plus,
+    };
+}
+
+// Nicely display Zzeta16 elements
+impl<I: IntBackend> Display for Zzeta16<I>
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        let x = self.coefficients();
+        write!(f,"{}+{}z+{}z^2+{}z^3+{}z^4+{}z^5+{}z^6+{}z^7",x[0],x[1],x[2],x[3],x[4],x[5],x[6],x[7])
+    }
+}
+
+// Negatation on Zzeta16
+impl<I: IntBackend> Neg for Zzeta16<I>{
+    type Output = Zzeta16<I>;
+
+    fn neg(self) -> Zzeta16<I> {
+        Zzeta16(-self.0, -self.1)
+    }
+}
+
+// Teaching rust how to add Zzeta16 elements
+impl<I: IntBackend> Add for Zzeta16<I> {
+    type Output = Zzeta16<I>;
+
+    fn add(self, other: Zzeta16<I>) -> Zzeta16<I> {
+        Zzeta16(self.0+other.0, self.1+other.1)
+    }
+}
+
+// Teaching rust how to subtract Zzeta16 elements
+impl<I: IntBackend> Sub for Zzeta16<I> {
+    type Output = Zzeta16<I>;
+
+    fn sub(self, other: Zzeta16<I>) -> Zzeta16<I> {
+        self+(-other)
+    }
+}
+
+// Teaching rust how to multiply Zzeta16 elements
+// (a + ζb)(c + ζd) = (ac + ωbd) + ζ(ad + bc)
+impl<I: IntBackend> Mul for Zzeta16<I> {
+    type Output = Zzeta16<I>;
+
+    fn mul(self, other: Zzeta16<I>) -> Zzeta16<I> {
+        let (a, b) = (self.0, self.1);
+        let (c, d) = (other.0, other.1);
+        Zzeta16(
+            a.clone()*c.clone() + (b.clone()*d.clone()).multiply_by_generator_ratio(1),
+            a*d + b*c
+            )
+    }
+}
+
+// Powers by square-and-multiply
+impl<I: IntBackend> Pow<u32> for Zzeta16<I>
+{
+    type Output = Self;
+    fn pow(self, p: u32) -> Self
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers only exist for units, like ζ and 1+√2
+impl<I: IntBackend> Pow<i32> for Zzeta16<I>
+{
+    type Output = Self;
+    fn pow(self, p: i32) -> Self
+    {
+        return signed_pow(self, p, Self::one(), |x| x.unit_inverse().expect("Negative power of a non-unit"));
+    }
+}
+
+// Localizing at the prime δ = 1+ζ, which lies over 2
+// Since ζ = -1 mod δ, the residue of x is the alternating sum of its
+// coefficients mod 2, which is the same as the plain sum mod 2
+//
+// Writing x = δy and comparing coefficients gives
+//      x_0 = y_0 - y_7   and   x_j = y_j + y_(j-1)   for j = 1, ..., 7
+// so the alternating sum of the x_j is -2y_7, and the rest follows
+impl<I: IntBackend> Localizable for Zzeta16<I>
+{
+    fn is_divisible(&self) -> bool
+    {
+        let sum = self.coefficients().into_iter().fold(I::zero(), |acc, x| acc + x);
+        return sum.trailing_zeros() > 0;
+    }
+
+    fn reduce_by_dividing(mut self) -> ( Self, LogDepInt )
+    {
+        let mut pow: LogDepInt = 0;
+        while !self.is_zero() && self.is_divisible()
+        {
+            let x = self.coefficients();
+            let mut alternating_sum = I::zero();
+            for (j, coefficient) in x.iter().enumerate()
+            {
+                alternating_sum = if j%2 == 0 { alternating_sum + coefficient.clone() } else { alternating_sum - coefficient.clone() };
+            }
+
+            let mut y = [I::zero(), I::zero(), I::zero(), I::zero(), I::zero(), I::zero(), I::zero(), I::zero()];
+            y[7] = -(alternating_sum >> 1);
+            y[0] = x[0].clone() + y[7].clone();
+            for j in 1..7
+            {
+                y[j] = x[j].clone() - y[j-1].clone();
+            }
+            self = Zzeta16::from_coefficients(y);
+            pow = pow + 1;
+        }
+        return (self, pow);
+    }
+
+    // (a + ζb)(1 + ζ) = (a + ωb) + ζ(a + b)
+    fn perform_n_multiplications(mut self, n: LogDepInt) -> Self
+    {
+        for _ in 0..n
+        {
+            let (a, b) = (self.0, self.1);
+            self = Zzeta16(a.clone() + b.clone().multiply_by_generator_ratio(1), a + b);
+        }
+        return self;
+    }
+
+    fn checked_perform_n_multiplications(mut self, n: LogDepInt) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        for _ in 0..n
+        {
+            let (a, b) = (&self.0, &self.1);
+            self = Zzeta16(a.checked_add(&b.clone().multiply_by_generator_ratio(1))?, a.checked_add(b)?);
+        }
+        return Ok(self);
+    }
+
+    const GENERATOR: &'static str = "(1+ζ)";
+}
+
+// The complex conjugate of 1+ζ is 1+ζ^(-1) = (1+ζ)/ζ
+impl<I: IntBackend> LocalizableConj for Zzeta16<I>
+{
+    fn multiply_by_generator_ratio(self, n: LogDepInt) -> Self
+    {
+        return self.multiply_by_zeta(n);
+    }
+}
+
+// Teaching rust how to compare these ring elements
+impl<I: IntBackend> PartialEq for Zzeta16<I>
+{
+    fn eq(&self, other: &Self) -> bool {
+        return self.0==other.0 && self.1==other.1;
+    }
+}
+
+impl<I: IntBackend> Zero for Zzeta16<I>
+{
+    fn zero() -> Self
+    {
+        return Zzeta16(Zomega::zero(), Zomega::zero());
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        return self.0.is_zero() && self.1.is_zero();
+    }
+}
+
+impl<I: IntBackend> One for Zzeta16<I>
+{
+    fn one() -> Self
+    {
+        return Zzeta16(Zomega::one(), Zomega::zero());
+    }
+}
+
+impl<I: IntBackend> Num for Zzeta16<I>
+{
+    // Some rust requirements to make a Local<Zzeta16> ring
+    type FromStrRadixErr = std::num::ParseIntError  ;
+    fn from_str_radix(_: &str, _: u32) -> core::result::Result<Self, Self::FromStrRadixErr>
+    {
+        panic!("Unimplimented or impossible");
+    }
+}
+
+impl<I: IntBackend> LocalizableNorm for Zzeta16<I>
+{
+    type Integer = I;
+
+    fn norm(&self) -> I
+    {
+        return Zzeta16::norm(self);
+    }
+}
+
+// Same formula as norm, but stops at the first overflow
+impl<I: IntBackend> CheckedNorm for Zzeta16<I>
+{
+    fn checked_norm(&self) -> core::result::Result<I, ArithmeticOverflow>
+    {
+        return self.checked_relative_norm()?.checked_norm();
+    }
+}
+
+// Same as +, - and * but never wraps around
+impl<I: IntBackend> CheckedRing for Zzeta16<I>
+{
+    fn checked_add(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Zzeta16(self.0.checked_add(&other.0)?, self.1.checked_add(&other.1)?));
+    }
+
+    fn checked_sub(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        return Ok(Zzeta16(self.0.checked_sub(&other.0)?, self.1.checked_sub(&other.1)?));
+    }
+
+    fn checked_mul(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        // Same formula as in Mul
+        let (a, b) = (&self.0, &self.1);
+        let (c, d) = (&other.0, &other.1);
+        let bd = b.checked_mul(d)?.multiply_by_generator_ratio(1);
+        return Ok(Zzeta16(
+            a.checked_mul(c)?.checked_add(&bd)?,
+            a.checked_mul(d)?.checked_add(&b.checked_mul(c)?)?
+            ));
+    }
+}
+
+
+// x/y = x*σ_9(y)/(y*σ_9(y)), and the denominator is in Z[ω]
+// so both Zomega parts are divided with Zomega division
+//
+// When y divides x this is exact, which is all that Local<Zzeta16> needs
+// Otherwise both parts get rounded, and this is not a Euclidean division
+//
+// As in Zomega, only the intermediate products can get too big for I
+// and then they are done over BigInt
+impl<I: IntBackend> Div for Zzeta16<I>
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self
+    {
+        return match self.checked_quotient(&other)
+        {
+            Ok(quotient) => quotient,
+            Err(ArithmeticOverflow) =>
+            {
+                let self_big = self.to_backend::<BigInt>().unwrap();
+                let other_big = other.to_backend::<BigInt>().unwrap();
+                let quotient_big = self_big.checked_quotient(&other_big).unwrap();
+                quotient_big.to_backend::<I>().expect("Quotient does not fit in the integer backend")
+            }
+        };
+    }
+}
+
+impl<I: IntBackend> Zzeta16<I>
+{
+    fn checked_relative_norm(&self) -> core::result::Result<Zomega<I>, ArithmeticOverflow>
+    {
+        let (a, b) = (&self.0, &self.1);
+        let b_squared = b.checked_mul(b)?.multiply_by_generator_ratio(1);
+        return a.checked_mul(a)?.checked_sub(&b_squared);
+    }
+
+    fn checked_quotient(&self, other: &Self) -> core::result::Result<Self, ArithmeticOverflow>
+    {
+        let denominator = other.checked_relative_norm()?;
+        let numerator = self.checked_mul(&other.conj_zeta())?;
+        return Ok(Zzeta16(numerator.0/denominator.clone(), numerator.1/denominator));
+    }
+}
+
+impl<I: IntBackend> Rem for Zzeta16<I>
+{
+    type Output = Self;
+    fn rem(self, other: Self) -> Self
+    {
+        let quotient = self.clone()/other.clone();
+        return self - quotient*other;
+    }
+}
+
+
+// Z[ζ] localized at δ = 1+ζ
+// Like D[ω] = Local<Zomega>, every element is x/δ^k for a single k
+type DLoc<I> = Local::<Zomega<I>>;
+type ZetaLoc<I> = Local::<Zzeta16<I>>;
+
+impl<I: IntBackend> Local<Zzeta16<I>>
+{
+    // The smallest denominator exponent, in powers of δ
+    // Since √2 = δ^4 times a unit, this is four times the sde in powers of √2
+    pub fn sde(&self) -> LogDepInt
+    {
+        return self.log_den;
+    }
+}
+
+// 1+ω = δ^2 times a unit, so x/(1+ω)^k = x*v^k/δ^(2k) where v = δ^2/(1+ω)
+impl<I: IntBackend> From<DLoc<I>> for ZetaLoc<I>
+{
+    fn from(input: DLoc<I>) -> Self
+    {
+        let delta_omega = Zzeta16::from_zomega(Zomega(I::one(), I::one(), I::zero(), I::zero()));
+        let delta = Zzeta16::one().perform_n_multiplications(1);
+
+        let mut num = Zzeta16::from_zomega(input.num);
+        if input.log_den < 0
+        {
+            num = num*delta_omega.pow((-input.log_den) as u32);
+        }
+        else
+        {
+            num = num*(delta.clone()*delta/delta_omega).pow(input.log_den as u32);
+        }
+        let den = Local
+        {
+            num: Zzeta16::one(),
+            log_den: 2*input.log_den.max(0)
+        };
+        return Local::from_base(num)*den;
+    }
+}
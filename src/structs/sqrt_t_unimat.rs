@@ -0,0 +1,223 @@
+// Unitary matrices from the Clifford+√T gate set
+//
+// Same shape as ExactUniMat in unimat.rs:
+// /                  \
+// | u  -t^* * zeta^k  |
+// | t   u^* * zeta^k  |
+// \                  /
+// but now u and t are in Z[ζ] localized at 1+ζ, where ζ is the sixteenth
+// root of unity, and the phase is saved as k mod 16
+//
+// The gates are H, T = diag(1,ω) and √T = diag(1,ζ). In gate strings, √T is Q
+
+
+use std::ops::Mul;
+use std::cmp::PartialEq;
+use num_traits::Pow;
+use num_traits::One;
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::structs::sunimat::SUniMat;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zzeta16::Zzeta16;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::Conj;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
+use crate::structs::rings::special_values::sqrt2_zomega;
+
+type ZetaLoc<I = Int> = Local<Zzeta16<I>>;
+
+// The special unitary part
+pub type ZetaMat<I = Int> = SUniMat<ZetaLoc<I>>;
+
+type ExpInt = u8;
+
+#[derive(Debug,Copy,Clone)]
+pub struct SqrtTUniMat<I = Int>
+{
+    pub mat: ZetaMat<I>,
+    pub zeta_exp: ExpInt            // This is a number mod 16
+}
+
+
+// Auxilary functions, as for ExactUniMat
+impl<I: IntBackend> SUniMat<ZetaLoc<I>>
+{
+    // Multiplies t by ζ^no_of_turns
+    pub fn twist(self, no_of_turns : ExpInt) -> Self
+    {
+        Self{
+            u : self.u,
+            t : Local
+            {
+                num: self.t.num.multiply_by_zeta(no_of_turns as LogDepInt),
+                log_den: self.t.log_den
+            }
+        }
+    }
+
+    // Both entries of a unitary share the same sde
+    // This counts powers of 1+ζ, so H can change it by up to 4
+    pub fn sde(&self) -> LogDepInt
+    {
+        return self.u.sde().max(self.t.sde());
+    }
+}
+
+
+// Nicely display Unitary Matrices
+impl<I: IntBackend> Display for SqrtTUniMat<I>
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        let phase = ZetaLoc::from_base(Zzeta16::one().multiply_by_zeta(self.zeta_exp as LogDepInt));
+        write!(f,"|\t{}\t{}\t|\n|\t{}\t{}\t|\n",self.mat.u, -self.mat.t.conj()*phase.clone() , self.mat.t,self.mat.u.conj()*phase)
+    }
+}
+
+
+impl<I: IntBackend> SqrtTUniMat<I>
+{
+    pub fn one() -> Self
+    {
+        return Self
+        {
+            mat: ZetaMat::one(),
+            zeta_exp: 0
+        };
+    }
+
+    // H = [[1,1],[1,-1]]/√2, and -1 = ζ^8
+    pub fn h_gate() -> Self
+    {
+        let sqrt2 = ZetaLoc::from_base(Zzeta16::from_zomega(sqrt2_zomega()));
+        let onebyroot2 = ZetaLoc::one()/sqrt2;
+        return Self
+        {
+            mat: ZetaMat{ u: onebyroot2.clone(), t: onebyroot2 },
+            zeta_exp: 8
+        };
+    }
+
+    pub fn t_gate() -> Self
+    {
+        return Self
+        {
+            mat: ZetaMat::one(),
+            zeta_exp: 2
+        };
+    }
+
+    pub fn sqrt_t_gate() -> Self
+    {
+        return Self
+        {
+            mat: ZetaMat::one(),
+            zeta_exp: 1
+        };
+    }
+
+    // Gates are applied right to left, as in ExactUniMat::from_string
+    // Every other Clifford+T letter is read by ExactUniMat::from_string
+    pub fn from_string(gate_string : &String) -> Self
+    {
+        let mut output = Self::one();
+        for i in gate_string.chars().rev()
+        {
+            output = match i
+            {
+                'H' => Self::h_gate()*output,
+                'T' => Self::t_gate()*output,
+                'Q' => Self::sqrt_t_gate()*output,
+                'I' => output,
+                _ => Self::from(ExactUniMat::<I>::from_string(&i.to_string()))*output,
+            };
+        }
+        return output;
+    }
+
+    // Conjugate-transpose, same as taking an inverse
+    pub fn inv(self) -> Self
+    {
+        return Self
+        {
+            mat: self.mat.inv().twist((16 - self.zeta_exp)%16),
+            zeta_exp: (16 - self.zeta_exp)%16,
+        };
+    }
+
+    pub fn det(self) -> ZetaLoc<I>
+    {
+        let phase = ZetaLoc::from_base(Zzeta16::one().multiply_by_zeta(self.zeta_exp as LogDepInt));
+        return self.mat.det()*phase;
+    }
+
+    pub fn sde(&self) -> LogDepInt
+    {
+        return self.mat.sde();
+    }
+}
+
+// Every Clifford+T gate is a Clifford+√T gate, with twice the phase exponent
+impl<I: IntBackend> From<ExactUniMat<I>> for SqrtTUniMat<I>
+{
+    fn from(input: ExactUniMat<I>) -> Self
+    {
+        let mat = input.to_dyadic();
+        return Self
+        {
+            mat: ZetaMat{ u: mat.u.into(), t: mat.t.into() },
+            zeta_exp: (2*input.omega_exp)%16
+        };
+    }
+}
+
+
+// Teaching rust how to multiply these matrices
+// See ExactUniMat for why the twist is there
+impl<I: IntBackend> Mul for SqrtTUniMat<I>
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self
+    {
+        Self{
+            mat: self.mat*other.mat.twist(self.zeta_exp),
+            zeta_exp: (self.zeta_exp+other.zeta_exp)%16,
+        }
+    }
+}
+
+// Powers by square-and-multiply
+impl<I: IntBackend> Pow<u32> for SqrtTUniMat<I>
+{
+    type Output = Self;
+    fn pow(self, p: u32) -> Self
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers are powers of the inverse
+impl<I: IntBackend> Pow<i32> for SqrtTUniMat<I>
+{
+    type Output = Self;
+    fn pow(self, p: i32) -> Self
+    {
+        return signed_pow(self, p, Self::one(), |x| x.inv());
+    }
+}
+
+// Teaching rust how to compare these matrices
+impl<I: IntBackend> PartialEq for SqrtTUniMat<I>
+{
+    fn eq(&self, other: &Self) -> bool {
+        return self.mat == other.mat && self.zeta_exp%16 == other.zeta_exp%16;
+    }
+}
@@ -6,7 +6,10 @@
 
 
 use crate::structs::rings::Conj; //Conjugation trait
+use crate::structs::rings::GaloisAutomorphism;
 use crate::structs::rings::Int; //Integer standard
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
 // use crate::structs::rings::Constructs; //Construction trait
 
 // We bring them in so that we can overload the operators
@@ -29,6 +32,7 @@ use num_traits::Zero;
 use num_traits::One;
 use num_traits::NumCast;
 use num_traits::FromPrimitive;
+use num_traits::Pow;
 
 
 
@@ -55,7 +59,7 @@ pub struct SUniMat<T>
 
 // Nicely display Unitary Matrices
 impl<T> Display for SUniMat<T>
-where T:Neg<Output=T>+Conj+Display+Copy
+where T:Neg<Output=T>+Conj+Display+Clone
 {
     fn fmt(&self, f: &mut Formatter) -> Result{
         // write!(f,"/       \\");
@@ -83,7 +87,7 @@ where T: Neg<Output=T>+Conj
 // Also see this for why it looks so weird:
 // https://stackoverflow.com/questions/39169795/error-when-using-operators-with-a-generic-type
 impl<T> Mul for SUniMat<T> 
-where T: Copy,
+where T: Clone,
       T: Conj,
       T: Mul<Output=T>,
       T: Add<Output=T>,
@@ -94,18 +98,64 @@ where T: Copy,
         -> SUniMat<T>
     {
         Self{
-            u: self.u*other.u-self.t.conj()*other.t,
+            u: self.u.clone()*other.u.clone()-self.t.conj()*other.t.clone(),
             t: self.t*other.u+self.u.conj()*other.t
         }
     }
 }
 
 
+// Powers by square-and-multiply
+impl<T> Pow<u32> for SUniMat<T> 
+where T: Clone+Conj+Zero+One,
+      T: Mul<Output=T>+Add<Output=T>+Sub<Output=T>
+{
+    type Output = SUniMat<T>;
+    fn pow(self, p: u32) -> SUniMat<T>
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers are powers of the inverse
+// Like inv, this assumes the matrix is unitary
+impl<T> Pow<i32> for SUniMat<T> 
+where T: Clone+Conj+Zero+One+Neg<Output=T>,
+      T: Mul<Output=T>+Add<Output=T>+Sub<Output=T>
+{
+    type Output = SUniMat<T>;
+    fn pow(self, p: i32) -> SUniMat<T>
+    {
+        return signed_pow(self, p, Self::one(), |x| x.inv());
+    }
+}
+
+
+
+// Applied entrywise
+// σ commutes with complex conjugation, so the image has the same shape
+impl<T> GaloisAutomorphism for SUniMat<T> 
+where T: GaloisAutomorphism
+{
+    type Embedding = SUniMat<T::Embedding>;
+
+    fn galois(&self, k: u8) -> Self
+    {
+        return Self{ u: self.u.galois(k), t: self.t.galois(k) };
+    }
+
+    fn embeddings(&self) -> (Self::Embedding, Self::Embedding)
+    {
+        let (u, u_bullet) = self.u.embeddings();
+        let (t, t_bullet) = self.t.embeddings();
+        return (SUniMat{ u, t }, SUniMat{ u: u_bullet, t: t_bullet });
+    }
+}
 
 // Get zero and one as Unitary matrices
 impl<T> SUniMat<T>
 where T: Zero,
-      T: Copy
+      T: Clone
 {
     // WARNING: zero is possible to construct, but avoid using it
     // It is not a unitary matrix
@@ -117,7 +167,7 @@ where T: Zero,
 
 impl<T> SUniMat<T>
 where T: Zero+One,
-      T: Copy
+      T: Clone
 {
     pub fn one() -> Self {
         return Self{ u: T::one(), t: T::zero()};
@@ -126,11 +176,11 @@ where T: Zero+One,
 
 impl<T> SUniMat<T>
 where T: Mul<Output=T>+Add<Output=T>+Conj,
-      T: Copy
+      T: Clone
 {
     pub fn det(self) -> T
     {
-        return self.u*self.u.conj()+self.t*self.t.conj();
+        return self.u.clone()*self.u.conj()+self.t.clone()*self.t.conj();
     }
 }
 
@@ -25,11 +25,21 @@ use std::fmt::Formatter;
 use crate::structs::sunimat::SUniMat;
 use crate::structs::rings::local_ring::Local;
 use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::ParseRingError;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
+use crate::structs::rings::parsing;
+use std::str::FromStr;
 use crate::structs::rings::Float;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
 use num_complex::Complex;
 use crate::structs::rings::special_values::mu_8;
 use crate::structs::rings::special_values::onebyroot2comp;
-type KMMring = Complex<Local<Zroot2>>;
+use crate::structs::rings::special_values::sqrtminus1;
+type KMMring<I = Int> = Complex<Local<Zroot2<I>>>;
 
 use crate::algorithms::inexact_synth::SQRT2;
 
@@ -45,48 +55,100 @@ use crate::algorithms::inexact_synth::SQRT2;
 // that is, when the unitary is from Clifford+T gate set
 // then we know that omega is an 8th root of unity
 // so we can instead save a single u8 value mod 8
+//
+// The entries are over Int unless asked otherwise
+// ExactUniMat<BigInt> never overflows
 
 
 type ExpInt = u8;
 
 #[derive(Debug,Copy,Clone)]
-pub struct ExactUniMat
+pub struct ExactUniMat<I = Int>
 {
-    pub mat: SUniMat<KMMring>,       // This will save the u and t
+    pub mat: SUniMat<KMMring<I>>,       // This will save the u and t
     pub omega_exp: ExpInt            // This will actually just be a number mod 8
 }
 
 
 
 // Nicely display Unitary Matrices
-impl Display for ExactUniMat
+impl<I: IntBackend> Display for ExactUniMat<I>
 {
     fn fmt(&self, f: &mut Formatter) -> Result{
         // write!(f,"/       \\");
         // write!(f,"| {} {} |", self.u,-self.t.conj());
-        write!(f,"|\t{}\t{}\t|\n|\t{}\t{}\t|\n",self.mat.u, -self.mat.t.conj()*mu_8().pow(self.omega_exp) , self.mat.t,self.mat.u.conj()*mu_8().pow(self.omega_exp))
+        let phase = mu_8::<I>().pow(self.omega_exp);
+        write!(f,"|\t{}\t{}\t|\n|\t{}\t{}\t|\n",self.mat.u, -self.mat.t.conj()*phase.clone() , self.mat.t,self.mat.u.conj()*phase)
     }
 }
 
 
 // Auxilary function to help in multiplication, inverse, etc.
-impl SUniMat<KMMring>
+impl<I: IntBackend> SUniMat<KMMring<I>>
 {
-    pub fn twist(self, no_of_turns : ExpInt) -> SUniMat<KMMring> 
+    pub fn twist(self, no_of_turns : ExpInt) -> SUniMat<KMMring<I>> 
     {
         Self{
             u : self.u,
             t : self.t*mu_8().pow(no_of_turns)
         }
     }
+
+    // Moving between integer backends
+    // Returns None if some entry does not fit in J
+    pub fn to_backend<J: IntBackend>(&self) -> Option<SUniMat<KMMring<J>>>
+    {
+        return Some(SUniMat
+        {
+            u: Complex{ re: self.u.re.to_backend()?, im: self.u.im.to_backend()? },
+            t: Complex{ re: self.t.re.to_backend()?, im: self.t.im.to_backend()? },
+        });
+    }
+}
+
+
+// The same matrices with entries in D[ω] = Local<Zomega>
+// Here u and t carry one denominator each, instead of one for the real part
+// and one for the imaginary part
+// Exact synthesis does its sde reduction on these
+pub type DyadicMat<I = Int> = SUniMat<Local<Zomega<I>>>;
+
+impl<I: IntBackend> SUniMat<KMMring<I>>
+{
+    pub fn to_dyadic(&self) -> DyadicMat<I>
+    {
+        return SUniMat
+        {
+            u: self.u.clone().into(),
+            t: self.t.clone().into(),
+        };
+    }
+}
+
+impl<I: IntBackend> SUniMat<Local<Zomega<I>>>
+{
+    pub fn to_kmm(&self) -> SUniMat<KMMring<I>>
+    {
+        return SUniMat
+        {
+            u: self.u.clone().into(),
+            t: self.t.clone().into(),
+        };
+    }
+
+    // Both entries of a unitary share the same sde
+    pub fn sde(&self) -> LogDepInt
+    {
+        return self.u.sde();
+    }
 }
 
 
 // Conjugate-transpose UniMat<T> elements
 // Same as taking an inverse
-impl ExactUniMat
+impl<I: IntBackend> ExactUniMat<I>
 {
-    pub fn inv(self) -> ExactUniMat 
+    pub fn inv(self) -> ExactUniMat<I> 
     {
         return ExactUniMat
         {
@@ -95,7 +157,7 @@ impl ExactUniMat
         }
     }
 
-    pub fn det(self) -> KMMring
+    pub fn det(self) -> KMMring<I>
     {
     
         return (self.mat.det())*mu_8().pow(self.omega_exp);
@@ -107,10 +169,10 @@ impl ExactUniMat
 // Teaching rust how to multiply UniMat<T> elements
 // Also see this for why it looks so weird:
 // https://stackoverflow.com/questions/39169795/error-when-using-operators-with-a-generic-type
-impl Mul for ExactUniMat
+impl<I: IntBackend> Mul for ExactUniMat<I>
 {
-    type Output = ExactUniMat;
-    fn mul(self, other: ExactUniMat) -> ExactUniMat
+    type Output = ExactUniMat<I>;
+    fn mul(self, other: ExactUniMat<I>) -> ExactUniMat<I>
     {
         Self{
             mat: self.mat*other.mat.twist(self.omega_exp),
@@ -120,16 +182,37 @@ impl Mul for ExactUniMat
 }
 
 
+// Powers by square-and-multiply
+impl<I: IntBackend> Pow<u32> for ExactUniMat<I>
+{
+    type Output = ExactUniMat<I>;
+    fn pow(self, p: u32) -> ExactUniMat<I>
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers are powers of the inverse
+impl<I: IntBackend> Pow<i32> for ExactUniMat<I>
+{
+    type Output = ExactUniMat<I>;
+    fn pow(self, p: i32) -> ExactUniMat<I>
+    {
+        return signed_pow(self, p, Self::one(), |x| x.inv());
+    }
+}
+
+
 
 // // Get zero and one as Unitary matrices
-impl ExactUniMat
+impl<I: IntBackend> ExactUniMat<I>
 {
     // WARNING: zero is possible to construct, but avoid using it
     // It is not a unitary matrix
     pub fn zero() -> Self 
     {
         return Self{
-            mat: SUniMat::<KMMring>
+            mat: SUniMat::<KMMring<I>>
                 { u: KMMring::zero(), t: KMMring::zero()},
             omega_exp : 0
         }
@@ -140,7 +223,7 @@ impl ExactUniMat
     {
         return Self
         {
-            mat: SUniMat::<KMMring>
+            mat: SUniMat::<KMMring<I>>
                 { u: KMMring::one(), t: KMMring::zero()},
             omega_exp : 0
         }
@@ -150,7 +233,7 @@ impl ExactUniMat
     {
         return Self
         {
-            mat: SUniMat::<KMMring>
+            mat: SUniMat::<KMMring<I>>
                 { u: onebyroot2comp(), t: onebyroot2comp()},
             omega_exp : 4
         }
@@ -161,47 +244,97 @@ impl ExactUniMat
     {
         return Self
         {
-            mat: SUniMat::<KMMring>
+            mat: SUniMat::<KMMring<I>>
                 { u: KMMring::one(), t: KMMring::zero() },
             omega_exp : 1
         };
     }
 
-    pub fn from_string(gate_string : &String) -> Self
+    // The gates below are all diagonal or antidiagonal
+    fn monomial(u: KMMring<I>, t: KMMring<I>, omega_exp: ExpInt) -> Self
     {
+        return Self
+        {
+            mat: SUniMat::<KMMring<I>>{ u, t },
+            omega_exp
+        };
+    }
 
-        let mut output = ExactUniMat::one();
+    // S = T^2 = diag(1, i)
+    pub fn s_gate() -> Self
+    {
+        return Self::monomial(KMMring::one(), KMMring::zero(), 2);
+    }
 
+    pub fn sdg_gate() -> Self
+    {
+        return Self::monomial(KMMring::one(), KMMring::zero(), 6);
+    }
 
+    pub fn tdg_gate() -> Self
+    {
+        return Self::monomial(KMMring::one(), KMMring::zero(), 7);
+    }
 
-        for i in gate_string.chars().rev()
-        {
-            if i == 'H'
-            {
-                output = ExactUniMat::h_gate()*output;
-            }
+    // Z = T^4 = diag(1, -1)
+    pub fn z_gate() -> Self
+    {
+        return Self::monomial(KMMring::one(), KMMring::zero(), 4);
+    }
 
-            else if i == 'T'
-            {
-                output = ExactUniMat::t_gate()*output;
-            }
+    // X = [[0,1],[1,0]], so -t^* omega^4 = 1
+    pub fn x_gate() -> Self
+    {
+        return Self::monomial(KMMring::zero(), KMMring::one(), 4);
+    }
 
-            else if i == 'I'
-            {
-                output = output;
-            }
-            else 
-            {
-                panic!("Gates other than H or T in the sequence");
-            }
+    // Y = [[0,-i],[i,0]]
+    pub fn y_gate() -> Self
+    {
+        return Self::monomial(KMMring::zero(), sqrtminus1(), 4);
+    }
+
+    // The global phase omega times the identity
+    // u^* omega^2 = omega, as it should
+    pub fn omega_gate() -> Self
+    {
+        return Self::monomial(mu_8(), KMMring::zero(), 2);
+    }
+
+    // Gates are applied right to left
+    // The letters are
+    //      H, T, S, X, Y, Z    the usual gates
+    //      t, s                T† and S†
+    //      W                   the global phase omega
+    //      I                   nothing
+    pub fn from_string(gate_string : &String) -> Self
+    {
+
+        let mut output = ExactUniMat::one();
 
+        for i in gate_string.chars().rev()
+        {
+            output = match i
+            {
+                'H' => ExactUniMat::h_gate()*output,
+                'T' => ExactUniMat::t_gate()*output,
+                't' => ExactUniMat::tdg_gate()*output,
+                'S' => ExactUniMat::s_gate()*output,
+                's' => ExactUniMat::sdg_gate()*output,
+                'X' => ExactUniMat::x_gate()*output,
+                'Y' => ExactUniMat::y_gate()*output,
+                'Z' => ExactUniMat::z_gate()*output,
+                'W' => ExactUniMat::omega_gate()*output,
+                'I' => output,
+                _ => panic!("Gates other than H, T, t, S, s, X, Y, Z or W in the sequence"),
+            };
         }
 
         return output;
     }
 
 
-    pub fn from_sunimat(input: SUniMat::<KMMring> ) -> Self
+    pub fn from_sunimat(input: SUniMat::<KMMring<I>> ) -> Self
     {
         return Self
         {
@@ -210,34 +343,142 @@ impl ExactUniMat
         }
     }
 
+    // Moving between integer backends
+    // Returns None if some entry does not fit in J
+    pub fn to_backend<J: IntBackend>(&self) -> Option<ExactUniMat<J>>
+    {
+        return Some(ExactUniMat
+        {
+            mat: self.mat.to_backend()?,
+            omega_exp: self.omega_exp
+        });
+    }
+
+    // The matrix part with entries in D[ω]
+    pub fn to_dyadic(&self) -> DyadicMat<I>
+    {
+        return self.mat.to_dyadic();
+    }
+
+    pub fn from_dyadic(input: DyadicMat<I>, omega_exp: ExpInt) -> Self
+    {
+        return Self
+        {
+            mat: input.to_kmm(),
+            omega_exp
+        }
+    }
+
     pub fn to_float_gate_upto_t_count(self) -> SUniMat::<Complex::<Float>>
     {
         return SUniMat::<Complex::<Float>>
         {
             u: Complex{
-                re: (  (self.mat.u.re.num.0 as Float) + SQRT2*( self.mat.u.re.num.1 as Float) )*SQRT2.pow(-self.mat.u.re.log_den),
-                im: (  (self.mat.u.im.num.0 as Float) + SQRT2*( self.mat.u.im.num.1 as Float) )*SQRT2.pow(-self.mat.u.im.log_den),
+                re: (  self.mat.u.re.num.0.to_float() + SQRT2*self.mat.u.re.num.1.to_float() )*SQRT2.pow(-self.mat.u.re.log_den),
+                im: (  self.mat.u.im.num.0.to_float() + SQRT2*self.mat.u.im.num.1.to_float() )*SQRT2.pow(-self.mat.u.im.log_den),
             },
             t: Complex{
-                re: (  (self.mat.t.re.num.0 as Float) + SQRT2*( self.mat.t.re.num.1 as Float) )*SQRT2.pow(-self.mat.t.re.log_den),
-                im: (  (self.mat.t.im.num.0 as Float) + SQRT2*( self.mat.t.im.num.1 as Float) )*SQRT2.pow(-self.mat.t.im.log_den),
+                re: (  self.mat.t.re.num.0.to_float() + SQRT2*self.mat.t.re.num.1.to_float() )*SQRT2.pow(-self.mat.t.re.log_den),
+                im: (  self.mat.t.im.num.0.to_float() + SQRT2*self.mat.t.im.num.1.to_float() )*SQRT2.pow(-self.mat.t.im.log_den),
             },
 
         }
     }
 
+    // The same unitary times ζ^-k, with ζ = e^{iπ/8} and ζ^2 = ω, which is in
+    // SU(2). So unlike the one above, the phase is not thrown away: T is
+    // diag(ζ^-1, ζ) here and not the identity. Up to a sign, since ζ^8 = -1
+    pub fn to_float_special_unitary(self) -> SUniMat::<Complex::<Float>>
+    {
+        let phase = Complex::from_polar(1.0, -(self.omega_exp as Float)*std::f64::consts::PI/8.0);
+        let gate = self.to_float_gate_upto_t_count();
+        return SUniMat::<Complex::<Float>>{ u: gate.u*phase, t: gate.t*phase };
+    }
+
 }
 
 
-// Teaching rust how to compare these ring elements
-impl PartialEq for ExactUniMat
+// A plain text form of the full matrix
+//   [[u, -t^* ω^k], [t, u^* ω^k]]
+// where every entry is written as re + im i, with re and im as Local<Zroot2> displays them
+// FromStr below reads it back
+impl<I: IntBackend> ExactUniMat<I>
 {
-    fn eq(&self, other: &Self) -> bool {
-        return self.mat == other.mat && self.omega_exp%8 == other.omega_exp%8;
+    pub fn to_matrix_string(&self) -> String
+    {
+        let phase = mu_8::<I>().pow(self.omega_exp);
+        let entries = [
+            self.mat.u.clone(),
+            -self.mat.t.conj()*phase.clone(),
+            self.mat.t.clone(),
+            self.mat.u.conj()*phase
+        ];
+        let written: Vec<String> = entries.iter().map(|z| format!("{} + {}i", z.re, z.im)).collect();
+        return format!("[[{}, {}], [{}, {}]]", written[0], written[1], written[2], written[3]);
+    }
+}
+
+fn parse_complex_entry<I: IntBackend>(input: &str) -> core::result::Result<KMMring<I>, ParseRingError>
+{
+    let without_i = input.strip_suffix('i').ok_or(parsing::error("expected re + im i", input))?;
+    let parts = parsing::split_top_level(without_i, '+');
+    if parts.len() != 2
+    {
+        return Err(parsing::error("expected re + im i", input));
     }
+    return Ok(Complex{ re: parts[0].parse()?, im: parts[1].parse()? });
 }
 
+impl<I: IntBackend> FromStr for ExactUniMat<I>
+{
+    type Err = ParseRingError;
 
+    fn from_str(input: &str) -> core::result::Result<Self, ParseRingError>
+    {
+        let normalized = parsing::normalize(input);
+        let rows = parsing::split_top_level(parsing::strip_brackets(&normalized, '[', ']'), ',');
+        if rows.len() != 2
+        {
+            return Err(parsing::error("expected two rows", input));
+        }
+
+        let mut entries = vec![];
+        for row in rows
+        {
+            let row_entries = parsing::split_top_level(parsing::strip_brackets(row, '[', ']'), ',');
+            if row_entries.len() != 2
+            {
+                return Err(parsing::error("expected two entries in a row", input));
+            }
+            for entry in row_entries
+            {
+                entries.push(parse_complex_entry::<I>(entry)?);
+            }
+        }
 
+        // The phase is whatever makes the second column fit
+        for omega_exp in 0..8
+        {
+            let candidate = ExactUniMat
+            {
+                mat: SUniMat{ u: entries[0].clone(), t: entries[2].clone() },
+                omega_exp
+            };
+            let phase = mu_8::<I>().pow(omega_exp);
+            if entries[1] == -candidate.mat.t.conj()*phase.clone() && entries[3] == candidate.mat.u.conj()*phase
+            {
+                return Ok(candidate);
+            }
+        }
+        return Err(parsing::error("not an exact unitary of the form [[u, -t^* ω^k], [t, u^* ω^k]]", input));
+    }
+}
 
 
+// Teaching rust how to compare these ring elements
+impl<I: IntBackend> PartialEq for ExactUniMat<I>
+{
+    fn eq(&self, other: &Self) -> bool {
+        return self.mat == other.mat && self.omega_exp%8 == other.omega_exp%8;
+    }
+}
@@ -0,0 +1,249 @@
+// Unitary matrices from the Pauli+V gate set
+//
+// The V gates are V_X = (I+2iX)/√5, V_Y = (I+2iY)/√5 and V_Z = (I+2iZ)/√5.
+// Together with the Paulis, they make exactly the special unitaries
+// /         \
+// | u  -t^* |
+// | t   u^* |  / √5^k
+// \         /
+// where u and t are Gaussian integers with |u|^2+|t|^2 = 5^k
+// See arxiv:1303.1411 by Bocharov, Gurevich and Svore
+//
+// As in ExactUniMat, we store u and t over Z[i] localized at 5,
+// and the leftover odd power of √5 as sqrt5_exp, which is 0 or 1
+//
+// The global phase is dropped. The Paulis are stored as iX, iY and iZ,
+// which have determinant 1, so X*X is -I here and not I
+//
+// In gate strings:
+//      X, Y, Z         Paulis
+//      A, B, C         V_X, V_Y, V_Z
+//      a, b, c         their inverses
+//      I               nothing
+
+
+use std::ops::Mul;
+use std::cmp::PartialEq;
+use num_traits::Pow;
+use num_traits::One;
+
+// For display
+use std::fmt::Result;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::structs::sunimat::SUniMat;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zi::Zi;
+use crate::structs::rings::LogDepInt;
+use crate::structs::rings::Int;
+use crate::structs::rings::IntBackend;
+use crate::structs::rings::Localizable;
+use crate::structs::rings::binary_pow;
+use crate::structs::rings::signed_pow;
+
+type ILoc<I = Int> = Local<Zi<I>>;
+
+// The special unitary part, times √5^sqrt5_exp
+pub type GaussMat<I = Int> = SUniMat<ILoc<I>>;
+
+type ExpInt = u8;
+
+#[derive(Debug,Copy,Clone)]
+pub struct ExactVUniMat<I = Int>
+{
+    pub mat: GaussMat<I>,
+    pub sqrt5_exp: ExpInt           // This is 0 or 1
+}
+
+
+// Nicely display Unitary Matrices
+impl<I: IntBackend> Display for ExactVUniMat<I>
+{
+    fn fmt(&self, f: &mut Formatter) -> Result{
+        write!(f,"{}/ √5^{}\n",self.mat, self.sqrt5_exp)
+    }
+}
+
+
+impl<I: IntBackend> ExactVUniMat<I>
+{
+    pub fn one() -> Self
+    {
+        return Self
+        {
+            mat: GaussMat::one(),
+            sqrt5_exp: 0
+        };
+    }
+
+    // Small helper for the gates below
+    fn from_gaussian(u: Zi<I>, t: Zi<I>, sqrt5_exp: ExpInt) -> Self
+    {
+        return Self
+        {
+            mat: GaussMat{ u: ILoc::from_base(u), t: ILoc::from_base(t) },
+            sqrt5_exp
+        };
+    }
+
+    fn gaussian(a: Int, b: Int) -> Zi<I>
+    {
+        return Zi(I::from_int(a), I::from_int(b));
+    }
+
+    // iX
+    pub fn x_gate() -> Self
+    {
+        return Self::from_gaussian(Self::gaussian(0, 0), Self::gaussian(0, 1), 0);
+    }
+
+    // iY
+    pub fn y_gate() -> Self
+    {
+        return Self::from_gaussian(Self::gaussian(0, 0), Self::gaussian(-1, 0), 0);
+    }
+
+    // iZ
+    pub fn z_gate() -> Self
+    {
+        return Self::from_gaussian(Self::gaussian(0, 1), Self::gaussian(0, 0), 0);
+    }
+
+    // (I+2iX)/√5
+    pub fn vx_gate() -> Self
+    {
+        return Self::from_gaussian(Self::gaussian(1, 0), Self::gaussian(0, 2), 1);
+    }
+
+    // (I+2iY)/√5
+    pub fn vy_gate() -> Self
+    {
+        return Self::from_gaussian(Self::gaussian(1, 0), Self::gaussian(-2, 0), 1);
+    }
+
+    // (I+2iZ)/√5
+    pub fn vz_gate() -> Self
+    {
+        return Self::from_gaussian(Self::gaussian(1, 2), Self::gaussian(0, 0), 1);
+    }
+
+    // Gates are applied right to left, as in ExactUniMat::from_string
+    pub fn from_string(gate_string : &String) -> Self
+    {
+        let mut output = Self::one();
+        for i in gate_string.chars().rev()
+        {
+            output = match i
+            {
+                'X' => Self::x_gate()*output,
+                'Y' => Self::y_gate()*output,
+                'Z' => Self::z_gate()*output,
+                'A' => Self::vx_gate()*output,
+                'B' => Self::vy_gate()*output,
+                'C' => Self::vz_gate()*output,
+                'a' => Self::vx_gate().inv()*output,
+                'b' => Self::vy_gate().inv()*output,
+                'c' => Self::vz_gate().inv()*output,
+                'I' => output,
+                _ => panic!("Gates other than X, Y, Z, A, B, C, a, b or c in the sequence"),
+            };
+        }
+        return output;
+    }
+
+    // Conjugate-transpose, same as taking an inverse
+    pub fn inv(self) -> Self
+    {
+        return Self
+        {
+            mat: self.mat.inv(),
+            sqrt5_exp: self.sqrt5_exp
+        };
+    }
+
+    // The determinant of the stored matrix, which is 5^sqrt5_exp
+    // The determinant of the actual gate is this divided by 5^sqrt5_exp
+    pub fn det(self) -> ILoc<I>
+    {
+        return self.mat.det();
+    }
+
+    // The smallest k such that the entries are Gaussian integers over √5^k
+    // This is the number of V gates needed
+    pub fn sde(&self) -> LogDepInt
+    {
+        let log_den = self.mat.u.log_den.max(self.mat.t.log_den);
+        return 2*log_den + self.sqrt5_exp as LogDepInt;
+    }
+
+    // The numerators u and t over √5^sde
+    pub fn gaussian_entries(&self) -> (Zi<I>, Zi<I>)
+    {
+        let log_den = (self.sde() - self.sqrt5_exp as LogDepInt)/2;
+        let scale = |x: &ILoc<I>| x.num.clone().perform_n_multiplications(log_den - x.log_den);
+        return (scale(&self.mat.u), scale(&self.mat.t));
+    }
+
+    // The inverse of gaussian_entries
+    // Assumes that |u|^2+|t|^2 = 5^sde
+    pub fn from_gaussian_entries(u: Zi<I>, t: Zi<I>, sde: LogDepInt) -> Self
+    {
+        let denominator = ILoc{ num: Zi::one(), log_den: sde/2 };
+        return Self
+        {
+            mat: GaussMat{ u: ILoc::from_base(u)*denominator.clone(), t: ILoc::from_base(t)*denominator },
+            sqrt5_exp: (sde%2) as ExpInt
+        };
+    }
+}
+
+
+// Teaching rust how to multiply these matrices
+// Two halves of √5 make a whole 5, which goes into the denominators
+impl<I: IntBackend> Mul for ExactVUniMat<I>
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self
+    {
+        let mat = self.mat*other.mat;
+        if self.sqrt5_exp + other.sqrt5_exp < 2
+        {
+            return Self{ mat, sqrt5_exp: self.sqrt5_exp + other.sqrt5_exp };
+        }
+        let one_by_five = ILoc{ num: Zi::one(), log_den: 1 };
+        return Self
+        {
+            mat: GaussMat{ u: mat.u*one_by_five.clone(), t: mat.t*one_by_five },
+            sqrt5_exp: 0
+        };
+    }
+}
+
+// Powers by square-and-multiply
+impl<I: IntBackend> Pow<u32> for ExactVUniMat<I>
+{
+    type Output = Self;
+    fn pow(self, p: u32) -> Self
+    {
+        return binary_pow(self, p, Self::one());
+    }
+}
+
+// Negative powers are powers of the inverse
+impl<I: IntBackend> Pow<i32> for ExactVUniMat<I>
+{
+    type Output = Self;
+    fn pow(self, p: i32) -> Self
+    {
+        return signed_pow(self, p, Self::one(), |x| x.inv());
+    }
+}
+
+// Teaching rust how to compare these matrices
+impl<I: IntBackend> PartialEq for ExactVUniMat<I>
+{
+    fn eq(&self, other: &Self) -> bool {
+        return self.mat == other.mat && self.sqrt5_exp == other.sqrt5_exp;
+    }
+}
@@ -0,0 +1,107 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::CheckedRing;
+use crate::structs::rings::CheckedNorm;
+use crate::structs::rings::LocalizableNorm;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::checked_pow;
+use crate::structs::rings::pow;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::algorithms::inexact_synth::attempt_to_figure_out_gate_given_that_this_point_is_feasible;
+use crate::algorithms::local_prime_factorization::attempt_to_write_this_number_as_sum_of_two_squares_in_loc;
+
+use num_complex::Complex;
+
+type Loc = Local<Zroot2>;
+type Vec4Int = nalgebra::Matrix4x1<Int>;
+
+
+#[test]
+pub fn checked_arithmetic_agrees_with_unchecked()
+{
+    let a = Zroot2(3, -5);
+    let b = Zroot2(-7, 2);
+    assert_eq!( a.checked_add(&b), Ok(a+b) );
+    assert_eq!( a.checked_sub(&b), Ok(a-b) );
+    assert_eq!( a.checked_mul(&b), Ok(a*b) );
+    assert_eq!( a.checked_norm(), Ok(a.norm()) );
+
+    let x = Zomega(1, -2, 3, 5);
+    let y = Zomega(4, 0, -1, 2);
+    assert_eq!( x.checked_add(&y), Ok(x+y) );
+    assert_eq!( x.checked_sub(&y), Ok(x-y) );
+    assert_eq!( x.checked_mul(&y), Ok(x*y) );
+    assert_eq!( x.checked_norm(), Ok(x.norm()) );
+
+    let p = Loc{ num: Zroot2(3, 1), log_den: 5 };
+    let q = Loc{ num: Zroot2(1, -1), log_den: 2 };
+    assert_eq!( p.checked_add(&q), Ok(p+q) );
+    assert_eq!( p.checked_sub(&q), Ok(p-q) );
+    assert_eq!( p.checked_mul(&q), Ok(p*q) );
+    assert_eq!( p.checked_norm(), Ok(p.norm()) );
+
+    let z = Complex::new(p, q);
+    let w = Complex::new(q, p);
+    assert_eq!( z.checked_mul(&w), Ok(z*w) );
+
+    assert_eq!( checked_pow(&a, 5), Ok(pow(a, 5)) );
+}
+
+
+#[test]
+pub fn checked_arithmetic_catches_overflows()
+{
+    let big : Int = 1 << 64;
+
+    let a = Zroot2(big, 1);
+    assert_eq!( a.checked_mul(&a), Err(ArithmeticOverflow) );
+    assert_eq!( a.checked_norm(), Err(ArithmeticOverflow) );
+    assert_eq!( Zroot2(Int::MAX, 0).checked_add(&Zroot2(1, 0)), Err(ArithmeticOverflow) );
+
+    // Fourth powers overflow way before squares do
+    let x = Zomega(1 << 40, 1, 0, 0);
+    assert!( x.checked_mul(&x).is_ok() );
+    assert_eq!( x.checked_norm(), Err(ArithmeticOverflow) );
+
+    // Bringing these to the same denominator means multiplying by 2^150
+    let tiny = Loc{ num: Zroot2(1, 0), log_den: 300 };
+    let one = Loc{ num: Zroot2(1, 0), log_den: 0 };
+    assert_eq!( tiny.checked_add(&one), Err(ArithmeticOverflow) );
+
+    assert_eq!( checked_pow(&a, 3), Err(ArithmeticOverflow) );
+
+    // None of this is a problem with BigInt
+    let x_big = x.to_backend::<BigInt>().unwrap();
+    assert!( x_big.checked_norm().is_ok() );
+    assert_eq!( x_big.checked_norm(), Ok(x_big.norm()) );
+}
+
+
+#[test]
+pub fn overflowing_lattice_candidates_are_reported()
+{
+    let point = Vec4Int::new((1 << 64) + 1, 1, 0, 0);
+
+    let with_int = attempt_to_figure_out_gate_given_that_this_point_is_feasible::<Int>(point, 0);
+    assert_eq!( with_int, Err(ArithmeticOverflow) );
+
+    // This point is nowhere near the unit disk, so there is no gate
+    let with_bigint = attempt_to_figure_out_gate_given_that_this_point_is_feasible::<BigInt>(point.map(BigInt::from), 0);
+    assert_eq!( with_bigint, Ok(None) );
+}
+
+
+#[test]
+pub fn sums_of_two_squares_report_overflows()
+{
+    // 2^50+1 - 7√2 has a prime norm near 2^100, so it is prime in Z[√2]
+    // Splitting the norm in Z[√2] squares it, which only fits in BigInt
+    let our_num = Loc::from_base(Zroot2((1 << 50) + 1, -7));
+    assert_eq!( attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num), Err(ArithmeticOverflow) );
+
+    // The norm is 7 mod 8, and appears once, so this is not a sum of two squares
+    let our_num_big = Local::from_base(Zroot2(BigInt::from((1 as Int) << 50) + 1, BigInt::from(-7)));
+    assert_eq!( attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num_big), Ok(None) );
+}
@@ -0,0 +1,121 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Gate;
+use crate::structs::circuit::Circuit;
+use crate::structs::circuit::SqrtTGate;
+use crate::structs::circuit::VGate;
+use crate::structs::sqrt_t_unimat::SqrtTUniMat;
+use crate::structs::v_unimat::ExactVUniMat;
+use crate::algorithms::exact_synth::exact_synth;
+use crate::algorithms::exact_synth::exact_synth_string;
+use crate::tests::int_backend_tests::long_gate_string;
+
+use rand::thread_rng;
+use rand::Rng;
+
+
+pub fn random_circuit(length: usize) -> Circuit
+{
+    let mut rng = thread_rng();
+    let mut circuit: Circuit = (0..length).map(|_| Gate::ALL[rng.gen_range(0..8)]).collect();
+    circuit.omega_exp = rng.gen_range(0..8);
+    return circuit;
+}
+
+
+#[test]
+pub fn circuits_read_and_write_gate_strings()
+{
+    for word in ["", "H", "HTHT", "WWHsZtXY", "SHTHTTHZ"]
+    {
+        let circuit: Circuit = word.parse().unwrap();
+        assert_eq!( circuit.to_string(), word );
+        assert_eq!( circuit.to_exact_unimat::<Int>(), ExactUniMat::from_string(&word.to_string()) );
+    }
+
+    // The phase is written first, and I is dropped
+    let circuit: Circuit = "HWITW".parse().unwrap();
+    assert_eq!( circuit.to_string(), "WWHT" );
+    assert_eq!( circuit.gates, vec![Gate::H, Gate::T] );
+    assert_eq!( circuit.omega_exp, 2 );
+
+    assert!( "HQT".parse::<Circuit>().is_err() );
+
+    for gate in Gate::ALL
+    {
+        assert_eq!( Gate::from_letter(gate.letter()), Some(gate) );
+        assert_eq!( gate.inverse().inverse(), gate );
+        assert_eq!( gate.to_exact_unimat::<Int>()*gate.inverse().to_exact_unimat(), ExactUniMat::one() );
+    }
+}
+
+
+#[test]
+pub fn circuits_multiply_like_their_matrices()
+{
+    for i in 0..30
+    {
+        let (a, b) = (random_circuit(i), random_circuit(30-i));
+        let product = a.clone()*b.clone();
+        assert_eq!( product.len(), a.len() + b.len() );
+        assert_eq!( product.to_exact_unimat::<Int>(), a.to_exact_unimat()*b.to_exact_unimat() );
+
+        assert_eq!( (a.clone()*a.inverse()).to_exact_unimat::<Int>(), ExactUniMat::one() );
+        assert_eq!( a.adjoint().to_exact_unimat::<Int>(), a.to_exact_unimat().inv() );
+        assert_eq!( a.inverse().inverse(), a );
+
+        let collapsed = a.collapse_t_runs();
+        assert_eq!( collapsed.to_exact_unimat::<Int>(), a.to_exact_unimat() );
+        assert!( collapsed.t_count() <= a.t_count() );
+
+        // Iterating gives the gates back in order
+        let gates: Vec<Gate> = a.iter().copied().collect();
+        assert_eq!( gates, a.gates );
+        assert_eq!( (&a).into_iter().count(), a.len() );
+        assert_eq!( a.clone().into_iter().collect::<Circuit>().gates, a.gates );
+    }
+}
+
+
+#[test]
+pub fn exact_synth_returns_circuits()
+{
+    for i in 1..20
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+        let circuit = exact_synth(gate);
+        assert_eq!( circuit.to_exact_unimat(), gate );
+        assert_eq!( exact_synth_string(gate), circuit.to_string() );
+    }
+
+    let gate = ExactUniMat::<BigInt>::from_string(&long_gate_string(60));
+    assert_eq!( exact_synth(gate.clone()).to_exact_unimat(), gate );
+}
+
+
+#[test]
+pub fn circuits_over_other_gate_sets()
+{
+    for word in ["", "Q", "HQQH", "HQTQHQ"]
+    {
+        let circuit: Circuit<SqrtTGate> = word.parse().unwrap();
+        assert_eq!( circuit.to_string(), word );
+        assert_eq!( circuit.to_sqrt_t_unimat::<Int>(), SqrtTUniMat::from_string(&word.to_string()) );
+    }
+    assert_eq!( "HQQTQ".parse::<Circuit<SqrtTGate>>().unwrap().sqrt_t_count(), 3 );
+
+    for word in ["", "X", "AbC", "XaYBZc"]
+    {
+        let circuit: Circuit<VGate> = word.parse().unwrap();
+        assert_eq!( circuit.to_string(), word );
+        assert_eq!( circuit.to_v_unimat::<Int>(), ExactVUniMat::from_string(&word.to_string()) );
+    }
+    assert_eq!( "XaYBZc".parse::<Circuit<VGate>>().unwrap().v_count(), 3 );
+
+    // Neither gate set has a letter for the phase, or the gates of the other
+    assert!( "WHQ".parse::<Circuit<SqrtTGate>>().is_err() );
+    assert!( "WA".parse::<Circuit<VGate>>().is_err() );
+    assert!( "HA".parse::<Circuit<SqrtTGate>>().is_err() );
+    assert!( "AH".parse::<Circuit<VGate>>().is_err() );
+}
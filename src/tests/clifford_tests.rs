@@ -0,0 +1,123 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Gate;
+use crate::structs::circuit::Circuit;
+use crate::structs::clifford::Clifford;
+use crate::structs::clifford::is_clifford;
+use crate::structs::clifford::ORDER;
+use crate::algorithms::exact_synth::exact_synth;
+use crate::tests::circuit_tests::random_circuit;
+
+use std::collections::HashSet;
+
+
+#[test]
+pub fn there_are_192_cliffords()
+{
+    let all: Vec<Clifford> = Clifford::all().collect();
+    assert_eq!( all.len(), ORDER );
+    assert_eq!( all[0], Clifford::one() );
+    assert_eq!( Clifford::one().to_exact_unimat::<Int>(), ExactUniMat::one() );
+
+    // All different, and each one is its own word
+    let matrices: HashSet<String> = all.iter().map(|c| c.to_exact_unimat::<Int>().to_string()).collect();
+    assert_eq!( matrices.len(), ORDER );
+    for c in all.iter()
+    {
+        let gate = c.to_exact_unimat::<Int>();
+        assert!( is_clifford(&gate) );
+        assert!( is_clifford(&c.to_exact_unimat::<BigInt>()) );
+        assert_eq!( Clifford::from_exact_unimat(&gate), Some(*c) );
+        assert_eq!( c.word().to_exact_unimat::<Int>(), gate );
+        assert!( c.word().iter().all(|g| *g == Gate::H || *g == Gate::S) );
+        assert_eq!( c.canonical_circuit().to_exact_unimat::<Int>(), gate );
+    }
+
+    // Some words we know to be shortest
+    assert_eq!( Clifford::from_gate(Gate::H).unwrap().word().to_string(), "H" );
+    assert_eq!( Clifford::from_gate(Gate::Z).unwrap().word().to_string(), "SS" );
+    assert_eq!( Clifford::from_gate(Gate::T), None );
+    assert_eq!( Clifford::from_circuit(&"SHSHSH".parse().unwrap()), Some(Clifford::omega()) );
+    assert_eq!( Clifford::omega().pow(8), Clifford::one() );
+    assert_eq!( Clifford::one().to_string(), "I" );
+
+    assert!( !is_clifford(&ExactUniMat::<Int>::t_gate()) );
+    assert!( !is_clifford(&ExactUniMat::<Int>::from_string(&"HTHTH".to_string())) );
+}
+
+
+#[test]
+pub fn the_multiplication_table_is_a_group()
+{
+    let table = Clifford::multiplication_table();
+    assert_eq!( table.len(), ORDER );
+    for a in Clifford::all()
+    {
+        // Each row is a permutation
+        let row: HashSet<Clifford> = table[a.index()].iter().copied().collect();
+        assert_eq!( row.len(), ORDER );
+
+        assert_eq!( a*a.inv(), Clifford::one() );
+        assert_eq!( a.inv()*a, Clifford::one() );
+        assert_eq!( Clifford::omega().pow(a.phase())*a.representative(), a );
+        assert_eq!( a.representative().phase(), 0 );
+
+        for b in Clifford::all().step_by(7)
+        {
+            assert_eq!( (a*b).to_exact_unimat::<Int>(), a.to_exact_unimat()*b.to_exact_unimat() );
+        }
+    }
+
+    for i in 0..30
+    {
+        let circuit: Circuit = random_circuit(i).into_iter().filter(|g| g.is_clifford()).collect();
+        let clifford = Clifford::from_circuit(&circuit).unwrap();
+        assert_eq!( clifford.to_exact_unimat::<Int>(), circuit.to_exact_unimat() );
+
+        // Shortest among words in H and S only
+        let h_and_s: Circuit = circuit.into_iter().filter(|g| *g == Gate::H || *g == Gate::S).collect();
+        assert!( Clifford::from_circuit(&h_and_s).unwrap().word().len() <= h_and_s.len() );
+    }
+}
+
+
+#[test]
+pub fn cliffords_permute_paulis()
+{
+    // H swaps X and Z, and sends Y to -Y
+    let h = Clifford::from_gate(Gate::H).unwrap();
+    assert_eq!( h.pauli_table(), [(1, Gate::Z), (-1, Gate::Y), (1, Gate::X)] );
+
+    // S sends X to Y and Y to -X
+    let s = Clifford::from_gate(Gate::S).unwrap();
+    assert_eq!( s.pauli_table(), [(1, Gate::Y), (-1, Gate::X), (1, Gate::Z)] );
+
+    // The table sees the class up to phase, and there are 24 classes
+    let tables: HashSet<_> = Clifford::all().map(|c| c.pauli_table()).collect();
+    assert_eq!( tables.len(), 24 );
+    for c in Clifford::all()
+    {
+        assert_eq!( Clifford::from_pauli_table(&c.pauli_table(), c.phase()), Some(c) );
+        assert_eq!( (Clifford::omega()*c).pauli_table(), c.pauli_table() );
+    }
+
+    // X cannot go to X and Y at the same time
+    assert_eq!( Clifford::from_pauli_table(&[(1, Gate::X), (1, Gate::X), (1, Gate::Z)], 0), None );
+    // Orientation matters too
+    assert_eq!( Clifford::from_pauli_table(&[(1, Gate::X), (-1, Gate::Y), (1, Gate::Z)], 0), None );
+}
+
+
+#[test]
+pub fn exact_synth_writes_cliffords_as_shortest_words()
+{
+    for c in Clifford::all()
+    {
+        let gate = c.to_exact_unimat::<Int>();
+        let circuit = exact_synth(gate);
+        assert_eq!( circuit.to_exact_unimat::<Int>(), gate );
+
+        assert_eq!( circuit, c.word() );
+    }
+}
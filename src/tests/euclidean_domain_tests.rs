@@ -0,0 +1,180 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::pow;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::rings::Conj;
+
+use std::fmt::Debug;
+
+use rand::thread_rng;
+use rand::Rng;
+
+use num_traits::Zero;
+use num_traits::One;
+
+
+pub fn random_zroot2(range: Int) -> Zroot2
+{
+    let mut rng = thread_rng();
+    return Zroot2(rng.gen_range(-range..range), rng.gen_range(-range..range));
+}
+
+pub fn random_zomega(range: Int) -> Zomega
+{
+    let mut rng = thread_rng();
+    return Zomega(rng.gen_range(-range..range), rng.gen_range(-range..range), rng.gen_range(-range..range), rng.gen_range(-range..range));
+}
+
+// The identities every EuclideanDomain should satisfy
+pub fn euclidean_identities<T>(a: T, b: T)
+where T: EuclideanDomain+Debug
+{
+    let (q, r) = a.div_rem(&b);
+    assert_eq!( a, q*b.clone()+r );
+
+    let (g, s, t) = a.extended_gcd(&b);
+    assert_eq!( g, s*a.clone()+t*b.clone() );
+    assert_eq!( g, a.gcd(&b) );
+    assert_eq!( g, g.normalize() );
+    assert!( a.div_rem(&g).1.is_zero() && b.div_rem(&g).1.is_zero() );
+
+    let l = a.lcm(&b);
+    assert!( l.div_rem(&a).1.is_zero() && l.div_rem(&b).1.is_zero() );
+    assert_eq!( (l*g).normalize(), (a.clone()*b.clone()).normalize() );
+
+    assert!( a.normalizing_unit().is_unit() );
+    assert_eq!( a.normalize().normalize(), a.normalize() );
+}
+
+
+#[test]
+pub fn euclidean_identities_for_integers()
+{
+    let mut rng = thread_rng();
+    for _ in 0..100
+    {
+        let a : Int = rng.gen_range(-100000..100000);
+        let b : Int = rng.gen_range(1..100000);
+        euclidean_identities(a, b);
+        euclidean_identities(a, -b);
+    }
+    assert_eq!( (-12 as Int).gcd(&18), 6 );
+    assert_eq!( (4 as Int).lcm(&-6), 12 );
+}
+
+
+#[test]
+pub fn euclidean_identities_for_zroot2()
+{
+    for _ in 0..100
+    {
+        let common = random_zroot2(100);
+        let a = common*random_zroot2(1000);
+        let b = common*random_zroot2(1000);
+        if a.is_zero() || b.is_zero()
+        {
+            continue;
+        }
+        euclidean_identities(a, b);
+    }
+}
+
+
+#[test]
+pub fn euclidean_identities_for_zomega()
+{
+    for _ in 0..100
+    {
+        let common = random_zomega(100);
+        let a = common*random_zomega(100);
+        let b = common*random_zomega(100);
+        if a.is_zero() || b.is_zero()
+        {
+            continue;
+        }
+        euclidean_identities(a, b);
+    }
+}
+
+
+#[test]
+pub fn zomega_quotients_that_do_not_fit_are_reported()
+{
+    // Dividing by λ^(-1) multiplies by λ, which pushes the coefficients past Int
+    let big = Int::MAX/2;
+    let x = Zomega(big, big, 0, -big);
+    let lambda_inv = Zomega::from_zroot2(Zroot2(-1, 1));
+    assert!( x.checked_div_rem(&lambda_inv).is_err() );
+    assert!( x.checked_gcd(&lambda_inv).is_err() );
+
+    // Everything that fits is the same as div_rem
+    let y = Zomega(big/4, 1, 0, 0);
+    assert_eq!( y.checked_div_rem(&lambda_inv), Ok(y.div_rem(&lambda_inv)) );
+    assert_eq!( x.checked_gcd(&Zomega(3, 0, 0, 0)), Ok(x.gcd(&Zomega(3, 0, 0, 0))) );
+}
+
+
+#[test]
+pub fn associates_have_the_same_normalization()
+{
+    let lambda = Zroot2(1, 1);
+    let lambda_inv = Zroot2(-1, 1);
+    let omega = Zomega(0, 1, 0, 0);
+
+    assert!( pow(lambda, 7).is_unit() );
+    assert!( !Zroot2(2, 0).is_unit() );
+    assert!( pow(omega, 3).is_unit() );
+    assert!( !Zomega(1, 1, 0, 0).is_unit() );
+
+    for _ in 0..100
+    {
+        let x = random_zroot2(1000);
+        let y = random_zomega(1000);
+        if x.is_zero() || y.is_zero()
+        {
+            continue;
+        }
+
+        for k in 1..6
+        {
+            assert_eq!( (x*pow(lambda, k)).normalize(), x.normalize() );
+            assert_eq!( (-x*pow(lambda_inv, k)).normalize(), x.normalize() );
+
+            let unit = pow(omega, k)*Zomega::from_zroot2(pow(lambda, k));
+            assert_eq!( (y*unit).normalize(), y.normalize() );
+            let unit = pow(omega, k)*Zomega::from_zroot2(pow(lambda_inv, k));
+            assert_eq!( (y*unit).normalize(), y.normalize() );
+        }
+    }
+
+    assert_eq!( Zroot2::<Int>::zero().normalize(), Zroot2::zero() );
+    assert_eq!( pow(lambda, 4).normalize(), Zroot2::one() );
+    assert_eq!( (-pow(omega, 5)).normalize(), Zomega::one() );
+}
+
+
+#[test]
+pub fn zomega_quotients_are_nearest()
+{
+    for _ in 0..1000
+    {
+        let x = random_zomega(1 << 20);
+        let y = random_zomega(1 << 10);
+        if y.is_zero()
+        {
+            continue;
+        }
+        let (q, r) = x.div_rem(&y);
+        assert_eq!( x, q*y+r );
+        assert!( 16*r.norm().abs() <= 9*y.norm().abs() );
+
+        // The coordinates of x/y are those of x*ȳ*n•, over N(y)
+        let relative_norm = y*y.conj();
+        let relative_norm = Zroot2(relative_norm.0, relative_norm.1);
+        let numerator = x*y.conj()*Zomega::from_zroot2(relative_norm.conj());
+        let absolute_norm = y.norm();
+        let nearest = |coordinate: Int| (2*coordinate + absolute_norm).div_euclid(2*absolute_norm);
+        assert_eq!( q, Zomega(nearest(numerator.0), nearest(numerator.1), nearest(numerator.2), nearest(numerator.3)) );
+    }
+}
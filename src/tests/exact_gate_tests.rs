@@ -2,8 +2,13 @@ use crate::structs::unimat::ExactUniMat;
 use crate::structs::rings::local_ring::Local; 
 use crate::structs::rings::zroot2::Zroot2;
 use crate::structs::sunimat::SUniMat; 
+use crate::structs::rings::Int;
 use crate::algorithms::exact_synth::exact_synth;
 use crate::algorithms::exact_synth::apply_gate_string_to_state;
+use crate::algorithms::exact_synth::collapse_t_runs;
+use crate::structs::sqrt_t_unimat::SqrtTUniMat;
+use crate::tests::int_backend_tests::long_gate_string;
+use num_traits::{One, Zero};
 use num_complex::Complex;
 type Loc = Local<Zroot2>;
 type Comp = Complex<Loc>;
@@ -13,7 +18,7 @@ type Mat = SUniMat<Comp>;
 #[test]
 pub fn basic_identies() 
 {
-    let one = ExactUniMat::one();
+    let one = ExactUniMat::<Int>::one();
 
     assert_eq!(one.inv(), one);
     assert_eq!(one*one, one);
@@ -39,7 +44,7 @@ pub fn basic_identies()
 #[test]
 pub fn more_identities()
 {
-    let h_gate = ExactUniMat::h_gate();
+    let h_gate = ExactUniMat::<Int>::h_gate();
     let t_gate = ExactUniMat::t_gate();
 
     let temp = t_gate * t_gate;
@@ -104,3 +109,57 @@ pub fn multiply_h_and_t()
     assert_eq!(prod, ExactUniMat::from_string(&"HT".to_string()) );
 
 }
+
+
+#[test]
+pub fn clifford_gate_vocabulary()
+{
+    let gate = |s: &str| ExactUniMat::<Int>::from_string(&s.to_string());
+    let one = ExactUniMat::<Int>::one();
+
+    // Powers of T, exactly and not just up to phase
+    assert_eq!( gate("S"), gate("TT") );
+    assert_eq!( gate("Z"), gate("TTTT") );
+    assert_eq!( gate("s"), gate("TTTTTT") );
+    assert_eq!( gate("t"), gate("TTTTTTT") );
+    assert_eq!( gate("sS"), one );
+    assert_eq!( gate("tT"), one );
+    assert_eq!( ExactUniMat::<Int>::tdg_gate(), ExactUniMat::t_gate().inv() );
+    assert_eq!( ExactUniMat::<Int>::sdg_gate(), ExactUniMat::s_gate().inv() );
+
+    // Paulis, with the phase
+    assert_eq!( gate("X"), gate("HZH") );
+    assert_eq!( gate("Y"), gate("WWXZ") );
+    assert_eq!( gate("Z"), gate("HXH") );
+    assert_eq!( gate("XYZ"), gate("WW") );
+    for p in ["X", "Y", "Z"]
+    {
+        assert_eq!( gate(p)*gate(p), one );
+        assert_eq!( gate(p).inv(), gate(p) );
+    }
+
+    // The global phase commutes with everything
+    assert_eq!( gate("WWWWWWWW"), one );
+    assert_eq!( gate("WH"), gate("HW") );
+    assert_eq!( gate("WWWW").mat, SUniMat{ u: -Comp::one(), t: Comp::zero() } );
+    assert_eq!( gate("W").mat, ExactUniMat::<Int>::omega_gate().mat );
+}
+
+
+#[test]
+pub fn collapsing_runs_of_t()
+{
+    assert_eq!( collapse_t_runs("TTHTTTTHTTTTTTTT"), "SHZH" );
+    assert_eq!( collapse_t_runs("TTTHTTTTTHTTTTTTT"), "STHZTHt" );
+    assert_eq!( collapse_t_runs("HTTTTTTH"), "HsH" );
+    assert_eq!( collapse_t_runs(""), "" );
+
+    for i in 1..30
+    {
+        let word = long_gate_string(i);
+        let collapsed = collapse_t_runs(&word);
+        assert_eq!( ExactUniMat::<Int>::from_string(&collapsed), ExactUniMat::from_string(&word) );
+        assert!( !collapsed.contains("TT") );
+        assert_eq!( SqrtTUniMat::<Int>::from_string(&collapsed), SqrtTUniMat::from_string(&word) );
+    }
+}
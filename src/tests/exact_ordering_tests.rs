@@ -0,0 +1,166 @@
+use crate::structs::rings::Conj;
+use crate::structs::rings::Int;
+use crate::structs::rings::Float;
+use crate::structs::rings::pow;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::algorithms::local_prime_factorization::find_unit_square_root;
+use crate::algorithms::inexact_synth::SQRT2;
+use crate::algorithms::inexact_synth::is_in_epsilon_region;
+use crate::tests::euclidean_domain_tests::random_zroot2;
+
+use std::cmp::Ordering;
+
+use rand::thread_rng;
+use rand::Rng;
+
+use num_traits::One;
+use num_traits::Zero;
+
+type Loc = Local<Zroot2>;
+type Vec4Int = nalgebra::Matrix4x1<Int>;
+
+
+#[test]
+pub fn signs_of_zroot2_agree_with_floats()
+{
+    for _ in 0..1000
+    {
+        let x = random_zroot2(1000);
+        let value = x.0 as Float + SQRT2*(x.1 as Float);
+        let expected = if x.is_zero() { Ordering::Equal } else if value > 0.0 { Ordering::Greater } else { Ordering::Less };
+        assert_eq!( x.sign(), expected );
+        assert_eq!( x.abs(), x.signum()*x );
+
+        let floor = x.floor();
+        assert!( (floor as Float) <= value && value < (floor as Float) + 1.0 );
+        assert!( (x.round() as Float - value).abs() <= 0.5 );
+    }
+}
+
+
+#[test]
+pub fn signs_that_floats_cannot_see()
+{
+    // λ^n = a + b√2 and a - b√2 = (1-√2)^n is tiny, with sign (-1)^n
+    let lambda = Zroot2(1 as Int, 1);
+    for n in 1..45
+    {
+        let x = pow(lambda, n).conj();
+        let expected = if n%2 == 0 { Ordering::Greater } else { Ordering::Less };
+        assert_eq!( x.sign(), expected );
+        assert_eq!( x.floor(), if n%2 == 0 { 0 } else { -1 } );
+        assert_eq!( x.round(), 0 );
+        assert_eq!( pow(lambda, n).is_doubly_positive(), n%2 == 0 );
+    }
+
+    // Too big for i128 squares, the comparison goes through BigInt
+    let big : Int = 1 << 100;
+    assert_eq!( Zroot2(big, -big).sign(), Ordering::Less );
+    assert!( Zroot2(big, 0) < Zroot2(0, big) );
+}
+
+
+#[test]
+pub fn local_zroot2_is_ordered_by_value()
+{
+    let root2 = Loc{ num: Zroot2::one(), log_den: -1 };
+    let half = Loc{ num: Zroot2::one(), log_den: 2 };
+    let onebyroot2 = Loc{ num: Zroot2::one(), log_den: 1 };
+    assert!( half < onebyroot2 && onebyroot2 < Loc::one() && Loc::one() < root2 );
+    assert!( -root2 < Loc::zero() );
+
+    // √2 is positive, but its conjugate is not
+    assert!( !root2.is_doubly_positive() );
+    assert!( (root2*root2).is_doubly_positive() );
+
+    let mut rng = thread_rng();
+    for _ in 0..1000
+    {
+        let x = Loc::from_base(random_zroot2(1000))*Loc{ num: Zroot2::one(), log_den: rng.gen_range(-6..12) };
+        let y = Loc::from_base(random_zroot2(1000))*Loc{ num: Zroot2::one(), log_den: rng.gen_range(-6..12) };
+        let (fx, fy) = (x.to_float(), y.to_float());
+
+        if (fx-fy).abs() > 1e-6
+        {
+            assert_eq!( x < y, fx < fy );
+        }
+        assert_eq!( x.abs(), x.signum()*x );
+
+        // The float can land on the wrong side of an integer it is close to
+        let floor = x.floor();
+        if (fx - fx.round()).abs() > 1e-6
+        {
+            assert_eq!( floor as Float, fx.floor() );
+        }
+
+        // Exact halves and integers are dyadic floats, so only values close to a half are skipped
+        let twice = x.clone() + x.clone();
+        let twice_is_an_integer = Loc::from_base(Zroot2(twice.floor(), 0)) == twice;
+        if twice_is_an_integer || (fx + 0.5 - (fx + 0.5).round()).abs() > 1e-6
+        {
+            assert_eq!( x.round() as Float, (fx + 0.5).floor() );
+        }
+        assert!( Loc::from_base(Zroot2(floor, 0)) <= x );
+    }
+}
+
+
+#[test]
+pub fn halves_round_up()
+{
+    // Towards +∞ on both sides of 0, not away from 0 like f64::round
+    let halves = [(1, 1), (-1, 0), (5, 3), (-5, -2), (579, 290), (-579, -289)];
+    for (odd, rounded) in halves
+    {
+        let x = Loc{ num: Zroot2(odd as Int, 0), log_den: 2 };
+        assert_eq!( x.round(), rounded );
+    }
+    let quarter = Loc{ num: Zroot2(-1 as Int, 0), log_den: 4 };
+    assert_eq!( quarter.round(), 0 );
+}
+
+
+#[test]
+pub fn unit_square_roots_are_exact()
+{
+    let lambda = Zroot2(1 as Int, 1);
+    let lambda_inv = Zroot2(-1 as Int, 1);
+    assert_eq!( find_unit_square_root(Zroot2::<Int>::one()), Some(Zroot2::one()) );
+    for k in 1..40
+    {
+        assert_eq!( find_unit_square_root(pow(lambda, 2*k)), Some(pow(lambda, k)) );
+        assert_eq!( find_unit_square_root(pow(lambda_inv, 2*k)), Some(pow(lambda_inv, k)) );
+        assert_eq!( find_unit_square_root(pow(lambda, 2*k-1)), None );
+    }
+}
+
+
+#[test]
+pub fn epsilon_regions_are_decided_exactly()
+{
+    assert_eq!( Loc::from_float_exactly(0.0), Some(Loc::zero()) );
+    assert_eq!( Loc::from_float_exactly(1.0), Some(Loc::one()) );
+    assert_eq!( Loc::from_float_exactly(-0.75), Some(Loc{ num: Zroot2(-3, 0), log_den: 4 }) );
+    assert_eq!( Loc::from_float_exactly(Float::NAN), None );
+    // to_float goes through powers of √2, so it is not exact
+    for x in [1e-7, 0.1, -3.5e10]
+    {
+        assert!( (Loc::from_float_exactly(x).unwrap().to_float() - x).abs() <= 1e-12*x.abs() );
+    }
+    // The float 0.1 is not 1/10
+    assert_ne!( Loc::from_float_exactly(0.1).unwrap()*Loc::from_base(Zroot2(10, 0)), Loc::one() );
+
+    // 3/4 is on the boundary of the half plane for epsilon 1/4
+    let direction = num_complex::Complex::new(1.0, 0.0);
+    let three_quarters = Vec4Int::new(3, 0, 0, 0);
+    assert!( !is_in_epsilon_region(three_quarters, 4, (direction, 0.25)) );
+    assert!( is_in_epsilon_region(three_quarters, 4, (direction, 0.25 + 1e-12)) );
+
+    // 3/4 ± (√2-1)^50, which is about 6e-20, are the same float
+    let tiny = pow(Zroot2(-1 as Int, 1), 50);
+    let above = Vec4Int::new(3 + 4*tiny.0, 4*tiny.1, 0, 0);
+    let below = Vec4Int::new(3 - 4*tiny.0, -4*tiny.1, 0, 0);
+    assert!( is_in_epsilon_region(above, 4, (direction, 0.25)) );
+    assert!( !is_in_epsilon_region(below, 4, (direction, 0.25)) );
+}
@@ -1,6 +1,23 @@
 use crate::algorithms::exact_synth_hashtable_lookup::generate_gate_table;
 use crate::algorithms::exact_synth_hashtable_lookup::read_hash_table;
-use crate::algorithms::exact_synth_hashtable_lookup::GATE_STRING_LENGTH;
+use crate::algorithms::exact_synth_hashtable_lookup::parse_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::load_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::gate_table;
+use crate::algorithms::exact_synth_hashtable_lookup::init_gate_table;
+use crate::algorithms::exact_synth_hashtable_lookup::TableSource;
+use crate::algorithms::exact_synth_hashtable_lookup::save_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_residual_table;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::residual_table_source;
+use crate::algorithms::exact_synth_hashtable_lookup::RESIDUAL_SDE;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_table_breadth_first;
+use crate::algorithms::exact_synth_hashtable_lookup::TableGeneration;
+use crate::algorithms::exact_synth::sde;
+use crate::algorithms::exact_synth::exact_synth;
+use crate::algorithms::normal_form::normal_form;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Circuit;
+use crate::structs::rings::Int;
 
 // Rings and matrices
 use crate::structs::rings::zroot2::Zroot2;
@@ -36,11 +53,10 @@ pub fn print_hashtable()
 #[test]
 pub fn checking_hashtable_values_easy_cases() 
 {
-    // Assuming that hashtable exists
+    // The table that is compiled in
     // we will check that some values are what we expect
 
-    let filename = "data/gates_with_small_t_count.dat";
-    let hashtable = read_hash_table(filename).unwrap();
+    let hashtable = load_hash_table(&TableSource::Embedded).unwrap();
 
     let state = apply_gate_string_to_state("HT".to_string(), ExactState::one());
     let seq = hashtable.get(&state).unwrap();
@@ -61,7 +77,7 @@ pub fn checking_hashtable_values_easy_cases()
     
     let state = apply_gate_string_to_state("TTH".to_string(), ExactState::one());
     let seq = hashtable.get(&state).unwrap();
-    assert_eq!(seq, "TTH");
+    assert_eq!(seq, "SH");
     
     let state = apply_gate_string_to_state("TH".to_string(), ExactState::one());
     let seq = hashtable.get(&state).unwrap();
@@ -73,20 +89,26 @@ pub fn checking_hashtable_values_easy_cases()
 #[test]
 pub fn checking_hashtable_values_more_difficult_cases()
 {
-    let filename = "data/gates_with_small_t_count.dat";
-    let hashtable = read_hash_table(filename).unwrap();
+    let hashtable = load_hash_table(&TableSource::Embedded).unwrap();
 
 
 
     let input_string = "TTHT";
-    let expected_answer = "TTH";
+    let expected_answer = "SH";
     let state = apply_gate_string_to_state(input_string.to_string(), ExactState::one());
     let seq = hashtable.get(&state).unwrap();
     assert_eq!(seq, expected_answer);
 
 
     let input_string = "TTTHTT";
-    let expected_answer = "TTTH";
+    let expected_answer = "TSH";
+    let state = apply_gate_string_to_state(input_string.to_string(), ExactState::one());
+    let seq = hashtable.get(&state).unwrap();
+    assert_eq!(seq, expected_answer);
+
+
+    let input_string = "THTTTHTTTH";
+    let expected_answer = "THTSHTSH";
     let state = apply_gate_string_to_state(input_string.to_string(), ExactState::one());
     let seq = hashtable.get(&state).unwrap();
     assert_eq!(seq, expected_answer);
@@ -94,5 +116,103 @@ pub fn checking_hashtable_values_more_difficult_cases()
 }
 
 
+#[test]
+pub fn gate_tables_load_from_every_source()
+{
+    let generated = load_hash_table(&TableSource::Generate).unwrap();
+
+    // print_hashtable may be rewriting the file at DEFAULT_TABLE_PATH, so use another one
+    let filename = std::env::temp_dir().join("grid_synthesis_gate_table_test.dat").to_str().unwrap().to_string();
+    save_hash_table(&generated, &filename).unwrap();
+    assert_eq!( load_hash_table(&TableSource::File(filename)).unwrap(), generated );
+    for (key, value) in generated.iter()
+    {
+        assert_eq!( apply_gate_string_to_state(value.to_string(), ExactState::one()), *key );
+    }
+
+    assert_eq!( load_hash_table(&TableSource::Embedded).unwrap(), generated );
+
+    // Errors, not panics
+    let missing = load_hash_table(&TableSource::File("no/such/table.dat".to_string()));
+    assert_eq!( missing.unwrap_err().kind(), std::io::ErrorKind::NotFound );
+    for bad in ["HT 1 2 3", "HT 1 0 0 0 0 0 0 0 0 0 0 x", "HT 1 0 0 0 0 0 0 0 0 0 0 0.5"]
+    {
+        assert_eq!( parse_hash_table(bad.as_bytes()).unwrap_err().kind(), std::io::ErrorKind::InvalidData );
+    }
+    assert!( parse_hash_table("".as_bytes()).unwrap().is_empty() );
+}
+
+
+#[test]
+pub fn the_gate_table_is_loaded_once_and_shared()
+{
+    let threads: Vec<_> = (0..4).map(|_| std::thread::spawn(|| gate_table() as *const _ as *const () as usize)).collect();
+    let addresses: Vec<usize> = threads.into_iter().map(|thread| thread.join().unwrap()).collect();
+    assert!( addresses.iter().all(|address| *address == addresses[0]) );
+
+    // Too late to choose a source now
+    let again = init_gate_table(TableSource::Generate);
+    assert_eq!( again.err().unwrap().kind(), std::io::ErrorKind::AlreadyExists );
+    assert_eq!( gate_table().len(), load_hash_table(&TableSource::Generate).unwrap().len() );
+}
+
+
+#[test]
+pub fn a_source_that_fails_leaves_the_compiled_in_table()
+{
+    // Whether or not another test loaded the table first, this one is refused
+    assert!( init_gate_table(TableSource::File("no/such/table.dat".to_string())).is_err() );
+    assert_eq!( gate_table().len(), load_hash_table(&TableSource::Embedded).unwrap().len() );
+    let gamma = ExactUniMat::<Int>::from_string(&"HTHTSHTHTHT".to_string());
+    let circuit = exact_synth(gamma);
+    assert!( circuit.to_exact_unimat::<Int>() == gamma );
+}
+
+
+#[test]
+pub fn the_residual_table_is_up_to_date_and_t_optimal()
+{
+    let table = generate_residual_table();
+    assert_eq!( residual_table_source(&table), include_str!("../algorithms/residual_table.rs") );
+
+    // The same matrices as the table of H and T strings of up to 25 letters
+    assert_eq!( table.len(), 464 );
 
+    for (key, value) in table.iter()
+    {
+        assert!( sde(*key) <= RESIDUAL_SDE );
+        let word: Circuit = value.parse().unwrap();
 
+        // No phase on the second column does better
+        let least = (0..8).map(|k| normal_form(&ExactUniMat{ mat: *key, omega_exp: k }).t_count()).min().unwrap();
+        assert_eq!( word.t_count(), least );
+    }
+}
+
+
+#[test]
+pub fn breadth_first_generation_is_t_optimal_with_any_number_of_threads()
+{
+    let one_thread = generate_table_breadth_first(&TableGeneration{ threads: 1, ..TableGeneration::new(6) });
+    let many_threads = generate_table_breadth_first(&TableGeneration{ threads: 5, report_progress: true, ..TableGeneration::new(6) });
+    assert_eq!( one_thread, many_threads );
+
+    // Everything the residual table has, and more
+    let residual = generate_residual_table();
+    assert!( one_thread.len() > residual.len() );
+    for (key, value) in residual.iter()
+    {
+        let word: Circuit = one_thread[key].parse().unwrap();
+        assert_eq!( word.t_count(), value.parse::<Circuit>().unwrap().t_count() );
+    }
+
+    for (key, value) in one_thread.iter()
+    {
+        assert!( sde(*key) <= 6 );
+        assert_eq!( apply_gate_string_to_state(value.to_string(), ExactState::one()), *key );
+
+        let word: Circuit = value.parse().unwrap();
+        let least = (0..8).map(|k| normal_form(&ExactUniMat{ mat: *key, omega_exp: k }).t_count()).min().unwrap();
+        assert_eq!( word.t_count(), least );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::Float;
+use crate::structs::rings::Conj;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::unimat::ExactUniMat;
+use crate::tests::euclidean_domain_tests::random_zroot2;
+use crate::tests::euclidean_domain_tests::random_zomega;
+use crate::tests::local_zomega_tests::random_loc;
+use crate::tests::local_zomega_tests::random_dloc;
+use crate::tests::int_backend_tests::long_gate_string;
+
+use num_complex::Complex;
+use num_traits::One;
+
+type Loc = Local<Zroot2>;
+type DLoc = Local<Zomega>;
+type CompLoc = Complex<Loc>;
+
+const ODD: [u8; 4] = [1, 3, 5, 7];
+
+
+// Floats lose digits to cancellation when one embedding is much bigger than the other
+// so the tolerance is relative to the size of both
+pub fn close_to(x: Complex<Float>, y: Complex<Float>, size: Float) -> bool
+{
+    return (x-y).norm() <= 1e-9*(1.0 + size);
+}
+
+
+#[test]
+pub fn galois_group_acts_on_zomega()
+{
+    for _ in 0..100
+    {
+        let (x, y) = (random_zomega(100), random_zomega(100));
+        let (p, q) = (random_dloc(), random_dloc());
+        for j in ODD
+        {
+            assert_eq!( (x*y).galois(j), x.galois(j)*y.galois(j) );
+            assert_eq!( (x+y).galois(j), x.galois(j)+y.galois(j) );
+            assert_eq!( (p*q).galois(j), p.galois(j)*q.galois(j) );
+            assert_eq!( (p+q).galois(j), p.galois(j)+q.galois(j) );
+            for k in ODD
+            {
+                assert_eq!( x.galois(j).galois(k), x.galois(j*k) );
+                assert_eq!( p.galois(j).galois(k), p.galois(j*k) );
+            }
+        }
+        assert_eq!( x.galois(1), x );
+        assert_eq!( x.complex_conj(), x.conj() );
+        assert_eq!( x.bullet(), x.conj_rt2() );
+        assert_eq!( p.complex_conj(), p.conj() );
+        assert_eq!( p.bullet(), p.conj_rt2() );
+    }
+}
+
+
+#[test]
+pub fn real_rings_only_see_the_bullet()
+{
+    for _ in 0..100
+    {
+        let x = random_zroot2(1000);
+        assert_eq!( x.complex_conj(), x );
+        assert_eq!( x.bullet(), Zroot2(x.0, -x.1) );
+        assert_eq!( x.galois(3), x.bullet() );
+
+        let p = random_loc();
+        assert_eq!( p.bullet(), p.conj() );
+        assert_eq!( p.complex_conj(), p );
+
+        let z = CompLoc::new(random_loc(), random_loc());
+        assert_eq!( z.complex_conj(), z.conj() );
+        assert_eq!( DLoc::from(z.bullet()), DLoc::from(z).bullet() );
+    }
+
+    // √2 goes to -√2, and so does 1/√2
+    let root2 = Loc{ num: Zroot2::<Int>::one(), log_den: -1 };
+    assert_eq!( root2.bullet(), -root2 );
+    assert_eq!( (Loc::one()/root2).bullet(), -Loc::one()/root2 );
+}
+
+
+#[test]
+#[should_panic]
+pub fn even_exponents_are_not_automorphisms()
+{
+    let _ = random_zomega(10).galois(2);
+}
+
+
+#[test]
+pub fn embeddings_come_from_one_place()
+{
+    for _ in 0..100
+    {
+        let p = random_dloc();
+        let (x, x_bullet) = p.embeddings();
+        let size = x.norm() + x_bullet.norm();
+        let (y, y_bullet) = CompLoc::from(p).embeddings();
+        assert!( close_to(x, y, size) && close_to(x_bullet, y_bullet, size) );
+
+        // The bullet swaps the two embeddings, complex conjugation conjugates them
+        let (z, z_bullet) = p.bullet().embeddings();
+        assert!( close_to(z, x_bullet, size) && close_to(z_bullet, x, size) );
+        let (w, w_bullet) = p.complex_conj().embeddings();
+        assert!( close_to(w, x.conj(), size) && close_to(w_bullet, x_bullet.conj(), size) );
+
+        let q = random_loc();
+        let (a, a_bullet) = q.embeddings();
+        let size = a.abs() + a_bullet.abs();
+        assert!( (a - q.to_float()).abs() <= 1e-9*(1.0 + size) );
+        let (b, _) = q.bullet().embeddings();
+        assert!( (b - a_bullet).abs() <= 1e-9*(1.0 + size) );
+    }
+}
+
+
+#[test]
+pub fn galois_images_of_gates_are_unitary()
+{
+    for i in 1..20
+    {
+        let mat = ExactUniMat::<Int>::from_string(&long_gate_string(i)).to_dyadic();
+        for k in ODD
+        {
+            let image = mat.galois(k);
+            assert_eq!( image*image.inv(), SUniMat::one() );
+            assert_eq!( image.det(), DLoc::one() );
+        }
+
+        let (float_mat, float_mat_bullet) = mat.embeddings();
+        for m in [float_mat, float_mat_bullet]
+        {
+            assert!( (m.u.norm_sqr() + m.t.norm_sqr() - 1.0).abs() < 1e-9 );
+        }
+    }
+}
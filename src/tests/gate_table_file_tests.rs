@@ -0,0 +1,144 @@
+use crate::algorithms::gate_table_file::encode_binary_table;
+use crate::algorithms::gate_table_file::decode_binary_table;
+use crate::algorithms::gate_table_file::save_binary_table;
+use crate::algorithms::gate_table_file::convert_text_table;
+use crate::algorithms::gate_table_file::crc32;
+use crate::algorithms::gate_table_file::TableParameters;
+use crate::algorithms::gate_table_file::GateTableError;
+use crate::algorithms::gate_table_file::FORMAT_VERSION;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_residual_table;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::save_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::load_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::TableSource;
+use crate::algorithms::exact_synth_hashtable_lookup::GateTable;
+use crate::algorithms::exact_synth_hashtable_lookup::key_from_numbers;
+use crate::structs::rings::Int;
+use crate::structs::rings::LogDepInt;
+
+
+fn temp_file(name: &str) -> String
+{
+    return std::env::temp_dir().join(name).to_str().unwrap().to_string();
+}
+
+// Changes the data and puts a correct checksum back
+fn with_new_checksum(mut data: Vec<u8>) -> Vec<u8>
+{
+    let length = data.len() - 4;
+    let checksum = crc32(&data[..length]);
+    data[length..].copy_from_slice(&checksum.to_le_bytes());
+    return data;
+}
+
+
+#[test]
+pub fn binary_tables_round_trip()
+{
+    // The usual check value of CRC-32
+    assert_eq!( crc32(b"123456789"), 0xCBF43926 );
+
+    let residual = generate_residual_table();
+    let parameters = TableParameters::residual_table(&residual);
+    let data = encode_binary_table(&residual, &parameters);
+    assert_eq!( data, encode_binary_table(&residual, &parameters) );
+    assert_eq!( decode_binary_table(&data).unwrap(), (parameters.clone(), residual.clone()) );
+
+    // Smaller than the text format
+    let filename = temp_file("grid_synthesis_residual_table_test.dat");
+    save_hash_table(&residual, &filename).unwrap();
+    assert!( data.len() < std::fs::metadata(&filename).unwrap().len() as usize );
+
+    let filename = temp_file("grid_synthesis_residual_table_test.bin");
+    save_binary_table(&residual, &parameters, &filename).unwrap();
+    assert_eq!( load_hash_table(&TableSource::Binary(filename)).unwrap(), residual );
+
+    let empty = Default::default();
+    assert_eq!( decode_binary_table(&encode_binary_table(&empty, &parameters)).unwrap().1, empty );
+}
+
+
+#[test]
+pub fn broken_binary_tables_give_errors()
+{
+    let residual = generate_residual_table();
+    let parameters = TableParameters::residual_table(&residual);
+    let data = encode_binary_table(&residual, &parameters);
+
+    let mut flipped = data.clone();
+    flipped[data.len()/2] ^= 1;
+    assert!( matches!( decode_binary_table(&flipped), Err(GateTableError::ChecksumMismatch{ .. }) ) );
+
+    for length in [0, 3, 5, 9]
+    {
+        assert!( matches!( decode_binary_table(&data[..length]), Err(GateTableError::Truncated) ) );
+    }
+    assert!( matches!( decode_binary_table(&data[..data.len()-1]), Err(GateTableError::ChecksumMismatch{ .. }) ) );
+    assert!( matches!( decode_binary_table(&with_new_checksum(data[..data.len()-20].to_vec())), Err(GateTableError::Truncated) ) );
+
+    let mut magic = data.clone();
+    magic[0] = b'X';
+    assert!( matches!( decode_binary_table(&magic), Err(GateTableError::BadMagic(_)) ) );
+    assert!( matches!( decode_binary_table(b"H 1 0 0 0 0 0 0 0 0 0 0 0"), Err(GateTableError::BadMagic(_)) ) );
+
+    let mut version = data.clone();
+    version[4] = (FORMAT_VERSION + 1) as u8;
+    assert!( matches!( decode_binary_table(&version), Err(GateTableError::UnsupportedVersion(_)) ) );
+
+    let mut trailing = data.clone();
+    trailing.splice(data.len()-4..data.len()-4, [0, 0, 0]);
+    assert!( matches!( decode_binary_table(&with_new_checksum(trailing)), Err(GateTableError::TrailingBytes(3)) ) );
+
+    // Entries that disagree with the parameters
+    for wrong in [
+        TableParameters{ gate_set: "HT".to_string(), ..parameters.clone() },
+        TableParameters{ sde_bound: 2, ..parameters.clone() },
+        TableParameters{ gate_string_length: 3, ..parameters.clone() },
+    ]
+    {
+        assert!( matches!( decode_binary_table(&encode_binary_table(&residual, &wrong)), Err(GateTableError::BadEntry(_)) ) );
+    }
+
+    // Keys that no table makes: 2/√2^2 is 1 written another way, and keys whose
+    // sde does not fit in Int or whose denominators do not fit in LogDepInt
+    let big = Int::MAX/2;
+    for numbers in [
+        [2, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [big, big, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [1, 0, Int::from(LogDepInt::MAX), 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ]
+    {
+        let table = GateTable::from([(key_from_numbers(&numbers), "H".to_string())]);
+        assert!( matches!( decode_binary_table(&encode_binary_table(&table, &parameters)), Err(GateTableError::BadEntry(_)) ) );
+    }
+
+    // Errors turn into io errors for TableSource
+    let missing = load_hash_table(&TableSource::Binary("no/such/table.bin".to_string()));
+    assert_eq!( missing.unwrap_err().kind(), std::io::ErrorKind::NotFound );
+    let error: std::io::Error = GateTableError::Truncated.into();
+    assert_eq!( error.kind(), std::io::ErrorKind::InvalidData );
+}
+
+
+#[test]
+pub fn text_tables_convert_to_binary()
+{
+    let table = generate_hash_table();
+    let text_filename = temp_file("grid_synthesis_legacy_table_test.dat");
+    let binary_filename = temp_file("grid_synthesis_legacy_table_test.bin");
+    save_hash_table(&table, &text_filename).unwrap();
+
+    let parameters = TableParameters::residual_table(&table);
+    let converted = convert_text_table(&text_filename, &binary_filename, &parameters).unwrap();
+    assert_eq!( converted, table );
+    assert_eq!( load_hash_table(&TableSource::Binary(binary_filename)).unwrap(), table );
+
+    // The strings have T in them
+    let wrong = TableParameters{ gate_set: "H".to_string(), ..parameters.clone() };
+    let result = convert_text_table(&text_filename, &temp_file("grid_synthesis_never_written.bin"), &wrong);
+    assert!( matches!( result, Err(GateTableError::BadEntry(_)) ) );
+
+    let result = convert_text_table("no/such/table.dat", &temp_file("grid_synthesis_never_written.bin"), &parameters);
+    assert!( matches!( result, Err(GateTableError::Io(_)) ) );
+}
@@ -0,0 +1,121 @@
+use crate::algorithms::generator_table::generate_table_with_generators;
+use crate::algorithms::generator_table::discover_relations;
+use crate::algorithms::generator_table::clifford_t_generators;
+use crate::algorithms::generator_table::Generator;
+use crate::algorithms::generator_table::free_generators_are_finite;
+use crate::algorithms::generator_table::GeneratorSearch;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_residual_table;
+use crate::algorithms::exact_synth_hashtable_lookup::RESIDUAL_SDE;
+use crate::algorithms::exact_synth::sde;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Gate;
+use crate::structs::circuit::Circuit;
+use crate::structs::rings::Int;
+
+
+// Every word in the table is a circuit for its key
+fn check_words(table: &crate::algorithms::exact_synth_hashtable_lookup::GateTable)
+{
+    for (key, value) in table.iter()
+    {
+        let circuit: Circuit = value.parse().unwrap();
+        assert_eq!( circuit.to_exact_unimat::<Int>().mat, *key );
+    }
+}
+
+fn cost(word: &str, generators: &[Generator]) -> u32
+{
+    return word.chars().filter_map(|letter| generators.iter().find(|g| g.name == letter.to_string())).map(|g| g.cost).sum();
+}
+
+
+#[test]
+pub fn relations_are_found_not_written_in()
+{
+    let h_and_t = vec![Generator::from_gate(Gate::H, 1), Generator::from_gate(Gate::T, 1)];
+    let relations = discover_relations(&h_and_t, 8);
+    assert!( relations.contains(&("HH".to_string(), "".to_string())) );
+    assert!( relations.contains(&("TTTTTTTT".to_string(), "".to_string())) );
+
+    // Every relation holds, and the other side is cheaper or shorter
+    for (word, cheaper) in relations.iter()
+    {
+        let left: ExactUniMat<Int> = ExactUniMat::from_string(&(word.to_string() + "I"));
+        let right: ExactUniMat<Int> = ExactUniMat::from_string(&(cheaper.to_string() + "I"));
+        assert!( left == right );
+        assert!( (cost(cheaper, &h_and_t), cheaper.len()) <= (cost(word, &h_and_t), word.len()) );
+    }
+
+    // With T^† for the price of T, T^7 is no longer the way to write it
+    let with_tdg = vec![Generator::from_gate(Gate::H, 1), Generator::from_gate(Gate::T, 1), Generator::from_gate(Gate::Tdg, 1)];
+    let relations = discover_relations(&with_tdg, 4);
+    assert!( relations.contains(&("Tt".to_string(), "".to_string())) );
+    assert!( relations.contains(&("tT".to_string(), "".to_string())) );
+}
+
+
+#[test]
+pub fn generator_tables_agree_with_the_residual_table()
+{
+    // H and S are free, so the cost is the T-count
+    let settings = GeneratorSearch{ sde_cutoff: RESIDUAL_SDE, max_cost: RESIDUAL_SDE as u32, report_progress: true };
+    let table = generate_table_with_generators(&clifford_t_generators(), &settings);
+    let residual = generate_residual_table();
+    assert_eq!( table.len(), residual.len() );
+    check_words(&table);
+    for (key, value) in table.iter()
+    {
+        let word: Circuit = value.parse().unwrap();
+        assert_eq!( word.t_count(), residual[key].parse::<Circuit>().unwrap().t_count() );
+        assert!( sde(*key) <= RESIDUAL_SDE );
+    }
+}
+
+
+#[test]
+pub fn generator_tables_follow_the_costs()
+{
+    // Some hardware where T is expensive and X is cheap
+    let hardware = vec![
+        Generator::from_gate(Gate::H, 2),
+        Generator::from_gate(Gate::S, 2),
+        Generator::from_gate(Gate::T, 10),
+        Generator::from_gate(Gate::X, 1),
+    ];
+    let settings = GeneratorSearch{ sde_cutoff: 3, max_cost: 40, report_progress: false };
+    let table = generate_table_with_generators(&hardware, &settings);
+    check_words(&table);
+
+    // Y is SX up to the phase on the second column
+    let y = Gate::Y.to_exact_unimat::<Int>().mat;
+    assert_eq!( table[&y], "SX" );
+    let x = Gate::X.to_exact_unimat::<Int>().mat;
+    assert_eq!( table[&x], "X" );
+
+    // With T^† at the same price, words never cost more
+    let mut with_tdg = hardware.clone();
+    with_tdg.push(Generator::from_gate(Gate::Tdg, 10));
+    let bigger = generate_table_with_generators(&with_tdg, &settings);
+    check_words(&bigger);
+    for (key, value) in table.iter()
+    {
+        assert!( cost(&bigger[key], &with_tdg) <= cost(value, &hardware) );
+    }
+
+    // Any exact unitary can be a generator, under any name
+    let named = vec![Generator::new("h", Gate::H.to_exact_unimat(), 1), Generator::new("sqrtX", ExactUniMat::from_string(&"HSH".to_string()), 1)];
+    let table = generate_table_with_generators(&named, &GeneratorSearch{ sde_cutoff: 2, max_cost: 4, report_progress: false });
+    let sqrt_x = ExactUniMat::<Int>::from_string(&"HSH".to_string()).mat;
+    assert_eq!( table[&sqrt_x], "sqrtX" );
+}
+
+
+#[test]
+pub fn free_generators_have_to_make_a_finite_group()
+{
+    // The Clifford group is as big as it gets, and T makes an infinite group
+    assert!( free_generators_are_finite(&clifford_t_generators()) );
+    let free_t = vec![Generator::from_gate(Gate::H, 0), Generator::from_gate(Gate::T, 0)];
+    assert!( !free_generators_are_finite(&free_t) );
+    assert!( std::panic::catch_unwind(|| discover_relations(&free_t, 1)).is_err() );
+}
@@ -242,7 +242,7 @@ pub fn random_inexact_synth_test()
 
     println!("(direction ,epsilon) = ({},{})",direction,epsilon );
 
-    let answer = grid_problem(direction,epsilon);
+    let answer = grid_problem::<Int>(direction,epsilon);
     println!("answer =\n {}",answer );
     println!("as a float gate = \n{}",answer.to_float_gate_upto_t_count() );
 
@@ -257,7 +257,7 @@ pub fn inexact_synth_testing()
 {
     // grid_problem_given_depth(0, (Comp::one(), 0.19));
 
-    let answer = grid_problem(Comp::one(), 0.020);
+    let answer = grid_problem::<Int>(Comp::one(), 0.020);
 
     println!("{}", answer.mat);
     println!("{}", answer.omega_exp);
@@ -0,0 +1,133 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::LocalizableNorm;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::unimat::ExactUniMat;
+use crate::algorithms::exact_synth::partial_exact_synth_given_norm_1;
+use crate::algorithms::exact_synth::sde;
+use crate::algorithms::local_prime_factorization::attempt_to_write_this_number_as_sum_of_two_squares_in_loc;
+use crate::algorithms::inexact_synth::grid_problem_given_depth;
+use crate::algorithms::inexact_synth::grid_problem_counting_overflows;
+
+use num_traits::Pow;
+use num_complex::Complex;
+
+type BigLoc = Local<Zroot2<BigInt>>;
+
+
+// Numbers way out of reach of i128 once multiplied a couple of times
+pub fn big(x: Int) -> BigInt
+{
+    let two = BigInt::from(2);
+    return two.pow(70u32) * BigInt::from(x) + BigInt::from(x*x + 1);
+}
+
+// A long gate sequence whose sde is well past what i128 can handle
+pub fn long_gate_string(number_of_h: usize) -> String
+{
+    let mut seq = "".to_string();
+    for i in 0..number_of_h
+    {
+        seq.push_str("H");
+        for _ in 0..(i*7 % 3 + 1)
+        {
+            seq.push_str("T");
+        }
+    }
+    return seq;
+}
+
+
+#[test]
+pub fn bigint_norms_are_multiplicative()
+{
+    let a = Zroot2(big(3), big(-5));
+    let b = Zroot2(big(-7), big(2));
+    assert_eq!( (a.clone()*b.clone()).norm(), a.norm()*b.norm() );
+
+    let x = Zomega(big(1), big(-2), big(3), big(5));
+    let y = Zomega(big(4), big(0), big(-1), big(2));
+    assert_eq!( (x.clone()*y.clone()).norm(), x.norm()*y.norm() );
+}
+
+
+#[test]
+pub fn bigint_exact_divisions()
+{
+    let a = Zroot2(big(3), big(-5));
+    let b = Zroot2(big(-7), big(2));
+    assert_eq!( (a.clone()*b.clone())/b, a );
+
+    let x = Zomega(big(1), big(-2), big(3), big(5));
+    let y = Zomega(big(4), big(0), big(-1), big(2));
+    assert_eq!( (x.clone()*y.clone())/y, x );
+
+    let p = BigLoc::from_base(Zroot2(big(9), big(4)));
+    let q = BigLoc::from_base(Zroot2(big(2), big(1)));
+    assert_eq!( (p.clone()*q.clone())/q, p );
+}
+
+
+#[test]
+pub fn switching_backends_round_trips()
+{
+    let gate = ExactUniMat::<Int>::from_string(&"HTHTTHTTTH".to_string());
+    let big_gate = gate.to_backend::<BigInt>().unwrap();
+    assert_eq!( big_gate, ExactUniMat::<BigInt>::from_string(&"HTHTTHTTTH".to_string()) );
+    assert_eq!( big_gate.to_backend::<Int>().unwrap(), gate );
+
+    let too_big = Zroot2(big(1)*big(1), big(1));
+    assert_eq!( too_big.to_backend::<Int>(), None );
+}
+
+
+#[test]
+pub fn bigint_sum_of_two_squares()
+{
+    // 3 + 2*sqrt2 = (1 + sqrt2)^2 is a unit and hence a sum of two squares
+    let our_num = BigLoc::from_base(Zroot2(BigInt::from(3), BigInt::from(2)));
+    let (left, right) = attempt_to_write_this_number_as_sum_of_two_squares_in_loc(our_num.clone()).unwrap().unwrap();
+    assert_eq!( left.clone()*left + right.clone()*right, our_num );
+}
+
+
+#[test]
+pub fn bigint_partial_exact_synth_on_long_sequences()
+{
+    let seq = long_gate_string(200);
+    let gate = ExactUniMat::<BigInt>::from_string(&seq);
+    assert!( sde(gate.mat.clone()) > 100 );
+
+    let (found_seq, residue) = partial_exact_synth_given_norm_1(gate.mat.clone());
+    // The reduction loop stops as soon as it is one step away from sde 3
+    assert!( sde(residue.clone()) <= 4 );
+
+    // Whatever is left over should be small enough for the lookup tables
+    assert!( residue.to_backend::<Int>().is_some() );
+
+    // What the found sequence does not account for is again a small gate
+    let found_gate = ExactUniMat::<BigInt>::from_string(&found_seq);
+    let difference = found_gate.inv() * gate;
+    assert!( sde(difference.mat.clone()) <= 4 );
+    assert!( difference.to_backend::<Int>().is_some() );
+}
+
+
+#[test]
+pub fn bigint_grid_problem_at_small_epsilon()
+{
+    // These are found at depths 80 and 85, where the norms to factor no longer fit in u128
+    for (angle, epsilon) in [(2.0, 1e-6), (2.5, 5e-7)]
+    {
+        let direction = Complex::new(0.0, angle).exp();
+        let (gate, overflow_count) = grid_problem_counting_overflows::<BigInt>(direction, epsilon);
+        assert_eq!( overflow_count, 0 );
+        assert!( sde(gate.mat.clone()) >= 80 );
+
+        let float_gate = gate.to_float_gate_upto_t_count();
+        assert!( (float_gate.u.norm_sqr() + float_gate.t.norm_sqr() - 1.0).abs() < 1e-12 );
+        assert!( (float_gate.u - direction).norm() < epsilon );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::Conj;
+use crate::structs::rings::Localizable;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::unimat::ExactUniMat;
+use crate::algorithms::exact_synth::sde;
+use crate::algorithms::exact_synth::multiply_h_times_t_to_n;
+use crate::algorithms::exact_synth::multiply_h_times_t_to_n_dyadic;
+use crate::algorithms::exact_synth::partial_exact_synth_dyadic;
+use crate::tests::euclidean_domain_tests::random_zroot2;
+use crate::tests::euclidean_domain_tests::random_zomega;
+use crate::tests::int_backend_tests::long_gate_string;
+
+use num_complex::Complex;
+use num_traits::One;
+
+use rand::thread_rng;
+use rand::Rng;
+
+type Loc = Local<Zroot2>;
+type Comp = Complex<Loc>;
+type DLoc = Local<Zomega>;
+
+
+pub fn random_loc() -> Loc
+{
+    let mut rng = thread_rng();
+    return Loc::from_base(random_zroot2(1000))*Loc{ num: Zroot2::one(), log_den: rng.gen_range(-5..20) };
+}
+
+pub fn random_dloc() -> DLoc
+{
+    let mut rng = thread_rng();
+    return DLoc::from_base(random_zomega(1000))*DLoc{ num: Zomega::one(), log_den: rng.gen_range(-5..20) };
+}
+
+
+#[test]
+pub fn dividing_by_one_plus_omega_undoes_multiplying()
+{
+    let delta = Zomega(1, 1, 0, 0);
+    assert!( delta.is_divisible() );
+    assert!( !Zomega::<Int>::one().is_divisible() );
+    assert_eq!( Zomega(2, 0, 0, 0).reduce_by_dividing().1, 4 );
+
+    for _ in 0..100
+    {
+        let (x, _) = random_zomega(1000).reduce_by_dividing();
+        for n in 0..10
+        {
+            assert_eq!( x.perform_n_multiplications(n).reduce_by_dividing(), (x, n) );
+            assert_eq!( x.checked_perform_n_multiplications(n), Ok(x.perform_n_multiplications(n)) );
+        }
+    }
+}
+
+
+#[test]
+pub fn converting_to_and_from_complex_is_lossless()
+{
+    for _ in 0..100
+    {
+        let z = Comp::new(random_loc(), random_loc());
+        let w = Comp::new(random_loc(), random_loc());
+        assert_eq!( Comp::from(DLoc::from(z)), z );
+
+        // Both conversions are ring homomorphisms
+        assert_eq!( DLoc::from(z*w), DLoc::from(z)*DLoc::from(w) );
+        assert_eq!( DLoc::from(z+w), DLoc::from(z)+DLoc::from(w) );
+
+        let x = random_dloc();
+        assert_eq!( DLoc::from(Comp::from(x)), x );
+    }
+}
+
+
+#[test]
+pub fn conjugations_on_local_zomega()
+{
+    for _ in 0..100
+    {
+        let z = Comp::new(random_loc(), random_loc());
+        let x = DLoc::from(z);
+        let y = random_dloc();
+
+        assert_eq!( x.conj(), DLoc::from(z.conj()) );
+        assert_eq!( (x*y).conj(), x.conj()*y.conj() );
+
+        // σ fixes i and conjugates √2
+        assert_eq!( x.conj_rt2(), DLoc::from(Comp::new(z.re.conj(), z.im.conj())) );
+        assert_eq!( x.conj_rt2().conj_rt2(), x );
+        assert_eq!( (x*y).conj_rt2(), x.conj_rt2()*y.conj_rt2() );
+    }
+}
+
+
+#[test]
+pub fn norm_and_sde_on_local_zomega()
+{
+    let delta = DLoc::from_base(Zomega(1, 1, 0, 0));
+    assert_eq!( delta.norm(), Local::<Int>::from_base(2) );
+    assert_eq!( DLoc::one().norm(), Local::<Int>::one() );
+    assert_eq!( format!("{}", DLoc::one()/delta), "(1+ω)^(-1)" );
+
+    let mut rng = thread_rng();
+    for _ in 0..100
+    {
+        // Norms are quartic in the entries, so keep them small enough for Int
+        let scale = Loc{ num: Zroot2::one(), log_den: rng.gen_range(-2..10) };
+        let z = Comp::new(Loc::from_base(random_zroot2(100))*scale, Loc::from_base(random_zroot2(100))*scale);
+        let x = DLoc::from(z);
+        let y = DLoc::from_base(random_zomega(100))*DLoc{ num: Zomega::one(), log_den: rng.gen_range(-2..10) };
+        assert_eq!( (x*y).norm(), x.norm()*y.norm() );
+
+        // The norm down to Q factors through the norm down to Q(√2)
+        assert_eq!( x.norm(), z.norm_sqr().norm() );
+    }
+
+    for i in 1..30
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+        assert_eq!( gate.to_dyadic().sde(), sde(gate.mat) );
+    }
+}
+
+
+#[test]
+pub fn exact_gates_with_dyadic_entries()
+{
+    for i in 1..30
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+        let other = ExactUniMat::<Int>::from_string(&long_gate_string(30-i));
+
+        assert_eq!( ExactUniMat::from_dyadic(gate.to_dyadic(), gate.omega_exp), gate );
+        assert_eq!( (gate.mat*other.mat).to_dyadic(), gate.to_dyadic()*other.to_dyadic() );
+        assert_eq!( gate.to_dyadic().det(), DLoc::one() );
+        assert_eq!( gate.to_dyadic().inv().to_kmm(), gate.mat.inv() );
+    }
+}
+
+
+#[test]
+pub fn exact_synth_reduction_on_dyadic_entries()
+{
+    for i in 1..30
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+
+        for n in -3..8
+        {
+            let stepped = multiply_h_times_t_to_n_dyadic(gate.to_dyadic(), n);
+            assert_eq!( stepped.to_kmm(), multiply_h_times_t_to_n(gate.mat, n) );
+        }
+
+        let (found_seq, residue) = partial_exact_synth_dyadic(gate.to_dyadic());
+        assert!( residue.sde() <= 3 );
+
+        // What the found sequence does not account for is again a small gate
+        let found_gate = ExactUniMat::<Int>::from_string(&found_seq);
+        assert!( sde((found_gate.inv()*gate).mat) <= 3 );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::algorithms::mapped_gate_table::MappedGateTable;
+use crate::algorithms::mapped_gate_table::save_mapped_table;
+use crate::algorithms::mapped_gate_table::write_mapped_table;
+use crate::algorithms::mapped_gate_table::write_mapped_table_in_runs;
+use crate::algorithms::mapped_gate_table::canonical_key;
+use crate::algorithms::gate_table_file::GateTableError;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_residual_table;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::load_hash_table;
+use crate::algorithms::exact_synth_hashtable_lookup::open_gate_table;
+use crate::algorithms::exact_synth_hashtable_lookup::TableSource;
+use crate::algorithms::exact_synth::apply_gate_string_to_state;
+
+use crate::structs::rings::zroot2::Zroot2;
+type Loc = crate::structs::rings::local_ring::Local::<Zroot2>;
+use num_complex::Complex;
+type ExactGate = crate::structs::sunimat::SUniMat<Complex<Loc>>;
+
+
+fn temp_file(name: &str) -> String
+{
+    return std::env::temp_dir().join(name).to_str().unwrap().to_string();
+}
+
+
+#[test]
+pub fn mapped_tables_find_the_same_words()
+{
+    for (table, name) in [(generate_residual_table(), "grid_synthesis_residual_table_test.map"), (generate_hash_table(), "grid_synthesis_legacy_table_test.map")]
+    {
+        let filename = temp_file(name);
+        assert_eq!( save_mapped_table(&table, &filename).unwrap(), table.len() );
+
+        let mapped = MappedGateTable::open(&filename).unwrap();
+        assert_eq!( mapped.len(), table.len() );
+        for (key, word) in table.iter()
+        {
+            assert_eq!( mapped.get(key), Some(word.as_str()) );
+        }
+        assert_eq!( mapped.to_gate_table().unwrap(), table );
+        assert_eq!( load_hash_table(&TableSource::Mapped(filename.clone())).unwrap(), table );
+
+        // Through the same interface as exact_synth
+        let opened = open_gate_table(&TableSource::Mapped(filename)).unwrap();
+        assert_eq!( opened.len(), table.len() );
+        let (key, word) = table.iter().next().unwrap();
+        assert_eq!( opened.get(key), Some(word.as_str()) );
+    }
+
+    // Gates with large sde are in neither table
+    let residual = generate_residual_table();
+    let filename = temp_file("grid_synthesis_residual_table_test.map");
+    let mapped = MappedGateTable::open(&filename).unwrap();
+    for word in ["HTHTHTHTHTH", "THTHTHTHTHTHT"]
+    {
+        let gate = apply_gate_string_to_state(word.to_string(), ExactGate::one());
+        assert_eq!( mapped.get(&gate), None );
+        assert_eq!( residual.get(&gate), None );
+    }
+}
+
+
+#[test]
+pub fn mapped_tables_sorted_in_runs()
+{
+    // Small runs, so the records are merged from several temporary files
+    let table = generate_residual_table();
+    let filename = temp_file("grid_synthesis_runs_table_test.map");
+    let entries = table.iter().map(|(key, word)| (*key, word.clone()));
+    assert_eq!( write_mapped_table_in_runs(entries, &filename, 7).unwrap(), table.len() );
+
+    let mapped = MappedGateTable::open(&filename).unwrap();
+    assert_eq!( mapped.to_gate_table().unwrap(), table );
+    for (key, word) in table.iter()
+    {
+        assert_eq!( mapped.get(key), Some(word.as_str()) );
+    }
+
+    // The runs are gone
+    assert!( !std::path::Path::new(&format!("{}.run0", filename)).exists() );
+}
+
+
+#[test]
+pub fn mapped_tables_handle_shared_keys()
+{
+    // Keys of different gates can only collide by chance, so check that
+    // equal records are all looked at by writing a gate twice
+    let residual = generate_residual_table();
+    let mut entries: Vec<(ExactGate, String)> = residual.iter().take(2).map(|(key, word)| (*key, word.clone())).collect();
+    let (a, b) = (entries[0].0, entries[1].0);
+    assert_ne!( canonical_key(&a), canonical_key(&b) );
+    entries.push(entries[1].clone());
+
+    let filename = temp_file("grid_synthesis_small_table_test.map");
+    assert_eq!( write_mapped_table(entries, &filename).unwrap(), 3 );
+    let mapped = MappedGateTable::open(&filename).unwrap();
+    assert_eq!( mapped.get(&a), residual.get(&a).map(|word| word.as_str()) );
+    assert_eq!( mapped.get(&b), residual.get(&b).map(|word| word.as_str()) );
+    let (other, _) = residual.iter().nth(2).unwrap();
+    assert_eq!( mapped.get(other), None );
+
+    let filename = temp_file("grid_synthesis_empty_table_test.map");
+    write_mapped_table(Vec::new(), &filename).unwrap();
+    let empty = MappedGateTable::open(&filename).unwrap();
+    assert!( empty.is_empty() );
+    assert_eq!( empty.get(&a), None );
+}
+
+
+#[test]
+pub fn broken_mapped_tables_give_errors()
+{
+    let filename = temp_file("grid_synthesis_broken_table_test.map");
+    save_mapped_table(&generate_residual_table(), &filename).unwrap();
+    let data = std::fs::read(&filename).unwrap();
+
+    let open = |bytes: &[u8]|
+    {
+        let broken = temp_file("grid_synthesis_broken_table_test_copy.map");
+        std::fs::write(&broken, bytes).unwrap();
+        return MappedGateTable::open(&broken).map(|table| table.len());
+    };
+    assert_eq!( open(&data).unwrap(), generate_residual_table().len() );
+
+    let mut magic = data.clone();
+    magic[0] = b'X';
+    assert!( matches!( open(&magic), Err(GateTableError::BadMagic(_)) ) );
+    assert!( matches!( open(b"GSMT"), Err(GateTableError::Truncated) ) );
+
+    let mut version = data.clone();
+    version[4] = 2;
+    assert!( matches!( open(&version), Err(GateTableError::UnsupportedVersion(2)) ) );
+
+    for length in [0, 10, 23, data.len() - 1]
+    {
+        assert!( matches!( open(&data[..length]), Err(GateTableError::Truncated) ) );
+    }
+    let mut trailing = data.clone();
+    trailing.extend_from_slice(&[0, 0, 0]);
+    assert!( matches!( open(&trailing), Err(GateTableError::TrailingBytes(3)) ) );
+
+    // Opening does not read the entries, so a bad record is only found by to_gate_table
+    let mut swapped = data.clone();
+    let end = data.len();
+    swapped[end-32..end-16].copy_from_slice(&data[end-16..end]);
+    swapped[end-16..end].copy_from_slice(&data[end-32..end-16]);
+    std::fs::write(&filename, &swapped).unwrap();
+    let table = MappedGateTable::open(&filename).unwrap();
+    assert!( matches!( table.to_gate_table(), Err(GateTableError::BadEntry(_)) ) );
+
+    let missing = open_gate_table(&TableSource::Mapped("no/such/table.map".to_string()));
+    assert_eq!( missing.err().unwrap().kind(), std::io::ErrorKind::NotFound );
+}
@@ -9,3 +9,22 @@ pub mod prime_factorization_tests;
 pub mod matrix_tests;
 pub mod exact_gate_tests;
 pub mod lll_tests;
+pub mod int_backend_tests;
+pub mod checked_arithmetic_tests;
+pub mod euclidean_domain_tests;
+pub mod local_zomega_tests;
+pub mod quaternion_tests;
+pub mod exact_ordering_tests;
+pub mod parsing_tests;
+pub mod pow_tests;
+pub mod unit_group_tests;
+pub mod galois_tests;
+pub mod zzeta16_tests;
+pub mod v_basis_tests;
+pub mod circuit_tests;
+pub mod normal_form_tests;
+pub mod clifford_tests;
+pub mod gate_table_file_tests;
+pub mod mapped_gate_table_tests;
+pub mod generator_table_tests;
+pub mod nearest_gate_tests;
@@ -0,0 +1,126 @@
+use crate::algorithms::nearest_gate::NearestGateTable;
+use crate::algorithms::nearest_gate::distance_upto_phase;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_table_breadth_first;
+use crate::algorithms::exact_synth_hashtable_lookup::generate_residual_table;
+use crate::algorithms::exact_synth_hashtable_lookup::TableGeneration;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Circuit;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::rings::Float;
+use crate::structs::rings::Int;
+
+use num_complex::Complex;
+use rand::thread_rng;
+use rand::Rng;
+
+type FloatGate = SUniMat<Complex<Float>>;
+
+
+fn random_float_gate() -> FloatGate
+{
+    let mut rng = thread_rng();
+    let point: Vec<Float> = (0..4).map(|_| rng.gen_range(-1.0..1.0)).collect();
+    let norm = point.iter().map(|x| x*x).sum::<Float>().sqrt();
+    return FloatGate{ u: Complex{ re: point[0]/norm, im: point[1]/norm }, t: Complex{ re: point[2]/norm, im: point[3]/norm } };
+}
+
+// The whole unitary of the word, phase included
+fn float_gate_of(word: &str) -> FloatGate
+{
+    let circuit: Circuit = word.parse().unwrap();
+    return circuit.to_exact_unimat::<Int>().to_float_special_unitary();
+}
+
+
+#[test]
+pub fn nearest_gates_agree_with_looking_at_everything()
+{
+    let table = generate_table_breadth_first(&TableGeneration::new(6));
+    let index = NearestGateTable::new(&table);
+    // Every key with each of its 8 phases
+    assert_eq!( index.len(), 8*table.len() );
+    let everything: Vec<FloatGate> = table.values().flat_map(|word| (0..8).map(move |power| float_gate_of(&(word.clone() + &"T".repeat(power))))).collect();
+
+    for _ in 0..20
+    {
+        let target = random_float_gate();
+        let mut distances: Vec<Float> = everything.iter().map(|gate| distance_upto_phase(&target, gate)).collect();
+        distances.sort_by(|a, b| a.total_cmp(b));
+
+        let nearest = index.nearest(&target, 5);
+        assert_eq!( nearest.len(), 5 );
+        let mut found: Vec<Float> = nearest.iter().map(|gate| gate.distance).collect();
+        found.sort_by(|a, b| a.total_cmp(b));
+        assert_eq!( found, distances[..5].to_vec() );
+
+        // Each word is as far as it says
+        for gate in nearest.iter()
+        {
+            assert!( (distance_upto_phase(&target, &float_gate_of(&gate.word)) - gate.distance).abs() < 1e-9 );
+        }
+
+        let epsilon = distances[10];
+        let within = index.within(&target, epsilon);
+        assert_eq!( within.len(), distances.iter().filter(|d| **d <= epsilon).count() );
+
+        // Sorted by T-count
+        assert!( within.windows(2).all(|pair| (pair[0].t_count, pair[0].distance) <= (pair[1].t_count, pair[1].distance)) );
+        assert!( nearest.windows(2).all(|pair| pair[0].t_count <= pair[1].t_count) );
+    }
+}
+
+
+#[test]
+pub fn table_entries_are_their_own_nearest_gates()
+{
+    let table = generate_residual_table();
+    let index = NearestGateTable::new(&table);
+
+    for word in ["I", "H", "TH", "HTHTSH"]
+    {
+        let target = float_gate_of(word);
+        let nearest = index.nearest(&target, 1);
+        assert!( nearest[0].distance < 1e-12 );
+        assert!( distance_upto_phase(&float_gate_of(&nearest[0].word), &target) < 1e-12 );
+    }
+
+    // Up to a global phase of -1
+    let h = float_gate_of("H");
+    let minus_h = FloatGate{ u: -h.u, t: -h.t };
+    assert!( distance_upto_phase(&h, &minus_h) < 1e-12 );
+
+    assert!( index.nearest(&h, 0).is_empty() );
+    // The table has the matrices of H and -H, and both are H up to phase
+    let exactly_h = index.within(&h, 0.0);
+    assert_eq!( exactly_h.len(), 2 );
+    assert!( exactly_h.iter().all(|gate| gate.distance == 0.0 && gate.t_count == 0) );
+    assert_eq!( index.nearest(&h, index.len() + 3).len(), index.len() );
+    assert!( NearestGateTable::new(&Default::default()).nearest(&h, 3).is_empty() );
+}
+
+
+#[test]
+pub fn the_words_found_are_as_far_as_they_say()
+{
+    // T and I have the same key, but T is not I
+    let t = float_gate_of("T");
+    assert!( (distance_upto_phase(&t, &float_gate_of("I")) - 2.0*(std::f64::consts::PI/16.0).sin()).abs() < 1e-12 );
+
+    let table = generate_residual_table();
+    let index = NearestGateTable::new(&table);
+    let exactly_t = index.within(&t, 1e-12);
+    assert!( !exactly_t.is_empty() );
+    assert!( exactly_t.iter().all(|gate| gate.t_count == 1) );
+
+    // The returned word is evaluated from scratch, and compared with a
+    // target that is given as a full unitary, not a point
+    for _ in 0..20
+    {
+        let target = random_float_gate();
+        for gate in index.nearest(&target, 3).iter()
+        {
+            let unitary = ExactUniMat::<Int>::from_string(&(gate.word.clone() + "I")).to_float_special_unitary();
+            assert!( (distance_upto_phase(&target, &unitary) - gate.distance).abs() < 1e-9 );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::circuit::Gate;
+use crate::structs::circuit::Circuit;
+use crate::algorithms::normal_form::normal_form;
+use crate::algorithms::normal_form::normal_form_of_circuit;
+use crate::algorithms::normal_form::same_unitary;
+use crate::algorithms::normal_form::bloch_lde;
+use crate::algorithms::normal_form::Syllable;
+use crate::algorithms::exact_synth::exact_synth;
+use crate::tests::circuit_tests::random_circuit;
+use crate::tests::int_backend_tests::long_gate_string;
+
+use rand::thread_rng;
+use rand::Rng;
+
+
+// A random word in (T|ε)(HT|SHT)*, which is already in normal form
+pub fn random_normal_form_word(number_of_syllables: usize) -> String
+{
+    let mut rng = thread_rng();
+    let mut word = if rng.gen_bool(0.5) { "T".to_string() } else { "".to_string() };
+    for _ in 0..number_of_syllables
+    {
+        word.push_str(if rng.gen_bool(0.5) { "HT" } else { "SHT" });
+    }
+    return word;
+}
+
+
+#[test]
+pub fn normal_forms_are_the_same_unitary()
+{
+    for i in 0..40
+    {
+        let circuit = random_circuit(i);
+        let form = normal_form_of_circuit(&circuit);
+        assert_eq!( form.to_exact_unimat::<BigInt>(), circuit.to_exact_unimat() );
+
+        // Normal forms are T-optimal
+        assert!( form.t_count() <= circuit.t_count() );
+        assert_eq!( form.t_count() as i32, bloch_lde(&circuit.to_exact_unimat::<BigInt>()) );
+
+        // and a fixed point
+        assert_eq!( normal_form_of_circuit(&form.to_circuit()), form );
+
+        // The Clifford is only H and S
+        assert!( form.clifford.iter().all(|g| *g == Gate::H || *g == Gate::S) );
+    }
+}
+
+
+#[test]
+pub fn words_in_normal_form_come_back_unchanged()
+{
+    for i in 0..30
+    {
+        let word = random_normal_form_word(i);
+        let form = normal_form(&ExactUniMat::<BigInt>::from_string(&word));
+        assert_eq!( form.to_circuit().to_string(), word );
+        assert_eq!( form.t_count(), word.matches('T').count() );
+        assert_eq!( form.leading_t, word.starts_with('T') );
+    }
+
+    let form = normal_form(&ExactUniMat::<Int>::from_string(&"THTSHTH".to_string()));
+    assert!( form.leading_t );
+    assert_eq!( form.syllables, vec![Syllable::HT, Syllable::SHT] );
+    assert_eq!( form.clifford.to_string(), "H" );
+}
+
+
+#[test]
+pub fn equal_unitaries_have_equal_normal_forms()
+{
+    let circuit = |s: &str| s.parse::<Circuit>().unwrap();
+    assert!( same_unitary(&circuit("HH"), &circuit("")) );
+    assert!( same_unitary(&circuit("TT"), &circuit("S")) );
+    assert!( same_unitary(&circuit("HSSH"), &circuit("X")) );
+    assert!( same_unitary(&circuit("XYZ"), &circuit("WW")) );
+    assert!( !same_unitary(&circuit("XYZ"), &circuit("")) );
+    assert!( !same_unitary(&circuit("HT"), &circuit("TH")) );
+
+    // Different spellings from exact_synth and from the input string
+    for i in 1..15
+    {
+        let word = long_gate_string(i);
+        let gate = ExactUniMat::<Int>::from_string(&word);
+        assert!( same_unitary(&exact_synth(gate), &word.parse().unwrap()) );
+        assert_eq!( normal_form(&gate), normal_form_of_circuit(&exact_synth(gate)) );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::unimat::ExactUniMat;
+use crate::tests::euclidean_domain_tests::random_zroot2;
+use crate::tests::euclidean_domain_tests::random_zomega;
+use crate::tests::local_zomega_tests::random_loc;
+use crate::tests::local_zomega_tests::random_dloc;
+use crate::tests::int_backend_tests::big;
+use crate::tests::int_backend_tests::long_gate_string;
+
+use std::str::FromStr;
+
+type Loc = Local<Zroot2>;
+type DLoc = Local<Zomega>;
+
+
+#[test]
+pub fn ring_elements_round_trip_through_strings()
+{
+    for _ in 0..200
+    {
+        let x = random_zroot2(1000);
+        assert_eq!( x.to_string().parse(), Ok(x) );
+
+        let y = random_zomega(1000);
+        assert_eq!( y.to_string().parse(), Ok(y) );
+
+        let p = random_loc();
+        assert_eq!( p.to_string().parse(), Ok(p) );
+
+        let q = random_dloc();
+        assert_eq!( q.to_string().parse(), Ok(q) );
+    }
+
+    let x = Local::from_base(Zroot2(big(3), big(-5)));
+    assert_eq!( x.to_string().parse::<Local<Zroot2<BigInt>>>(), Ok(x) );
+}
+
+
+#[test]
+pub fn other_spellings_of_ring_elements()
+{
+    assert_eq!( "3-2*sqrt2".parse(), Ok(Zroot2(3 as Int, -2)) );
+    assert_eq!( " 4 + 5 √2 ".parse(), Ok(Zroot2(4 as Int, 5)) );
+    assert_eq!( "-sqrt(2)".parse(), Ok(Zroot2(0 as Int, -1)) );
+
+    assert_eq!( "(1,-2,0,3)".parse(), Ok(Zomega(1 as Int, -2, 0, 3)) );
+    assert_eq!( "omega^3 - ω^5".parse(), Ok(Zomega(0 as Int, 1, 0, 1)) );
+    assert_eq!( "sqrt2".parse(), Ok(Zomega(0 as Int, 1, 0, -1)) );
+
+    let expected = Loc::from_base(Zroot2(3, 2))*Loc{ num: Zroot2(1, 0), log_den: 5 };
+    assert_eq!( Loc::from_str("3+2√2 / √2^5"), Ok(expected) );
+    assert_eq!( Loc::from_str("(3+2*sqrt2)*sqrt2^(-5)"), Ok(expected) );
+    assert_eq!( Loc::from_str("sqrt2^-2"), Ok(Loc{ num: Zroot2(1, 0), log_den: 2 }) );
+    assert_eq!( DLoc::from_str("(1+omega)^(-3)"), Ok(DLoc{ num: Zomega(1, 0, 0, 0), log_den: 3 }) );
+}
+
+
+#[test]
+pub fn bad_strings_are_rejected()
+{
+    assert!( Zroot2::<Int>::from_str("3+x√2").is_err() );
+    assert!( Zroot2::<Int>::from_str("").is_err() );
+    assert!( Zomega::<Int>::from_str("(1,2,3)").is_err() );
+    assert!( Loc::from_str("3+2√2*√2^(-1)").is_err() );
+    assert!( ExactUniMat::<Int>::from_str("[[1 + 0i, 0 + 0i], [0 + 0i, 2 + 0i]]").is_err() );
+}
+
+
+#[test]
+pub fn exact_gates_round_trip_through_strings()
+{
+    for i in 1..30
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+        for _ in 0..(i%4)
+        {
+            let gate = gate*ExactUniMat::t_gate();
+            assert_eq!( gate.to_matrix_string().parse(), Ok(gate) );
+        }
+        assert_eq!( gate.to_matrix_string().parse(), Ok(gate) );
+    }
+
+    let identity = ExactUniMat::<Int>::one();
+    assert_eq!( ExactUniMat::from_str("[[1 + 0i, 0 + 0i], [0 + 0i, 1 + 0i]]"), Ok(identity) );
+}
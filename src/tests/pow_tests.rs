@@ -0,0 +1,97 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::pow;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::rings::special_values::sqrt2loc;
+use crate::structs::rings::special_values::omega;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::unimat::ExactUniMat;
+use crate::tests::euclidean_domain_tests::random_zroot2;
+use crate::tests::euclidean_domain_tests::random_zomega;
+use crate::tests::int_backend_tests::long_gate_string;
+
+use num_complex::Complex;
+use num_traits::One;
+use num_traits::Pow;
+
+use rand::thread_rng;
+use rand::Rng;
+
+type Loc = Local<Zroot2>;
+
+
+#[test]
+pub fn powers_agree_with_repeated_multiplication()
+{
+    let mut rng = thread_rng();
+    for _ in 0..20
+    {
+        // Small entries, so the twelfth powers stay within Int
+        let z = Loc::from_base(random_zroot2(10))*Loc{ num: Zroot2::one(), log_den: rng.gen_range(-2..5) };
+        let (x, y) = (random_zroot2(10), random_zomega(10));
+        let (mut xn, mut yn, mut zn) = (Zroot2::one(), Zomega::one(), Loc::one());
+        for n in 0..12u32
+        {
+            assert_eq!( x.pow(n), xn );
+            assert_eq!( y.pow(n), yn );
+            assert_eq!( z.pow(n), zn );
+            assert_eq!( z.pow(n as i32), zn );
+            assert_eq!( pow(x, n as Int), xn );
+            xn = xn*x;
+            yn = yn*y;
+            zn = zn*z;
+        }
+    }
+}
+
+
+#[test]
+pub fn negative_powers_of_units()
+{
+    let lambda = Zroot2(1 as Int, 1);
+    assert_eq!( lambda.pow(-1), Zroot2(-1 as Int, 1) );
+    for k in 0..30
+    {
+        assert_eq!( lambda.pow(-k)*lambda.pow(k), Zroot2::one() );
+        assert_eq!( omega::<Int>().pow(-k), omega().pow(8*k-k) );
+    }
+
+    // Powers of √2 only move the denominator around
+    assert_eq!( sqrt2loc::<Int>().pow(-5), Loc{ num: Zroot2::one(), log_den: 5 } );
+    assert_eq!( sqrt2loc::<Int>().pow(0), Loc::one() );
+    let x = Loc{ num: lambda, log_den: 3 };
+    assert_eq!( x.pow(-4)*x.pow(4), Loc::one() );
+}
+
+
+#[test]
+#[should_panic]
+pub fn negative_powers_of_non_units_panic()
+{
+    let _ = Zroot2(3 as Int, 1).pow(-1);
+}
+
+
+#[test]
+pub fn powers_of_gates()
+{
+    let t = ExactUniMat::<Int>::t_gate();
+    assert_eq!( t.pow(8u32), ExactUniMat::one() );
+    assert_eq!( t.pow(-1), t.pow(7) );
+
+    for i in 1..20
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+        assert_eq!( gate.pow(0u32), ExactUniMat::one() );
+        assert_eq!( gate.pow(5), gate*gate*gate*gate*gate );
+        assert_eq!( gate.pow(-3)*gate.pow(3), ExactUniMat::one() );
+
+        let mat = gate.to_dyadic();
+        assert_eq!( mat.pow(3u32), mat*mat*mat );
+        assert_eq!( mat.pow(-2)*mat.pow(2), SUniMat::one() );
+
+        let quaternion_like = SUniMat::<Complex<Loc>>::from(gate.to_quaternion());
+        assert_eq!( quaternion_like.pow(4u32), quaternion_like*quaternion_like*quaternion_like*quaternion_like );
+    }
+}
@@ -8,9 +8,14 @@ use crate::algorithms::local_prime_factorization::power_mod_p;
 use crate::algorithms::local_prime_factorization::compute_gcd;
 use crate::algorithms::local_prime_factorization::find_unit_square_root;
 use crate::algorithms::local_prime_factorization::floorlog;
+use crate::algorithms::local_prime_factorization::factor_integer;
+use crate::algorithms::local_prime_factorization::is_probable_prime;
+use crate::algorithms::local_prime_factorization::square_root_mod;
 
 
+use crate::structs::rings::Conj;
 use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
 use crate::structs::rings::Float;
 use crate::structs::rings::LogDepInt;
 // use crate::structs::rings::LocalizableNorm;
@@ -213,6 +218,42 @@ pub fn testing_lots_of_gcd_in_zomega()
 }
 
 
+// 15 bit factors multiply to 30+ bit coefficients
+// Taking norms of these used to overflow
+#[test]
+pub fn testing_gcd_of_zomega_with_big_coefficients()
+{
+    let N = 1 << 15;
+    let mut rng = thread_rng();
+
+    let common = Zomega(rng.gen_range(-N..N), rng.gen_range(-N..N), rng.gen_range(-N..N), rng.gen_range(-N..N));
+    let left = Zomega(rng.gen_range(-N..N), rng.gen_range(-N..N), rng.gen_range(-N..N), rng.gen_range(-N..N));
+    let right = Zomega(rng.gen_range(-N..N), rng.gen_range(-N..N), rng.gen_range(-N..N), rng.gen_range(-N..N));
+
+    if common.is_zero() || left.is_zero() || right.is_zero()
+    {
+        return;
+    }
+
+    let n1 = common*left;
+    let n2 = common*right;
+
+    let gcd = compute_gcd(n1,n2);
+
+    assert!( n1%gcd == Zomega::zero() && n2%gcd == Zomega::zero() );
+    assert!( gcd%common == Zomega::zero() );
+}
+
+#[test]
+pub fn testing_lots_of_gcd_in_zomega_with_big_coefficients()
+{
+    for _ in 0..100
+    {
+        testing_gcd_of_zomega_with_big_coefficients();
+    }
+}
+
+
 #[test]
 pub fn testing_lots_of_gcd_in_sqrt2()
 {
@@ -279,14 +320,14 @@ pub fn testing_prime_factorization_in_loc()
 
 
     // println!("------------------ \n \n INPUT: {}",factorize_this);
-    let factorvec = prime_factorization_of_loc(factorize_this);
+    let factorvec = prime_factorization_of_loc(factorize_this).unwrap().unwrap();
     // println!("NUMBER OF FACTORS: {}",factorvec.len());
 
     let mut prod = Loc::one();
     for (prime,primeloc,power) in factorvec
     {
         // println!("ONE FACTOR IS : {} : with multiplicity {}",primeloc, power );
-        if prime == 2
+        if prime == BigInt::from(2)
         {
             if power < 0
             {
@@ -417,7 +458,7 @@ pub fn testing_if_sum_of_locs_work()
     // attempt_to_write_this_number_as_sum_of_two_squares_in_loc( sqrt2loc()  );
     // let out = attempt_to_write_this_number_as_sum_of_two_squares_in_loc(u1*u1 + u2*u2);
     //
-    let out = attempt_to_write_this_number_as_sum_of_two_squares_in_loc(input);
+    let out = attempt_to_write_this_number_as_sum_of_two_squares_in_loc(input).unwrap();
 
     if out == None
     {
@@ -498,3 +539,38 @@ pub fn floorlogtest()
     }
 
 }
+
+
+#[test]
+pub fn factoring_integers_above_u128()
+{
+    let mersenne_89 = BigInt::from(2).pow(89u32) - 1;
+    let mersenne_107 = BigInt::from(2).pow(107u32) - 1;
+    let above_u64 = BigInt::from(2).pow(64u32) + 13;
+
+    assert!( is_probable_prime(&mersenne_89) );
+    assert!( is_probable_prime(&above_u64) );
+    assert!( !is_probable_prime(&(&mersenne_89 * &mersenne_107)) );
+    // A Carmichael number
+    assert!( !is_probable_prime(&BigInt::from(561)) );
+
+    // Two big primes are too much for Pollard's rho, and the number is given up on
+    // With only one, and small ones that the rho splits off, it goes through
+    let n = BigInt::from(9) * BigInt::from(1000003) * BigInt::from(65537).pow(3u32) * &mersenne_89 * &mersenne_89 * &mersenne_107;
+    assert_eq!( factor_integer(&n), None );
+    let n: BigInt = &n / (&mersenne_89 * &mersenne_107);
+    assert!( n.bits() > 128 );
+    let factors = factor_integer(&n).unwrap();
+    assert_eq!( factors, vec![(BigInt::from(3), 2), (BigInt::from(65537), 3), (BigInt::from(1000003), 1), (mersenne_89.clone(), 1)] );
+    assert_eq!( factor_integer(&(-BigInt::from(12))).unwrap(), vec![(BigInt::from(2), 2), (BigInt::from(3), 1)] );
+
+    for p in [mersenne_89, mersenne_107, above_u64, BigInt::from(1000003)]
+    {
+        for x in [2, 3, 12345]
+        {
+            let square = BigInt::from(x) * BigInt::from(x);
+            let root = square_root_mod(&square, &p);
+            assert_eq!( (&root * &root - &square) % &p, BigInt::from(0) );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::Float;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::structs::rings::quaternion::Quaternion;
+use crate::structs::rings::Conj;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::unimat::ExactUniMat;
+use crate::tests::int_backend_tests::long_gate_string;
+
+use num_complex::Complex;
+use num_traits::One;
+
+use std::f64::consts::PI;
+
+type Loc = Local<Zroot2>;
+type Quat = Quaternion<Loc>;
+
+
+pub fn close_to(x: Float, y: Float) -> bool
+{
+    return (x-y).abs() < 1e-9;
+}
+
+
+#[test]
+pub fn quaternions_multiply_like_special_unitaries()
+{
+    for i in 1..30
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+        let other = ExactUniMat::<Int>::from_string(&long_gate_string(30-i));
+
+        let q = Quat::from(gate.mat);
+        assert_eq!( SUniMat::<Complex<Loc>>::from(q), gate.mat );
+        assert_eq!( Quat::from(gate.mat*other.mat), q*Quat::from(other.mat) );
+        assert_eq!( q.rsqnorm(), Loc::one() );
+        assert_eq!( q*q.inv(), Quat::one() );
+    }
+}
+
+
+#[test]
+pub fn exact_gates_as_quaternions()
+{
+    let root2 = Loc{ num: Zroot2::one(), log_den: -1 };
+    let allowed_norms = [Loc::one(), Loc::from_base(Zroot2(2, 0))+root2, Loc::from_base(Zroot2(2, 0))-root2];
+
+    for i in 1..30
+    {
+        let gate = ExactUniMat::<Int>::from_string(&long_gate_string(i));
+        let q = gate.to_quaternion();
+
+        assert!( allowed_norms.contains(&q.rsqnorm()) );
+        assert_eq!( ExactUniMat::from_quaternion(&q, gate.omega_exp), gate );
+
+        // Composition agrees up to the global phase
+        let other = ExactUniMat::<Int>::from_string(&long_gate_string(30-i));
+        let product = (gate*other).to_float_unit_quaternion();
+        let composed = (q*other.to_quaternion()).to_float_unit_quaternion();
+        let sign = if product.0*composed.0 + product.1*composed.1 + product.2*composed.2 + product.3*composed.3 > 0.0 { 1.0 } else { -1.0 };
+        assert!( close_to(product.0, sign*composed.0) && close_to(product.1, sign*composed.1) );
+        assert!( close_to(product.2, sign*composed.2) && close_to(product.3, sign*composed.3) );
+    }
+}
+
+
+#[test]
+pub fn angles_and_axes_of_clifford_t_gates()
+{
+    // T is exp(-iπ/8 Z) up to phase, and S is exp(-iπ/4 Z)
+    let (angle, axis) = Quat::t_gate().to_float_unit_quaternion().angle_and_axis();
+    assert!( close_to(angle, PI/4.0) );
+    assert!( close_to(axis[0], 0.0) && close_to(axis[1], 0.0) && close_to(axis[2], 1.0) );
+
+    let (angle, axis) = ExactUniMat::<Int>::s_gate().to_float_unit_quaternion().angle_and_axis();
+    assert!( close_to(angle, PI/2.0) );
+    assert!( close_to(axis[0], 0.0) && close_to(axis[1], 0.0) && close_to(axis[2], 1.0) );
+
+    let (angle, axis) = Quat::h_gate().to_float_unit_quaternion().angle_and_axis();
+    assert!( close_to(angle, PI) );
+    assert!( close_to(axis[0].abs(), axis[2].abs()) && close_to(axis[1], 0.0) );
+
+    let (angle, _) = Quat::one().to_float_unit_quaternion().angle_and_axis();
+    assert!( close_to(angle, 0.0) );
+}
+
+
+#[test]
+pub fn axes_are_in_bloch_sphere_coordinates()
+{
+    let (c, s) = ((PI/6.0).cos(), (PI/6.0).sin());
+    let i = Complex::new(0.0, 1.0);
+
+    // exp(-iπ/6 X), exp(-iπ/6 Y) and exp(-iπ/6 Z), with u and t the first column
+    let rotations = [
+        (SUniMat{ u: Complex::new(c, 0.0), t: -i*s }, [1.0, 0.0, 0.0]),
+        (SUniMat{ u: Complex::new(c, 0.0), t: Complex::new(s, 0.0) }, [0.0, 1.0, 0.0]),
+        (SUniMat{ u: Complex::new(c, -s), t: Complex::new(0.0, 0.0) }, [0.0, 0.0, 1.0]),
+    ];
+    for (gate, expected) in rotations
+    {
+        let (angle, axis) = Quaternion::<Float>::from(gate).angle_and_axis();
+        assert!( close_to(angle, PI/3.0) );
+        assert!( (0..3).all(|k| close_to(axis[k], expected[k])) );
+
+        // The opposite rotation turns around the opposite axis
+        let (angle, axis) = Quaternion::<Float>::from(gate).conj().angle_and_axis();
+        assert!( close_to(angle, PI/3.0) );
+        assert!( (0..3).all(|k| close_to(axis[k], -expected[k])) );
+    }
+}
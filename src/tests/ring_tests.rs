@@ -25,6 +25,8 @@ use crate::structs::rings::special_values::sqrt2;
 use num_complex::Complex;
 use crate::structs::sunimat::SUniMat; 
 use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use num_traits::Signed;
 use crate::structs::rings::Float;
 use crate::algorithms::exact_synth::apply_gate_string_to_state;
 
@@ -40,7 +42,7 @@ pub fn basic_identities<T>() -> ()
     where T: Copy+Debug+Display,
           T: Add<Output=T>+Mul<Output=T>+Sub<Output=T>+Neg<Output=T>,
           T: PartialEq,
-          T: NumCast
+          T: One+NumCast
 {
 
     // println!("Testing basic identities");
@@ -218,9 +220,9 @@ pub fn break_division_in_loc_26_10_2022() {
     // type Mat = SUniMat<Comp>;
     // type Quat = Quaternion<Loc>;
 
-    let omega = mu_8();
-    let onebyroot2 = onebyroot2comp();
-    let root2 = sqrt2();
+    let omega = mu_8::<Int>();
+    let onebyroot2 = onebyroot2comp::<Int>();
+    let root2 = sqrt2::<Int>();
     let one = Comp::one();
     let zero = Comp::zero();
 
@@ -238,7 +240,7 @@ pub fn doesnt_break_matrices_27_10_2022()
 
     let omega = mu_8();
     let onebyroot2 = onebyroot2comp();
-    let root2 = sqrt2();
+    let root2 = sqrt2::<Int>();
     let one = Comp::one();
     let zero = Comp::zero();
 
@@ -450,10 +452,44 @@ pub fn testing_randomly_euclidean_division_many_times()
 }
 
 
+// Coefficients of 32 bits, so the absolute norms do not fit in an Int
+// We compare the norms over BigInt
+#[test]
+pub fn testing_euclidean_division_in_zomega_randomly()
+{
+    let r : Int = 1 << 32; //range
+
+    let mut rng = thread_rng();
+    let u = Zomega(rng.gen_range(-r..r), rng.gen_range(-r..r), rng.gen_range(-r..r), rng.gen_range(-r..r));
+    let v = Zomega(rng.gen_range(-r..r), rng.gen_range(-r..r), rng.gen_range(-r..r), rng.gen_range(-r..r));
+
+    let (q, rem) = u.div_rem(&v);
+    assert_eq!(u, q*v+rem );
+    assert_eq!(q, u/v );
+    assert_eq!(rem, u%v );
+
+    let rem_norm = rem.to_backend::<BigInt>().unwrap().norm().abs();
+    let v_norm = v.to_backend::<BigInt>().unwrap().norm().abs();
+    assert!( rem_norm < v_norm );
+
+    // Exact divisions stay exact
+    assert_eq!( (u*v)/v, u );
+}
+
+#[test]
+pub fn testing_randomly_euclidean_division_in_zomega_many_times()
+{
+    for _ in 0..1000
+    {
+        testing_euclidean_division_in_zomega_randomly();
+    }
+}
+
+
 #[test]
 pub fn is_zero_test() {
-    let zroot2_zero = Zroot2::zero();
-    let zomega_zero = Zomega::zero();
+    let zroot2_zero = Zroot2::<Int>::zero();
+    let zomega_zero = Zomega::<Int>::zero();
     let local_zroot2_zero = Local::<Zroot2>::zero();
     
     assert!(zroot2_zero.is_zero());
@@ -0,0 +1,94 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::ArithmeticOverflow;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::algorithms::inexact_synth::SQRT2;
+use crate::structs::rings::zroot2::Zroot2;
+use crate::tests::euclidean_domain_tests::random_zroot2;
+
+use rand::thread_rng;
+use rand::Rng;
+
+use num_traits::One;
+use num_traits::Zero;
+use num_traits::Pow;
+
+
+#[test]
+pub fn units_decompose_into_powers_of_lambda()
+{
+    let lambda = Zroot2(1 as Int, 1);
+    for k in -40..40
+    {
+        let u = Zroot2::lambda_pow(k).unwrap();
+        assert_eq!( u, lambda.pow(k) );
+        assert!( u.is_unit() );
+        assert_eq!( u.unit_decompose(), Some((Zroot2::one(), k)) );
+        assert_eq!( (-u).unit_decompose(), Some((-Zroot2::one(), k)) );
+    }
+
+    assert_eq!( Zroot2::<Int>::zero().unit_decompose(), None );
+    assert_eq!( Zroot2(3 as Int, 1).unit_decompose(), None );
+    assert_eq!( Zroot2(0 as Int, 1).unit_decompose(), None );
+}
+
+
+#[test]
+pub fn huge_units_do_not_overflow()
+{
+    // The norm of λ^99 does not fit in an i128, but λ^99 does
+    let u = Zroot2::<Int>::lambda_pow(99).unwrap();
+    assert!( u.is_unit() );
+    assert_eq!( u.unit_decompose(), Some((Zroot2::one(), 99)) );
+    assert_eq!( Zroot2::<Int>::lambda_pow(-98).unwrap().unit_sqrt(), Some(Zroot2::lambda_pow(-49).unwrap()) );
+    assert_eq!( Zroot2::<Int>::lambda_pow(200), Err(ArithmeticOverflow) );
+
+    let big = Zroot2::<BigInt>::lambda_pow(-1001).unwrap();
+    assert_eq!( big.unit_decompose(), Some((Zroot2::one(), -1001)) );
+}
+
+
+#[test]
+pub fn square_roots_of_units()
+{
+    for k in -30..30
+    {
+        let u = Zroot2::<Int>::lambda_pow(k).unwrap();
+        assert_eq!( (u*u).unit_sqrt(), Some(u) );
+        assert_eq!( (-u*u).unit_sqrt(), None );
+        assert_eq!( (u*u*Zroot2(1, 1)).unit_sqrt(), None );
+    }
+    assert_eq!( Zroot2(4 as Int, 0).unit_sqrt(), None );
+}
+
+
+#[test]
+pub fn reducing_by_units_balances_the_embeddings()
+{
+    let lambda = 1.0 + SQRT2;
+    let mut rng = thread_rng();
+    for _ in 0..500
+    {
+        let x = random_zroot2(1000);
+        if x.is_zero()
+        {
+            continue;
+        }
+        let (y, m) = x.reduce_by_units().unwrap();
+        assert_eq!( y, x*Zroot2::lambda_pow(m).unwrap() );
+
+        let (y_float, y_bullet) = y.embeddings();
+        let ratio = (y_float/y_bullet).abs();
+        assert!( 1.0/lambda - 1e-9 <= ratio && ratio <= lambda + 1e-9 );
+
+        // Every associate reduces to the same thing
+        let k = rng.gen_range(-15..15);
+        let (z, n) = (x*Zroot2::lambda_pow(k).unwrap()).reduce_by_units().unwrap();
+        assert_eq!( z, y );
+        assert_eq!( n, m-k );
+    }
+
+    assert_eq!( Zroot2::<Int>::zero().reduce_by_units(), Ok((Zroot2::zero(), 0)) );
+    assert_eq!( Zroot2::<Int>::lambda_pow(37).unwrap().reduce_by_units(), Ok((Zroot2::one(), -37)) );
+}
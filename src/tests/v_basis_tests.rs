@@ -0,0 +1,196 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::Float;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::Conj;
+use crate::structs::rings::Localizable;
+use crate::structs::rings::EuclideanDomain;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zi::Zi;
+use crate::structs::v_unimat::ExactVUniMat;
+use crate::algorithms::exact_synth_v::exact_synth_v;
+use crate::algorithms::exact_synth_v::exact_synth_v_string;
+use crate::algorithms::inexact_synth_v::grid_problem_v_given_theta_and_epsilon;
+use crate::algorithms::inexact_synth_v::grid_problem_v_counting_overflows;
+use crate::algorithms::local_prime_factorization::attempt_to_write_integer_as_sum_of_two_squares;
+use crate::tests::euclidean_domain_tests::euclidean_identities;
+
+use rand::thread_rng;
+use rand::Rng;
+
+use num_complex::Complex;
+use num_traits::Zero;
+use num_traits::One;
+use num_traits::Pow;
+
+type ILoc = Local<Zi>;
+
+const PAULI_AND_V_GATES: [char; 9] = ['X', 'Y', 'Z', 'A', 'B', 'C', 'a', 'b', 'c'];
+
+
+pub fn random_zi(range: Int) -> Zi
+{
+    let mut rng = thread_rng();
+    return Zi(rng.gen_range(-range..range), rng.gen_range(-range..range));
+}
+
+pub fn random_v_string(length: usize) -> String
+{
+    let mut rng = thread_rng();
+    return (0..length).map(|_| PAULI_AND_V_GATES[rng.gen_range(0..9)]).collect();
+}
+
+
+#[test]
+pub fn gaussian_integers_form_a_euclidean_domain()
+{
+    for _ in 0..200
+    {
+        let (x, y, z) = (random_zi(1000), random_zi(1000), random_zi(1000));
+        assert_eq!( (x*y)*z, x*(y*z) );
+        assert_eq!( x*(y+z), x*y + x*z );
+        assert_eq!( (x*y).norm(), x.norm()*y.norm() );
+        assert_eq!( (x*y).conj(), x.conj()*y.conj() );
+        assert_eq!( x*x.conj(), Zi(x.norm(), 0) );
+
+        if !y.is_zero()
+        {
+            assert_eq!( (x*y)/y, x );
+            euclidean_identities(x, y);
+            assert!( 2*(x%y).norm() <= y.norm() );
+        }
+    }
+
+    let i = Zi::<Int>(0, 1);
+    assert_eq!( i.pow(4u32), Zi::one() );
+    assert_eq!( i.pow(-1), -i );
+    assert_eq!( Zi::<Int>(2, 1)*Zi(2, -1), Zi(5, 0) );
+}
+
+
+#[test]
+pub fn localizing_gaussian_integers_at_five()
+{
+    for _ in 0..100
+    {
+        let x = random_zi(1000);
+        if x.is_zero()
+        {
+            continue;
+        }
+        let (y, n) = x.reduce_by_dividing();
+        assert!( !y.is_divisible() );
+        assert_eq!( y.perform_n_multiplications(n), x );
+        assert_eq!( x.perform_n_multiplications(3).reduce_by_dividing(), (y, n+3) );
+
+        let p = ILoc::from_base(x)*ILoc::from_base(Zi(2, 1));
+        assert_eq!( p.conj(), ILoc::from_base(x.conj()*Zi(2, -1)) );
+    }
+
+    // 2+i alone is not inverted, only 5 is
+    assert_eq!( ILoc::from_base(Zi(2, 1)).log_den, 0 );
+    let one_by_five = ILoc::one()/ILoc::from_base(Zi(5, 0));
+    assert_eq!( one_by_five, ILoc{ num: Zi::one(), log_den: 1 } );
+    assert_eq!( one_by_five*ILoc::from_base(Zi(2, 1))*ILoc::from_base(Zi(2, -1)), ILoc::one() );
+}
+
+
+#[test]
+pub fn v_gates_are_unitary()
+{
+    let one = ExactVUniMat::<Int>::one();
+    for v in ['A', 'B', 'C']
+    {
+        let gate = ExactVUniMat::<Int>::from_string(&v.to_string());
+        let inverse = ExactVUniMat::<Int>::from_string(&v.to_ascii_lowercase().to_string());
+        assert_eq!( gate*inverse, one );
+        assert_eq!( gate.pow(-1), inverse );
+        assert_eq!( gate.sde(), 1 );
+        assert_eq!( gate.det(), ILoc::from_base(Zi(5, 0)) );
+    }
+
+    // The Paulis square to -I once the phase is dropped
+    for p in ["X", "Y", "Z"]
+    {
+        let gate = ExactVUniMat::<Int>::from_string(&p.to_string());
+        assert_eq!( gate*gate, ExactVUniMat::from_string(&"XX".to_string()) );
+        assert_eq!( gate.pow(4u32), one );
+        assert_eq!( gate.sde(), 0 );
+    }
+    assert_eq!( ExactVUniMat::<Int>::from_string(&"XYZ".to_string()), one );
+
+    // Paulis permute the V gates: X V_Y X^-1 = V_Y^-1
+    assert_eq!( ExactVUniMat::<Int>::from_string(&"XBXXX".to_string()), ExactVUniMat::from_string(&"b".to_string()) );
+
+    for i in 0..30
+    {
+        let gate = ExactVUniMat::<Int>::from_string(&random_v_string(i));
+        let (u, t) = gate.gaussian_entries();
+        assert_eq!( u.norm() + t.norm(), (5 as Int).pow(gate.sde() as u32) );
+        assert_eq!( ExactVUniMat::from_gaussian_entries(u, t, gate.sde()), gate );
+        assert_eq!( gate*gate.inv(), one );
+    }
+}
+
+
+#[test]
+pub fn exact_synthesis_with_v_gates()
+{
+    for i in 0..40
+    {
+        let gate = ExactVUniMat::<Int>::from_string(&random_v_string(i));
+        let circuit = exact_synth_v(gate);
+        assert_eq!( circuit.to_v_unimat::<Int>(), gate );
+
+        let seq = exact_synth_v_string(gate);
+        assert_eq!( seq, circuit.to_string() );
+        assert_eq!( ExactVUniMat::from_string(&seq), gate );
+
+        // One V gate for each power of √5
+        let v_count = circuit.v_count();
+        assert_eq!( v_count, seq.chars().filter(|c| "ABCabc".contains(*c)).count() );
+        assert_eq!( v_count as i32, gate.sde() );
+        assert!( v_count <= i );
+    }
+
+    let gate = ExactVUniMat::<BigInt>::from_string(&random_v_string(150));
+    assert_eq!( exact_synth_v(gate.clone()).to_v_unimat::<BigInt>(), gate );
+}
+
+
+#[test]
+pub fn gaussian_sums_of_two_squares()
+{
+    for n in 0..2000
+    {
+        let answer = attempt_to_write_integer_as_sum_of_two_squares::<Int>(n);
+        let brute_force = (0..45).any(|a: Int| (0..45).any(|b: Int| a*a+b*b == n));
+        assert_eq!( answer.is_some(), brute_force );
+        if let Some(x) = answer
+        {
+            assert_eq!( x.norm(), n );
+        }
+    }
+}
+
+
+#[test]
+pub fn grid_problem_for_v_gates()
+{
+    let mut rng = thread_rng();
+    for epsilon in [0.1, 0.03, 0.01]
+    {
+        let theta: Float = rng.gen_range(0.0..6.28);
+        let gate = grid_problem_v_given_theta_and_epsilon::<Int>(theta, epsilon);
+
+        let (u, _) = gate.gaussian_entries();
+        let scale = (5.0 as Float).powi(gate.sde()).sqrt();
+        let u_float = Complex::new(u.0 as Float, u.1 as Float)/scale;
+        assert!( (u_float - Complex::new(0.0, theta).exp()).norm() <= epsilon );
+
+        assert_eq!( exact_synth_v(gate).to_v_unimat::<Int>(), gate );
+
+        // At these epsilons nothing comes close to overflowing
+        let (_, overflow_count) = grid_problem_v_counting_overflows::<Int>(Complex::new(0.0, theta).exp(), epsilon);
+        assert_eq!( overflow_count, 0 );
+    }
+}
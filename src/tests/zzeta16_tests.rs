@@ -0,0 +1,230 @@
+use crate::structs::rings::Int;
+use crate::structs::rings::Float;
+use crate::structs::rings::BigInt;
+use crate::structs::rings::Conj;
+use crate::structs::rings::Localizable;
+use crate::structs::rings::LocalizableNorm;
+use crate::structs::rings::GaloisAutomorphism;
+use crate::structs::rings::local_ring::Local;
+use crate::structs::rings::zomega::Zomega;
+use crate::structs::rings::zzeta16::Zzeta16;
+use crate::structs::rings::special_values::zeta16;
+use crate::structs::sunimat::SUniMat;
+use crate::structs::unimat::ExactUniMat;
+use crate::structs::sqrt_t_unimat::SqrtTUniMat;
+use crate::algorithms::exact_synth_sqrt_t::exact_synth_sqrt_t;
+use crate::algorithms::exact_synth_sqrt_t::exact_synth_sqrt_t_string;
+use crate::structs::circuit::SqrtTGate;
+use crate::tests::euclidean_domain_tests::random_zomega;
+use crate::tests::local_zomega_tests::random_dloc;
+use crate::tests::int_backend_tests::long_gate_string;
+use crate::tests::galois_tests::close_to;
+
+use rand::thread_rng;
+use rand::Rng;
+
+use num_traits::Zero;
+use num_traits::One;
+use num_traits::Pow;
+
+type DLoc = Local<Zomega>;
+type ZetaLoc = Local<Zzeta16>;
+
+const ODD: [u8; 8] = [1, 3, 5, 7, 9, 11, 13, 15];
+
+
+pub fn random_zzeta16(range: Int) -> Zzeta16
+{
+    return Zzeta16(random_zomega(range), random_zomega(range));
+}
+
+pub fn random_zeta_loc() -> ZetaLoc
+{
+    let mut rng = thread_rng();
+    return ZetaLoc::from_base(random_zzeta16(100))*ZetaLoc{ num: Zzeta16::one(), log_den: rng.gen_range(-5..20) };
+}
+
+// A word in H and √T, with 1 to 15 copies of √T after each H
+pub fn random_sqrt_t_string(number_of_h: usize) -> String
+{
+    let mut rng = thread_rng();
+    let mut seq = "".to_string();
+    for _ in 0..number_of_h
+    {
+        seq.push_str("H");
+        seq.push_str(&"Q".repeat(rng.gen_range(1..16)));
+    }
+    return seq;
+}
+
+
+#[test]
+pub fn zeta_is_a_sixteenth_root_of_unity()
+{
+    let zeta = zeta16::<Int>();
+    assert_eq!( zeta.pow(8u32), -Zzeta16::one() );
+    assert_eq!( zeta.pow(2u32), Zzeta16::from_zomega(Zomega(0, 1, 0, 0)) );
+    assert_eq!( zeta.pow(-1)*zeta, Zzeta16::one() );
+    assert_eq!( zeta.conj(), zeta.pow(15u32) );
+    assert_eq!( zeta.norm(), 1 );
+
+    // 1+ζ has norm 2, and √2 is (1+ζ)^4 times a unit
+    let delta = Zzeta16::one() + zeta;
+    assert_eq!( delta.norm(), 2 );
+    let sqrt2 = Zzeta16::from_zomega(Zomega(0, 1, 0, -1));
+    assert_eq!( sqrt2.reduce_by_dividing().1, 4 );
+    assert!( (sqrt2/delta.pow(4u32)).is_unit() );
+}
+
+
+#[test]
+pub fn ring_axioms_and_norms()
+{
+    for _ in 0..100
+    {
+        let (x, y, z) = (random_zzeta16(50), random_zzeta16(50), random_zzeta16(50));
+        assert_eq!( (x*y)*z, x*(y*z) );
+        assert_eq!( x*(y+z), x*y + x*z );
+        assert_eq!( (x*y).norm(), x.norm()*y.norm() );
+        assert_eq!( (x*y).conj(), x.conj()*y.conj() );
+        assert_eq!( x.conj().conj(), x );
+        assert_eq!( Zzeta16::from_coefficients(x.coefficients()), x );
+
+        // Division is exact when it can be
+        if !y.is_zero()
+        {
+            assert_eq!( (x*y)/y, x );
+        }
+    }
+}
+
+
+#[test]
+pub fn dividing_by_one_plus_zeta_undoes_multiplying()
+{
+    for _ in 0..100
+    {
+        let x = random_zzeta16(1000);
+        if x.is_zero()
+        {
+            continue;
+        }
+        let (y, n) = x.reduce_by_dividing();
+        assert!( !y.is_divisible() );
+        assert_eq!( y.perform_n_multiplications(n), x );
+
+        let (z, m) = x.perform_n_multiplications(5).reduce_by_dividing();
+        assert_eq!( (z, m), (y, n+5) );
+        assert_eq!( x.checked_perform_n_multiplications(5), Ok(x.perform_n_multiplications(5)) );
+    }
+}
+
+
+#[test]
+pub fn galois_group_of_the_sixteenth_cyclotomic()
+{
+    for _ in 0..50
+    {
+        let (x, y) = (random_zzeta16(50), random_zzeta16(50));
+        let (p, q) = (random_zeta_loc(), random_zeta_loc());
+        for j in ODD
+        {
+            assert_eq!( (x*y).galois(j), x.galois(j)*y.galois(j) );
+            assert_eq!( (p*q).galois(j), p.galois(j)*q.galois(j) );
+            assert_eq!( (p+q).galois(j), p.galois(j)+q.galois(j) );
+            for k in ODD
+            {
+                assert_eq!( x.galois(j).galois(k), x.galois(((j as u32*k as u32)%16) as u8) );
+            }
+        }
+        assert_eq!( x.complex_conj(), x.conj() );
+        assert_eq!( p.complex_conj(), p.conj() );
+        assert_eq!( x.galois(9), x.conj_zeta() );
+        assert_eq!( x*x.galois(9), Zzeta16::from_zomega(x.relative_norm()) );
+
+        // The norm is the product of all eight embeddings
+        let product: Float = x.all_embeddings().iter().map(|e| e.norm_sqr()).product();
+        assert!( (product - x.norm() as Float).abs() <= 1e-6*product.max(1.0) );
+    }
+}
+
+
+#[test]
+pub fn zomega_sits_inside_zzeta16()
+{
+    for _ in 0..100
+    {
+        let (a, b) = (random_zomega(100), random_zomega(100));
+        let (x, y) = (Zzeta16::from_zomega(a), Zzeta16::from_zomega(b));
+        assert_eq!( x*y, Zzeta16::from_zomega(a*b) );
+        assert_eq!( x.conj(), Zzeta16::from_zomega(a.conj()) );
+        assert_eq!( x.bullet(), Zzeta16::from_zomega(a.bullet()) );
+        assert_eq!( x.norm(), a.norm()*a.norm() );
+
+        let (p, q) = (random_dloc(), random_dloc());
+        assert_eq!( ZetaLoc::from(p*q), ZetaLoc::from(p)*ZetaLoc::from(q) );
+        assert_eq!( ZetaLoc::from(p+q), ZetaLoc::from(p)+ZetaLoc::from(q) );
+        assert_eq!( ZetaLoc::from(p).sde(), 2*p.sde() );
+
+        let (e, e_bullet) = p.embeddings();
+        let (f, f_bullet) = ZetaLoc::from(p).embeddings();
+        let size = e.norm() + e_bullet.norm();
+        assert!( close_to(e, f, size) && close_to(e_bullet, f_bullet, size) );
+    }
+}
+
+
+#[test]
+pub fn sqrt_t_gates()
+{
+    let q = SqrtTUniMat::<Int>::sqrt_t_gate();
+    let h = SqrtTUniMat::<Int>::h_gate();
+    assert_eq!( q*q, SqrtTUniMat::t_gate() );
+    assert_eq!( q.pow(16u32), SqrtTUniMat::one() );
+    assert_eq!( h*h, SqrtTUniMat::one() );
+    assert_eq!( q.pow(-1)*q, SqrtTUniMat::one() );
+    assert_eq!( h.det(), -ZetaLoc::one() );
+
+    // Clifford+T gates are Clifford+√T gates
+    for i in 1..20
+    {
+        let word = long_gate_string(i);
+        let gate = SqrtTUniMat::<Int>::from(ExactUniMat::from_string(&word));
+        assert_eq!( gate, SqrtTUniMat::from_string(&word) );
+        assert_eq!( gate, SqrtTUniMat::from_string(&word.replace("T", "QQ")) );
+        assert_eq!( gate.mat*gate.mat.inv(), SUniMat::one() );
+    }
+}
+
+
+#[test]
+pub fn exact_synthesis_with_sqrt_t()
+{
+    for i in 0..12
+    {
+        let gate = SqrtTUniMat::<Int>::from_string(&random_sqrt_t_string(i));
+        let circuit = exact_synth_sqrt_t(gate);
+        assert_eq!( circuit.to_sqrt_t_unimat::<Int>(), gate );
+        assert!( circuit.iter().all(|g| *g == SqrtTGate::H || *g == SqrtTGate::Q) );
+
+        let seq = exact_synth_sqrt_t_string(gate);
+        assert_eq!( seq, circuit.to_string() );
+        assert_eq!( SqrtTUniMat::from_string(&seq), gate );
+    }
+
+    // Numerators over powers of 1+ζ grow about five times per H,
+    // so longer words need BigInt
+    for i in [20, 40]
+    {
+        let gate = SqrtTUniMat::<BigInt>::from_string(&random_sqrt_t_string(i));
+        assert_eq!( exact_synth_sqrt_t(gate.clone()).to_sqrt_t_unimat::<BigInt>(), gate );
+    }
+
+    // Monomial gates have sde 0
+    for word in ["", "Q", "HQQQQQQQQH", "QQQHQQQQQQQQHQQ", "HQQQQQQQQHQQQQQHQQQQQQQQH"]
+    {
+        let gate = SqrtTUniMat::<Int>::from_string(&word.to_string());
+        assert_eq!( gate.sde(), 0 );
+        assert_eq!( SqrtTUniMat::from_string(&exact_synth_sqrt_t_string(gate)), gate );
+    }
+}